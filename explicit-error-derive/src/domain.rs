@@ -1,14 +1,168 @@
 use quote::quote;
+use syn::{
+    Data, Fields, Member, Token,
+    parse::{Parse, ParseStream},
+    parse_quote,
+    punctuated::Punctuated,
+};
 
 pub fn derive(
     input: syn::DeriveInput,
     crate_name: &'static str,
+    output: &'static str,
 ) -> syn::Result<proc_macro2::TokenStream> {
+    let transparent = transparent(&input)?;
+    let map = map(&input, output)?;
+    let sources = sources(&input)?;
+    let exit_codes = exit_codes(&input, output)?;
+    if let (Some(_), Some(source)) = (&transparent, sources.first()) {
+        return Err(syn::Error::new_spanned(
+            &source.attr,
+            "#[source] and #[from] cannot be combined with #[explicit_error(transparent)]",
+        ));
+    }
     let ident = input.ident;
-    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
     let crate_name: proc_macro2::TokenStream = syn::parse_str(crate_name)?;
+    let output: proc_macro2::TokenStream = syn::parse_str(output)?;
 
-    //TODO: re-implement source attribute like ThisError
+    // Bounds required by `ToDomainError` for generic domain errors
+    let is_generic = input.generics.type_params().next().is_some();
+    let mut generics = input.generics.clone();
+    let mut transparent_generics = input.generics.clone();
+    if is_generic {
+        let (_, ty_generics, _) = input.generics.split_for_impl();
+        let type_params: Vec<_> = input.generics.type_params().map(|p| &p.ident).collect();
+
+        for generics in [&mut generics, &mut transparent_generics] {
+            let where_clause = generics.make_where_clause();
+            for param in &type_params {
+                where_clause
+                    .predicates
+                    .push(parse_quote!(#param: std::fmt::Debug + Send + Sync + 'static));
+            }
+        }
+
+        generics.make_where_clause().predicates.push(
+            parse_quote!(for<'explicit_error> &'explicit_error #ident #ty_generics: Into<#crate_name::#output>),
+        );
+
+        for source in &sources {
+            let ty = &source.ty;
+            generics
+                .make_where_clause()
+                .predicates
+                .push(parse_quote!(#ty: std::error::Error + 'static));
+        }
+
+        for (_, ty) in transparent.iter().flatten() {
+            generics
+                .make_where_clause()
+                .predicates
+                .push(parse_quote!(#ty: std::error::Error + 'static));
+            transparent_generics.make_where_clause().predicates.extend::<[syn::WherePredicate; 2]>([
+                parse_quote!(#ty: std::error::Error + 'static),
+                parse_quote!(for<'explicit_error> &'explicit_error #ty: Into<#crate_name::#output>),
+            ]);
+        }
+    }
+    let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+
+    let (transparent_output, source) = match transparent {
+        Some(arms) => {
+            let patterns: Vec<_> = arms.iter().map(|(pattern, _)| pattern).collect();
+            let (impl_generics, _, transparent_where_clause) =
+                transparent_generics.split_for_impl();
+            (
+                quote! {
+                    #[automatically_derived]
+                    impl #impl_generics From<&#ident #ty_generics> for #crate_name::#output #transparent_where_clause {
+                        fn from(value: &#ident #ty_generics) -> Self {
+                            match value {
+                                #(#patterns => Into::<#crate_name::#output>::into(inner),)*
+                            }
+                        }
+                    }
+                },
+                quote! {
+                    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+                        match self {
+                            #(#patterns => Some(inner),)*
+                        }
+                    }
+                },
+            )
+        }
+        None if sources.is_empty() => (quote! {}, quote! {}),
+        None => {
+            let patterns: Vec<_> = sources.iter().map(|source| &source.pattern).collect();
+            let from_impls = sources.iter().filter(|source| source.from).map(|source| {
+                let SourceField { pattern, ty, .. } = source;
+                quote! {
+                    #[automatically_derived]
+                    impl #impl_generics From<#ty> for #ident #ty_generics #where_clause {
+                        fn from(source: #ty) -> Self {
+                            #pattern
+                        }
+                    }
+                }
+            });
+            (
+                quote! { #(#from_impls)* },
+                quote! {
+                    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+                        #[allow(unreachable_patterns)]
+                        match self {
+                            #(#patterns => Some(source),)*
+                            _ => None,
+                        }
+                    }
+                },
+            )
+        }
+    };
+
+    let map_output = match map {
+        Some(arms) => {
+            let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+            quote! {
+                #[automatically_derived]
+                impl #impl_generics From<&#ident #ty_generics> for #crate_name::#output #where_clause {
+                    fn from(value: &#ident #ty_generics) -> Self {
+                        match value {
+                            #(#arms)*
+                        }
+                    }
+                }
+            }
+        }
+        None => quote! {},
+    };
+
+    let exit_codes_output = match exit_codes {
+        Some(entries) => {
+            let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+            let patterns = entries.iter().map(|entry| &entry.pattern);
+            let codes: Vec<_> = entries.iter().map(|entry| &entry.code).collect();
+            let docs = entries.iter().map(|entry| &entry.doc);
+            quote! {
+                #[automatically_derived]
+                impl #impl_generics #ident #ty_generics #where_clause {
+                    /// Exit codes and their description, declared with `#[exit(code = .., doc = "..")]`
+                    pub fn exit_code_table() -> &'static [(u8, &'static str)] {
+                        &[#((#codes, #docs)),*]
+                    }
+
+                    /// Exit code declared with `#[exit(code = .., doc = "..")]`
+                    pub fn exit_code(&self) -> std::process::ExitCode {
+                        match self {
+                            #(#patterns => std::process::ExitCode::from(#codes),)*
+                        }
+                    }
+                }
+            }
+        }
+        None => quote! {},
+    };
 
     Ok(quote! {
         #[automatically_derived]
@@ -23,12 +177,451 @@ pub fn derive(
         }
 
         #[automatically_derived]
-        impl #impl_generics From<#ident> for #crate_name::Error #ty_generics #where_clause {
-            fn from(value: #ident) -> Self {
-                #crate_name::Error::Domain(Box::new(<#ident as #crate_name::ToDomainError>::to_domain_error(value)))
+        impl #impl_generics From<#ident #ty_generics> for #crate_name::Error #where_clause {
+            fn from(value: #ident #ty_generics) -> Self {
+                #crate_name::Error::Domain(Box::new(<#ident #ty_generics as #crate_name::ToDomainError>::to_domain_error(value)))
+            }
+        }
+
+        #[automatically_derived]
+        impl #impl_generics TryFrom<#crate_name::Error> for #ident #ty_generics #where_clause {
+            type Error = #crate_name::Error;
+
+            fn try_from(value: #crate_name::Error) -> std::result::Result<Self, Self::Error> {
+                match value.downcast_source_ref::<Self>() {
+                    Some(_) => Ok(value.downcast_source::<Self>().unwrap()),
+                    None => Err(value),
+                }
             }
         }
 
-        impl #impl_generics std::error::Error for #ident #ty_generics #where_clause {}
+        #transparent_output
+
+        #map_output
+
+        #exit_codes_output
+
+        impl #impl_generics std::error::Error for #ident #ty_generics #where_clause {
+            #source
+        }
     })
 }
+
+/// Return a pattern binding the single field to `inner`, and the field type, for each variant if the type is `#[explicit_error(transparent)]`.
+/// The attribute is set on structs or on every variant of enums.
+fn transparent(
+    input: &syn::DeriveInput,
+) -> syn::Result<Option<Vec<(proc_macro2::TokenStream, syn::Type)>>> {
+    let ident = &input.ident;
+
+    match &input.data {
+        Data::Struct(data) => {
+            let Some(attr) = transparent_attr(&input.attrs)? else {
+                return Ok(None);
+            };
+
+            let (member, ty) = single_field(&data.fields, attr)?;
+            Ok(Some(vec![(quote! { #ident { #member: inner } }, ty)]))
+        }
+        Data::Enum(data) => {
+            let mut arms = Vec::new();
+            for variant in &data.variants {
+                if let Some(attr) = transparent_attr(&variant.attrs)? {
+                    let variant_ident = &variant.ident;
+                    let (member, ty) = single_field(&variant.fields, attr)?;
+                    arms.push((quote! { #ident::#variant_ident { #member: inner } }, ty));
+                }
+            }
+
+            if arms.is_empty() {
+                return Ok(None);
+            }
+
+            if let Some(variant) = data
+                .variants
+                .iter()
+                .find(|v| explicit_error_attr(&v.attrs).is_none())
+            {
+                return Err(syn::Error::new_spanned(
+                    &variant.ident,
+                    "#[explicit_error(transparent)] must be set on every variant when set on one of them",
+                ));
+            }
+
+            Ok(Some(arms))
+        }
+        Data::Union(_) => match explicit_error_attr(&input.attrs) {
+            Some(attr) => Err(syn::Error::new_spanned(
+                attr,
+                "#[explicit_error(transparent)] is not supported on unions",
+            )),
+            None => Ok(None),
+        },
+    }
+}
+
+/// Field marked `#[source]` or `#[from]`, which is returned by [std::error::Error::source].
+struct SourceField {
+    /// Struct or variant binding the field to `source`, usable as a pattern or, for `#[from]`, as an expression
+    pattern: proc_macro2::TokenStream,
+    ty: syn::Type,
+    /// `#[from]` also generates a `From` implementation from the field type
+    from: bool,
+    attr: syn::Attribute,
+}
+
+/// Return the field marked `#[source]` or `#[from]` of the struct or of each variant of the enum, if any.
+fn sources(input: &syn::DeriveInput) -> syn::Result<Vec<SourceField>> {
+    let ident = &input.ident;
+
+    let fields: Vec<(proc_macro2::TokenStream, &Fields)> = match &input.data {
+        Data::Struct(data) => vec![(quote! { #ident }, &data.fields)],
+        Data::Enum(data) => data
+            .variants
+            .iter()
+            .map(|variant| {
+                let variant_ident = &variant.ident;
+                (quote! { #ident::#variant_ident }, &variant.fields)
+            })
+            .collect(),
+        Data::Union(_) => Vec::new(),
+    };
+
+    let mut sources = Vec::new();
+    for (path, fields) in fields {
+        let mut source: Option<SourceField> = None;
+        for (i, field) in fields.iter().enumerate() {
+            let Some(attr) = field
+                .attrs
+                .iter()
+                .find(|attr| attr.path().is_ident("source") || attr.path().is_ident("from"))
+            else {
+                continue;
+            };
+            attr.meta.require_path_only()?;
+
+            if source.is_some() {
+                return Err(syn::Error::new_spanned(
+                    attr,
+                    "only one field can be marked #[source] or #[from]",
+                ));
+            }
+
+            let from = attr.path().is_ident("from");
+            if from && fields.len() != 1 {
+                return Err(syn::Error::new_spanned(
+                    attr,
+                    "#[from] requires the field to be the only one",
+                ));
+            }
+
+            let member = match &field.ident {
+                Some(ident) => Member::Named(ident.clone()),
+                None => Member::Unnamed(syn::Index::from(i)),
+            };
+            source = Some(SourceField {
+                pattern: match from {
+                    true => quote! { #path { #member: source } },
+                    false => quote! { #path { #member: source, .. } },
+                },
+                ty: field.ty.clone(),
+                from,
+                attr: attr.clone(),
+            });
+        }
+        sources.extend(source);
+    }
+
+    Ok(sources)
+}
+
+/// Exit code of a struct or variant declared with `#[exit(code = 65, doc = "data format error")]`.
+struct ExitCodeEntry {
+    /// Struct or variant ignoring its fields
+    pattern: proc_macro2::TokenStream,
+    code: syn::LitInt,
+    doc: syn::LitStr,
+}
+
+/// Return the exit code of the struct or of each variant of the enum if they are marked `#[exit(..)]`, only supported by the ExitError derive.
+/// The attribute is set on structs or on every variant of enums.
+fn exit_codes(
+    input: &syn::DeriveInput,
+    output: &'static str,
+) -> syn::Result<Option<Vec<ExitCodeEntry>>> {
+    if output != "ExitError" {
+        return Ok(None);
+    }
+    let ident = &input.ident;
+
+    let items: Vec<(proc_macro2::TokenStream, &syn::Ident, &[syn::Attribute])> = match &input.data {
+        Data::Struct(_) => vec![(quote! { #ident { .. } }, ident, &input.attrs)],
+        Data::Enum(data) => data
+            .variants
+            .iter()
+            .map(|variant| {
+                let variant_ident = &variant.ident;
+                (
+                    quote! { #ident::#variant_ident { .. } },
+                    variant_ident,
+                    variant.attrs.as_slice(),
+                )
+            })
+            .collect(),
+        Data::Union(_) => Vec::new(),
+    };
+
+    let mut entries = Vec::new();
+    let mut missing = None;
+    for (pattern, item_ident, attrs) in items {
+        let Some(attr) = attrs.iter().find(|attr| attr.path().is_ident("exit")) else {
+            missing.get_or_insert(item_ident);
+            continue;
+        };
+
+        let mut code = None;
+        let mut doc = None;
+        attr.parse_nested_meta(|meta| {
+            if meta.path.is_ident("code") {
+                let value: syn::LitInt = meta.value()?.parse()?;
+                value.base10_parse::<u8>().map_err(|_| {
+                    syn::Error::new_spanned(
+                        &value,
+                        "invalid exit code, expected a number between 0 and 255",
+                    )
+                })?;
+                code = Some(value);
+                Ok(())
+            } else if meta.path.is_ident("doc") {
+                doc = Some(meta.value()?.parse()?);
+                Ok(())
+            } else {
+                Err(meta.error("unsupported exit attribute, expected `code` or `doc`"))
+            }
+        })?;
+
+        match (code, doc) {
+            (Some(code), Some(doc)) => entries.push(ExitCodeEntry { pattern, code, doc }),
+            _ => {
+                return Err(syn::Error::new_spanned(
+                    attr,
+                    "#[exit(..)] requires both `code` and `doc`, eg: #[exit(code = 65, doc = \"data format error\")]",
+                ));
+            }
+        }
+    }
+
+    if entries.is_empty() {
+        return Ok(None);
+    }
+
+    if let Some(item_ident) = missing {
+        return Err(syn::Error::new_spanned(
+            item_ident,
+            "#[exit(..)] must be set on every variant when set on one of them",
+        ));
+    }
+
+    Ok(Some(entries))
+}
+
+/// Entry of `#[explicit_error(map(..))]`: `Variant => (status, "type", "title")` or `_ => fallback`.
+enum MapEntry {
+    Variant {
+        variant: syn::Ident,
+        status: syn::LitInt,
+        r#type: syn::LitStr,
+        title: syn::LitStr,
+    },
+    Fallback(syn::ExprPath),
+}
+
+impl Parse for MapEntry {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        if input.peek(Token![_]) {
+            input.parse::<Token![_]>()?;
+            input.parse::<Token![=>]>()?;
+            return Ok(MapEntry::Fallback(input.parse()?));
+        }
+
+        let variant = input.parse()?;
+        input.parse::<Token![=>]>()?;
+        let content;
+        syn::parenthesized!(content in input);
+        let status: syn::LitInt = content.parse()?;
+        content.parse::<Token![,]>()?;
+        let r#type = content.parse()?;
+        content.parse::<Token![,]>()?;
+        let title = content.parse()?;
+        content.parse::<Option<Token![,]>>()?;
+
+        if !matches!(status.base10_parse::<u16>(), Ok(100..=999)) {
+            return Err(syn::Error::new_spanned(
+                status,
+                "invalid HTTP status code, expected a number between 100 and 999",
+            ));
+        }
+
+        Ok(MapEntry::Variant {
+            variant,
+            status,
+            r#type,
+            title,
+        })
+    }
+}
+
+/// Return the match arms of `From<&T> for HttpError` if the enum is `#[explicit_error(map(..))]`.
+/// Variants missing from the table are converted by the fallback function, which is required if there are any.
+fn map(
+    input: &syn::DeriveInput,
+    output: &'static str,
+) -> syn::Result<Option<Vec<proc_macro2::TokenStream>>> {
+    let Some(attr) = explicit_error_attr(&input.attrs) else {
+        return Ok(None);
+    };
+    let Data::Enum(data) = &input.data else {
+        return Ok(None);
+    };
+
+    let mut entries = None;
+    attr.parse_nested_meta(|meta| {
+        if meta.path.is_ident("transparent") {
+            Err(syn::Error::new_spanned(
+                attr,
+                "#[explicit_error(transparent)] must be set on each variant of an enum",
+            ))
+        } else if meta.path.is_ident("map") {
+            let content;
+            syn::parenthesized!(content in meta.input);
+            entries = Some(Punctuated::<MapEntry, Token![,]>::parse_terminated(
+                &content,
+            )?);
+            Ok(())
+        } else {
+            Err(meta.error("unsupported explicit_error attribute, expected `map`"))
+        }
+    })?;
+    let Some(entries) = entries else {
+        return Ok(None);
+    };
+
+    if output != "HttpError" {
+        return Err(syn::Error::new_spanned(
+            attr,
+            "#[explicit_error(map(..))] is only supported by the HttpError derive",
+        ));
+    }
+    if let Some(variant) = data
+        .variants
+        .iter()
+        .find(|v| explicit_error_attr(&v.attrs).is_some())
+    {
+        return Err(syn::Error::new_spanned(
+            &variant.ident,
+            "#[explicit_error(transparent)] cannot be combined with #[explicit_error(map(..))]",
+        ));
+    }
+
+    let ident = &input.ident;
+    let mut arms = Vec::new();
+    let mut mapped = Vec::new();
+    let mut fallback = None;
+    for entry in entries {
+        match entry {
+            MapEntry::Variant {
+                variant,
+                status,
+                r#type,
+                title,
+            } => {
+                if !data.variants.iter().any(|v| v.ident == variant) {
+                    return Err(syn::Error::new_spanned(
+                        &variant,
+                        format!("unknown variant `{variant}` of `{ident}`"),
+                    ));
+                }
+                if mapped.contains(&variant) {
+                    return Err(syn::Error::new_spanned(
+                        &variant,
+                        format!("variant `{variant}` is mapped more than once"),
+                    ));
+                }
+
+                arms.push(quote! {
+                    #ident::#variant { .. } => explicit_error_http::mapped_http_error(#status, #r#type, #title),
+                });
+                mapped.push(variant);
+            }
+            MapEntry::Fallback(path) => {
+                if fallback.is_some() {
+                    return Err(syn::Error::new_spanned(
+                        path,
+                        "only one fallback `_ => function` is allowed",
+                    ));
+                }
+                fallback = Some(path);
+            }
+        }
+    }
+
+    if let Some(variant) = data.variants.iter().find(|v| !mapped.contains(&v.ident)) {
+        match &fallback {
+            Some(path) => arms.push(quote! { _ => #path(value), }),
+            None => {
+                return Err(syn::Error::new_spanned(
+                    &variant.ident,
+                    format!(
+                        "variant `{}` is missing from #[explicit_error(map(..))], map it or add a fallback `_ => function`",
+                        variant.ident
+                    ),
+                ));
+            }
+        }
+    }
+
+    Ok(Some(arms))
+}
+
+fn explicit_error_attr(attrs: &[syn::Attribute]) -> Option<&syn::Attribute> {
+    attrs
+        .iter()
+        .find(|attr| attr.path().is_ident("explicit_error"))
+}
+
+fn transparent_attr(attrs: &[syn::Attribute]) -> syn::Result<Option<&syn::Attribute>> {
+    let Some(attr) = explicit_error_attr(attrs) else {
+        return Ok(None);
+    };
+
+    attr.parse_nested_meta(|meta| {
+        if meta.path.is_ident("transparent") {
+            Ok(())
+        } else {
+            Err(meta.error("unsupported explicit_error attribute, expected `transparent`"))
+        }
+    })?;
+
+    Ok(Some(attr))
+}
+
+fn single_field(fields: &Fields, attr: &syn::Attribute) -> syn::Result<(Member, syn::Type)> {
+    let mut iter = fields.iter();
+    match (iter.next(), iter.next()) {
+        (Some(field), None) => Ok((
+            match &field.ident {
+                Some(ident) => Member::Named(ident.clone()),
+                None => Member::Unnamed(syn::Index::from(0)),
+            },
+            field.ty.clone(),
+        )),
+        (None, _) => Err(syn::Error::new_spanned(
+            attr,
+            "#[explicit_error(transparent)] requires exactly one field",
+        )),
+        _ => Err(syn::Error::new_spanned(
+            fields,
+            "#[explicit_error(transparent)] requires exactly one field",
+        )),
+    }
+}
@@ -1,21 +1,67 @@
 use proc_macro2::TokenStream;
 use quote::quote;
+use syn::{Data, Member};
+
+pub fn derive(input: syn::DeriveInput) -> syn::Result<TokenStream> {
+    if let Data::Enum(data) = &input.data {
+        return derive_enum(&input, data);
+    }
 
-pub fn derive(input: syn::DeriveInput) -> TokenStream {
     let ident = input.ident;
     let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+    let metrics = |handler_error: TokenStream| {
+        if cfg!(feature = "metrics") {
+            quote! { explicit_error_http::record_error_metrics::<Self>(#handler_error); }
+        } else {
+            quote! {}
+        }
+    };
+    let actix_metrics = metrics(quote! { self });
+    let axum_metrics = metrics(quote! { &self });
 
     let actix = if cfg!(feature = "actix-web") {
         quote! {
             #[automatically_derived]
             impl #impl_generics actix_web::ResponseError for #ident #ty_generics #where_clause {
                 fn error_response(&self) -> actix_web::HttpResponse {
+                    #actix_metrics
                     match <Self as explicit_error_http::HandlerError>::error(self) {
-                        explicit_error_http::Error::Domain(d) => actix_web::HttpResponse::build(
-                            actix_web::http::StatusCode::from_u16(d.output.http_status_code.as_u16()).unwrap())
-                            .json(<Self as explicit_error_http::HandlerError>::domain_response(d)),
-                        explicit_error_http::Error::Fault(b) => actix_web::HttpResponse::InternalServerError()
-                            .json(<Self as explicit_error_http::HandlerError>::public_fault_response(b)),
+                        explicit_error_http::Error::Domain(d) => {
+                            let body = <Self as explicit_error_http::HandlerError>::domain_response(d);
+                            let mut builder = actix_web::HttpResponse::build(
+                                actix_web::http::StatusCode::from_u16(d.output.http_status_code.as_u16()).unwrap());
+
+                            for (name, value) in d.output.headers.iter().flat_map(|headers| headers.iter()) {
+                                builder.append_header((name.as_str(), value.as_bytes()));
+                            }
+
+                            if !d.output.has_body() {
+                                return builder.finish();
+                            }
+
+                            match explicit_error_http::encode_error_body(&body) {
+                                Some((content_type, bytes)) => builder
+                                    .insert_header((actix_web::http::header::CONTENT_TYPE, content_type))
+                                    .body(bytes),
+                                None => builder
+                                    .insert_header((actix_web::http::header::CONTENT_TYPE, d.output.content_type()))
+                                    .body(explicit_error_http::json_error_body(&body)),
+                            }
+                        }
+                        explicit_error_http::Error::Fault(b) => {
+                            let context = explicit_error_http::ResponseContext::current();
+                            let body = explicit_error_http::public_fault_body::<Self>(b, context.as_ref());
+                            let mut builder = actix_web::HttpResponse::InternalServerError();
+
+                            match explicit_error_http::encode_error_body(&body) {
+                                Some((content_type, bytes)) => builder
+                                    .insert_header((actix_web::http::header::CONTENT_TYPE, content_type))
+                                    .body(bytes),
+                                None => builder
+                                    .insert_header((actix_web::http::header::CONTENT_TYPE, explicit_error_http::error_content_type()))
+                                    .body(explicit_error_http::json_error_body(&body)),
+                            }
+                        }
                     }
                 }
             }
@@ -29,15 +75,60 @@ pub fn derive(input: syn::DeriveInput) -> TokenStream {
             #[automatically_derived]
             impl #impl_generics axum::response::IntoResponse for #ident #ty_generics #where_clause {
                 fn into_response(self) -> axum::response::Response {
+                    #axum_metrics
                     match <Self as explicit_error_http::HandlerError>::error(&self) {
-                        explicit_error_http::Error::Domain(d) => axum::response::IntoResponse::into_response((
-                            axum::http::StatusCode::from_u16(d.output.http_status_code.as_u16()).unwrap(),
-                            axum::Json(<Self as explicit_error_http::HandlerError>::domain_response(d)),
-                        )),
-                        explicit_error_http::Error::Fault(b) => axum::response::IntoResponse::into_response((
-                            axum::http::StatusCode::INTERNAL_SERVER_ERROR,
-                            axum::Json(<Self as explicit_error_http::HandlerError>::public_fault_response(b)),
-                        )),
+                        explicit_error_http::Error::Domain(d) => {
+                            let status = axum::http::StatusCode::from_u16(d.output.http_status_code.as_u16()).unwrap();
+                            let body = <Self as explicit_error_http::HandlerError>::domain_response(d);
+                            let mut response = if !d.output.has_body() {
+                                axum::response::IntoResponse::into_response(status)
+                            } else {
+                                match explicit_error_http::encode_error_body(&body) {
+                                    Some((content_type, bytes)) => axum::response::IntoResponse::into_response((
+                                        status,
+                                        [(axum::http::header::CONTENT_TYPE, content_type)],
+                                        bytes,
+                                    )),
+                                    None => axum::response::IntoResponse::into_response((
+                                        status,
+                                        [(axum::http::header::CONTENT_TYPE, d.output.content_type())],
+                                        explicit_error_http::json_error_body(&body),
+                                    )),
+                                }
+                            };
+
+                            if let Some(headers) = &d.output.headers {
+                                response.headers_mut().extend(headers.as_ref().clone());
+                            }
+
+                            match &d.output.trailers {
+                                Some(trailers) => {
+                                    let (parts, body) = response.into_parts();
+                                    axum::response::Response::from_parts(
+                                        parts,
+                                        axum::body::Body::new(explicit_error_http::axum::with_trailers(body, trailers.as_ref().clone())),
+                                    )
+                                }
+                                None => response,
+                            }
+                        }
+                        explicit_error_http::Error::Fault(b) => {
+                            let context = explicit_error_http::ResponseContext::current();
+                            let body = explicit_error_http::public_fault_body::<Self>(b, context.as_ref());
+
+                            match explicit_error_http::encode_error_body(&body) {
+                                Some((content_type, bytes)) => axum::response::IntoResponse::into_response((
+                                    axum::http::StatusCode::INTERNAL_SERVER_ERROR,
+                                    [(axum::http::header::CONTENT_TYPE, content_type)],
+                                    bytes,
+                                )),
+                                None => axum::response::IntoResponse::into_response((
+                                    axum::http::StatusCode::INTERNAL_SERVER_ERROR,
+                                    [(axum::http::header::CONTENT_TYPE, explicit_error_http::error_content_type())],
+                                    explicit_error_http::json_error_body(&body),
+                                )),
+                            }
+                        }
                     }
                 }
             }
@@ -48,7 +139,7 @@ pub fn derive(input: syn::DeriveInput) -> TokenStream {
 
     //TODO: re-implement source attribute like ThisError
 
-    quote! {
+    Ok(quote! {
         #axum
 
         #actix
@@ -62,7 +153,10 @@ pub fn derive(input: syn::DeriveInput) -> TokenStream {
 
         #[automatically_derived]
         impl #impl_generics From<explicit_error_http::Error> for #ident #ty_generics #where_clause {
-            fn from(value: explicit_error_http::Error) -> Self {
+            fn from(mut value: explicit_error_http::Error) -> Self {
+                if let explicit_error_http::Error::Domain(d) = &mut value {
+                    <Self as explicit_error_http::HandlerError>::redact_domain_error(d);
+                }
                 <Self as explicit_error_http::HandlerError>::from_error(value)
             }
         }
@@ -70,14 +164,14 @@ pub fn derive(input: syn::DeriveInput) -> TokenStream {
         #[automatically_derived]
         impl #impl_generics From<explicit_error_http::HttpError> for #ident #ty_generics #where_clause {
             fn from(value: explicit_error_http::HttpError) -> Self {
-                <Self as explicit_error_http::HandlerError>::from_error(value.into())
+                <Self as From<explicit_error_http::Error>>::from(value.into())
             }
         }
 
         #[automatically_derived]
         impl #impl_generics From<explicit_error_http::DomainError> for #ident #ty_generics #where_clause {
             fn from(value: explicit_error_http::DomainError) -> Self {
-                <Self as explicit_error_http::HandlerError>::from_error(value.into())
+                <Self as From<explicit_error_http::Error>>::from(value.into())
             }
         }
 
@@ -101,5 +195,140 @@ pub fn derive(input: syn::DeriveInput) -> TokenStream {
                 Some(<Self as explicit_error_http::HandlerError>::error(self))
             }
         }
+    })
+}
+
+/// Enums delegate the response of each variant to its single field. The variant marked `#[explicit_error]`
+/// wraps a type deriving HandlerErrorHelpers, conversions from errors target it.
+fn derive_enum(input: &syn::DeriveInput, data: &syn::DataEnum) -> syn::Result<TokenStream> {
+    let ident = &input.ident;
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+
+    let mut patterns = Vec::new();
+    let mut marked = None;
+    for variant in &data.variants {
+        let mut fields = variant.fields.iter();
+        let field = match (fields.next(), fields.next()) {
+            (Some(field), None) => field,
+            _ => {
+                return Err(syn::Error::new_spanned(
+                    variant,
+                    "HandlerErrorHelpers requires each variant of an enum to have exactly one field",
+                ));
+            }
+        };
+
+        let variant_ident = &variant.ident;
+        let member = match &field.ident {
+            Some(ident) => Member::Named(ident.clone()),
+            None => Member::Unnamed(syn::Index::from(0)),
+        };
+
+        if let Some(attr) = variant
+            .attrs
+            .iter()
+            .find(|attr| attr.path().is_ident("explicit_error"))
+        {
+            attr.meta.require_path_only()?;
+            if marked.is_some() {
+                return Err(syn::Error::new_spanned(
+                    attr,
+                    "#[explicit_error] must be set on only one variant",
+                ));
+            }
+            marked = Some((quote! { #ident::#variant_ident }, member.clone(), &field.ty));
+        }
+
+        patterns.push(quote! { #ident::#variant_ident { #member: inner } });
     }
+
+    let Some((marked, member, ty)) = marked else {
+        return Err(syn::Error::new_spanned(
+            ident,
+            "HandlerErrorHelpers on an enum requires the variant wrapping a HandlerError to be marked #[explicit_error]",
+        ));
+    };
+
+    let actix = if cfg!(feature = "actix-web") {
+        quote! {
+            #[automatically_derived]
+            impl #impl_generics actix_web::ResponseError for #ident #ty_generics #where_clause {
+                fn error_response(&self) -> actix_web::HttpResponse {
+                    match self {
+                        #(#patterns => actix_web::ResponseError::error_response(inner),)*
+                    }
+                }
+            }
+        }
+    } else {
+        quote! {}
+    };
+
+    let axum = if cfg!(feature = "axum") {
+        quote! {
+            #[automatically_derived]
+            impl #impl_generics axum::response::IntoResponse for #ident #ty_generics #where_clause {
+                fn into_response(self) -> axum::response::Response {
+                    match self {
+                        #(#patterns => axum::response::IntoResponse::into_response(inner),)*
+                    }
+                }
+            }
+        }
+    } else {
+        quote! {}
+    };
+
+    let conversions = [
+        quote! { explicit_error_http::Fault },
+        quote! { explicit_error_http::Error },
+        quote! { explicit_error_http::HttpError },
+        quote! { explicit_error_http::DomainError },
+    ]
+    .into_iter()
+    .map(|from| {
+        quote! {
+            #[automatically_derived]
+            impl #impl_generics From<#from> for #ident #ty_generics #where_clause {
+                fn from(value: #from) -> Self {
+                    #marked { #member: <#ty as From<#from>>::from(value) }
+                }
+            }
+        }
+    });
+
+    Ok(quote! {
+        #axum
+
+        #actix
+
+        #(#conversions)*
+
+        #[automatically_derived]
+        impl #impl_generics std::fmt::Display for #ident #ty_generics #where_clause {
+            fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                match self {
+                    #(#patterns => std::fmt::Display::fmt(inner, f),)*
+                }
+            }
+        }
+
+        #[automatically_derived]
+        impl #impl_generics std::fmt::Debug for #ident #ty_generics #where_clause {
+            fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                match self {
+                    #(#patterns => std::fmt::Debug::fmt(inner, f),)*
+                }
+            }
+        }
+
+        #[automatically_derived]
+        impl #impl_generics std::error::Error for #ident #ty_generics #where_clause {
+            fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+                match self {
+                    #(#patterns => std::error::Error::source(inner),)*
+                }
+            }
+        }
+    })
 }
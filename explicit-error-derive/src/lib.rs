@@ -7,6 +7,8 @@ extern crate syn;
 mod domain;
 #[cfg(feature = "http")]
 mod http;
+#[cfg(feature = "http")]
+mod problem_type;
 
 #[cfg(any(feature = "http", feature = "exit"))]
 use proc_macro::TokenStream;
@@ -14,28 +16,41 @@ use proc_macro::TokenStream;
 use syn::{DeriveInput, parse_macro_input};
 
 #[cfg(feature = "http")]
-#[proc_macro_derive(HttpError)]
+#[proc_macro_derive(HttpError, attributes(explicit_error, source, from))]
 pub fn derive_http_error(input: TokenStream) -> TokenStream {
     let input = parse_macro_input!(input as DeriveInput);
 
-    domain::derive(input, "explicit_error_http")
+    domain::derive(input, "explicit_error_http", "HttpError")
         .unwrap_or_else(syn::Error::into_compile_error)
         .into()
 }
 
 #[cfg(feature = "exit")]
-#[proc_macro_derive(ExitError)]
+#[proc_macro_derive(ExitError, attributes(explicit_error, source, from, exit))]
 pub fn derive_bin_error(input: TokenStream) -> TokenStream {
     let input = parse_macro_input!(input as DeriveInput);
 
-    domain::derive(input, "explicit_error_exit")
+    domain::derive(input, "explicit_error_exit", "ExitError")
         .unwrap_or_else(syn::Error::into_compile_error)
         .into()
 }
 
 #[cfg(feature = "http")]
-#[proc_macro_derive(HandlerErrorHelpers)]
+#[proc_macro_derive(HandlerErrorHelpers, attributes(explicit_error))]
 pub fn derive_actix_handler_error(input: TokenStream) -> TokenStream {
     let input = parse_macro_input!(input as DeriveInput);
-    http::derive(input).into()
+
+    http::derive(input)
+        .unwrap_or_else(syn::Error::into_compile_error)
+        .into()
+}
+
+#[cfg(feature = "http")]
+#[proc_macro]
+pub fn problem_type(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as problem_type::ProblemType);
+
+    problem_type::expand(input)
+        .unwrap_or_else(syn::Error::into_compile_error)
+        .into()
 }
@@ -0,0 +1,56 @@
+use proc_macro2::TokenStream;
+use quote::quote;
+use syn::{
+    Token,
+    parse::{Parse, ParseStream},
+};
+
+/// Input of `problem_type!("/errors/not-found")` or `problem_type!("/errors/not-found", REGISTRY)`.
+pub struct ProblemType {
+    r#type: syn::LitStr,
+    registry: Option<syn::Path>,
+}
+
+impl Parse for ProblemType {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let r#type = input.parse()?;
+        let registry = if input.parse::<Option<Token![,]>>()?.is_some() && !input.is_empty() {
+            Some(input.parse()?)
+        } else {
+            None
+        };
+        input.parse::<Option<Token![,]>>()?;
+
+        Ok(Self { r#type, registry })
+    }
+}
+
+pub fn expand(input: ProblemType) -> syn::Result<TokenStream> {
+    let ProblemType { r#type, registry } = input;
+
+    if let Err(e) = r#type.value().parse::<::http::Uri>() {
+        return Err(syn::Error::new_spanned(
+            r#type,
+            format!("invalid problem type URI: {e}"),
+        ));
+    }
+
+    let registered = registry.map(|registry| {
+        let message = format!("problem type `{}` is not registered", r#type.value());
+        quote! {
+            const {
+                assert!(
+                    explicit_error_http::problem_type_registered(#registry, #r#type),
+                    #message
+                )
+            };
+        }
+    });
+
+    Ok(quote! {
+        {
+            #registered
+            explicit_error_http::problem_type_uri(#r#type)
+        }
+    })
+}
@@ -1,5 +1,5 @@
 use crate::{Error, ExitError};
-use explicit_error::{Domain, Error as ExplicitError};
+use explicit_error::{Domain, Extensions, Retryable};
 use std::{error::Error as StdError, fmt::Display};
 
 /// Wrapper for errors that are not a [Fault](explicit_error::Fault). It is used as the [explicit_error::Error::Domain] variant generic type.
@@ -92,6 +92,25 @@ pub struct DomainError {
     pub source: Option<Box<dyn StdError + Send + Sync>>,
 }
 
+impl DomainError {
+    /// Iterate over the [DomainError] followed by its chain of [sources](StdError::source), see [errors_chain](explicit_error::errors_chain).
+    pub fn chain(&self) -> impl Iterator<Item = &(dyn StdError + 'static)> {
+        explicit_error::errors_chain(self)
+    }
+}
+
+/// Two domain errors are equal if their [ExitError]s are equal and their sources debug format the same,
+/// see [sources_eq](explicit_error::sources_eq). Extensions are ignored.
+impl PartialEq for DomainError {
+    fn eq(&self, other: &Self) -> bool {
+        self.output == other.output
+            && explicit_error::sources_eq(
+                self.source.as_deref().map(|s| s as _),
+                other.source.as_deref().map(|s| s as _),
+            )
+    }
+}
+
 impl Display for DomainError {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(f, "{}", self.output)
@@ -104,9 +123,10 @@ impl StdError for DomainError {
     }
 }
 
-impl From<DomainError> for ExplicitError<DomainError> {
-    fn from(value: DomainError) -> Self {
-        Error::Domain(Box::new(value))
+/// Never retryable, exit errors end the process.
+impl Retryable for DomainError {
+    fn is_retryable(&self) -> bool {
+        false
     }
 }
 
@@ -119,10 +139,27 @@ impl Domain for DomainError {
         self.output.context.as_deref()
     }
 
+    fn context_mut(&mut self) -> Option<&mut Option<String>> {
+        Some(&mut self.output.context)
+    }
+
     fn with_context(mut self, context: impl Display) -> Self {
         self.output = self.output.with_context(context);
         self
     }
+
+    fn extensions(&self) -> &Extensions {
+        &self.output.extensions
+    }
+
+    fn extensions_mut(&mut self) -> Option<&mut Extensions> {
+        Some(&mut self.output.extensions)
+    }
+
+    /// The message of the [ExitError]
+    fn user_message(&self) -> Option<&str> {
+        Some(&self.output.message)
+    }
 }
 
 /// Internally used by [ExitError](crate::derive::ExitError) derive.
@@ -157,6 +194,12 @@ where
     /// Err::<(), _>(Fault::new()).with_context("Foo bar");
     /// ```
     fn with_context(self, context: impl std::fmt::Display) -> std::result::Result<T, DomainError>;
+
+    /// Add a context, if there is one, to an error that convert to [Error] wrapped in a [Result::Err]
+    fn with_context_opt(
+        self,
+        context: Option<impl std::fmt::Display>,
+    ) -> std::result::Result<T, DomainError>;
 }
 
 impl<T, D> ResultDomainWithContext<T, D> for std::result::Result<T, D>
@@ -170,4 +213,11 @@ where
             Err(e) => Err(e.to_domain_error().with_context(context)),
         }
     }
+
+    fn with_context_opt(
+        self,
+        context: Option<impl std::fmt::Display>,
+    ) -> std::result::Result<T, DomainError> {
+        self.map_err(|e| e.to_domain_error().with_context_opt(context))
+    }
 }
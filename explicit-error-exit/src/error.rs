@@ -1,6 +1,7 @@
-use std::{fmt::Display, process::ExitCode};
+use std::{fmt::Display, process::ExitCode, sync::Arc};
 
-use crate::{DomainError, Error};
+use crate::{DomainError, Error, Fault};
+use explicit_error::Extensions;
 
 /// Representation of errors that ends a process/program.
 ///
@@ -48,11 +49,64 @@ use crate::{DomainError, Error};
 ///     Ok(())
 /// }
 /// ```
+///
+/// Both [ExitError] and domain errors that derive [ExitError](crate::derive::ExitError) are converted by `?` directly.
+/// ```rust
+/// # use explicit_error_exit::{ExitError, Result, derive::ExitError};
+/// # use std::process::ExitCode;
+/// # #[derive(ExitError, Debug)]
+/// # enum MyError {
+/// #     Foo,
+/// # }
+/// # impl From<&MyError> for ExitError {
+/// #     fn from(_: &MyError) -> Self {
+/// #         ExitError::new("Foo went wrong", ExitCode::from(43))
+/// #     }
+/// # }
+/// fn inline() -> Result<()> {
+///     Err(ExitError::new("Something went wrong because ..", ExitCode::from(42)))?;
+///     Ok(())
+/// }
+///
+/// fn derived() -> Result<()> {
+///     Err(MyError::Foo)?;
+///     Ok(())
+/// }
+///
+/// assert_eq!(inline().unwrap_err().unwrap().output.exit_code(), ExitCode::from(42));
+/// assert_eq!(derived().unwrap_err().unwrap().output.exit_code(), ExitCode::from(43));
+/// ```
+///
+/// It is `#[non_exhaustive]` so fields can be added without breaking changes: generate it with [new](ExitError::new)
+/// and use the accessors.
+/// ```rust
+/// use explicit_error_exit::ExitError;
+/// use std::process::ExitCode;
+///
+/// let mut error = ExitError::new("Something went wrong because ..", ExitCode::from(42))
+///     .with_context("The reason why it went wrong")
+///     .with_code("CONFIG_INVALID");
+/// error.set_message("Configuration is invalid");
+/// error.set_exit_code(ExitCode::from(78));
+///
+/// assert_eq!(error.message(), "Configuration is invalid");
+/// assert_eq!(error.exit_code(), ExitCode::from(78));
+/// assert_eq!(error.context(), Some("The reason why it went wrong"));
+/// assert_eq!(error.code(), Some("CONFIG_INVALID"));
+/// assert!(!error.is_fatal());
+///
+/// error.set_context(None::<String>);
+/// assert_eq!(error.context(), None);
+/// ```
 #[derive(Debug)]
+#[non_exhaustive]
 pub struct ExitError {
     pub message: String,
     pub exit_code: ExitCode,
     pub context: Option<String>,
+    pub fatal: bool,
+    pub code: Option<&'static str>,
+    pub(crate) extensions: Extensions,
 }
 
 impl ExitError {
@@ -73,6 +127,9 @@ impl ExitError {
             message: message.to_string(),
             exit_code,
             context: None,
+            fatal: false,
+            code: None,
+            extensions: Extensions::new(),
         }
     }
 
@@ -92,21 +149,155 @@ impl ExitError {
         self.context = Some(context.to_string());
         self
     }
+
+    /// Add a context to an [ExitError] if there is one, override if one was set.
+    pub fn with_context_opt(self, context: Option<impl Display>) -> Self {
+        match context {
+            Some(context) => self.with_context(context),
+            None => self,
+        }
+    }
+
+    /// Add a stable machine-readable code to an [ExitError], override if one was set. Unlike the exit code,
+    /// which is consumed by the OS, it appears in [Display] to be used in structured logs and documentation.
+    /// # Examples
+    /// ```rust
+    /// use explicit_error_exit::{Error, ExitError};
+    /// use std::process::ExitCode;
+    ///
+    /// let error = Error::from(
+    ///     ExitError::new("Configuration is invalid", ExitCode::from(78)).with_code("CONFIG_INVALID"),
+    /// );
+    /// assert_eq!(error.to_string(), "CONFIG_INVALID: Configuration is invalid");
+    /// assert_eq!(error.unwrap().output.code, Some("CONFIG_INVALID"));
+    /// ```
+    pub fn with_code(mut self, code: &'static str) -> Self {
+        self.code = Some(code);
+        self
+    }
+
+    /// Return the message
+    pub fn message(&self) -> &str {
+        &self.message
+    }
+
+    /// Replace the message
+    pub fn set_message(&mut self, message: impl Display) {
+        self.message = message.to_string();
+    }
+
+    /// Return the exit code
+    pub fn exit_code(&self) -> ExitCode {
+        self.exit_code
+    }
+
+    /// Replace the exit code
+    pub fn set_exit_code(&mut self, exit_code: ExitCode) {
+        self.exit_code = exit_code;
+    }
+
+    /// Return the context
+    pub fn context(&self) -> Option<&str> {
+        self.context.as_deref()
+    }
+
+    /// Set or remove the context
+    pub fn set_context(&mut self, context: Option<impl Display>) {
+        self.context = context.map(|context| context.to_string());
+    }
+
+    /// Return the code set with [with_code](ExitError::with_code)
+    pub fn code(&self) -> Option<&'static str> {
+        self.code
+    }
+
+    /// Return true if the [ExitError] is marked [fatal](ExitError::fatal)
+    pub fn is_fatal(&self) -> bool {
+        self.fatal
+    }
+
+    /// Mark the [ExitError] as fatal: the process is aborted with [std::process::abort] instead of exiting with its exit code
+    /// when the error is reported, see [MainError](crate::MainError). The report is printed on stderr beforehand.
+    ///
+    /// Use it only for errors revealing a corrupted state (eg: broken invariant, memory corruption) where unwinding
+    /// and running destructors is unsafe. Aborting does not flush buffers nor run cleanup code.
+    /// # Examples
+    /// ```rust
+    /// use explicit_error_exit::ExitError;
+    /// use std::process::ExitCode;
+    ///
+    /// ExitError::new(
+    ///     "Journal is corrupted",
+    ///     ExitCode::FAILURE
+    /// ).fatal();
+    /// ```
+    pub fn fatal(mut self) -> Self {
+        self.fatal = true;
+        self
+    }
+}
+
+struct FatalMarker;
+
+/// Mark a [Fault] as fatal, see [ExitError::fatal] for when to use it.
+///
+/// To use this trait on [Fault] import the prelude `use explicit_error_exit::prelude::*`
+pub trait FatalFault {
+    /// Mark the [Fault] as fatal: the process is aborted with [std::process::abort]
+    /// when it is reported, see [MainError](crate::MainError). The report is printed on stderr beforehand.
+    /// # Examples
+    /// ```rust
+    /// # use explicit_error_exit::{prelude::*, Fault, Result};
+    /// fn check_invariant(len: usize, capacity: usize) -> Result<()> {
+    ///     if len > capacity {
+    ///         Err(Fault::new().with_context("Buffer length exceeds its capacity").fatal())?;
+    ///     }
+    ///     Ok(())
+    /// }
+    /// ```
+    fn fatal(self) -> Self;
+
+    /// Return true if the [Fault] has been marked as fatal
+    fn is_fatal(&self) -> bool;
+}
+
+impl FatalFault for Fault {
+    fn fatal(self) -> Self {
+        self.with_extension(Arc::new(FatalMarker))
+    }
+
+    fn is_fatal(&self) -> bool {
+        self.extension::<FatalMarker>().is_some()
+    }
 }
 
 impl std::error::Error for ExitError {}
 
 impl Display for ExitError {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        write!(f, "{}", self.message)
+        match self.code {
+            Some(code) => write!(f, "{code}: {}", self.message),
+            None => write!(f, "{}", self.message),
+        }
+    }
+}
+
+/// Extensions are ignored.
+impl PartialEq for ExitError {
+    fn eq(&self, other: &Self) -> bool {
+        self.message == other.message
+            && self.exit_code == other.exit_code
+            && self.context == other.context
+            && self.fatal == other.fatal
+            && self.code == other.code
     }
 }
 
 impl From<ExitError> for Error {
     fn from(value: ExitError) -> Self {
-        Error::Domain(Box::new(DomainError {
+        Error::new_domain(DomainError {
             output: value,
             source: None,
-        }))
+        })
     }
 }
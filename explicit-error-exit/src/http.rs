@@ -0,0 +1,82 @@
+use crate::{DomainError, Error, ExitError};
+use explicit_error::Domain;
+use std::process::ExitCode;
+
+/// Convert an [explicit_error_http::Error] to an [Error], eg: in a binary serving HTTP that must exit when a boot step fails.
+///
+/// The [ExitCode] is deduced from the HTTP status code of domain errors, following `sysexits.h` conventions, and the message
+/// is the [user_message](explicit_error_http::HttpError::user_message) of the [HttpError](explicit_error_http::HttpError) or
+/// else its status code. The context, source and extensions are preserved.
+/// Faults are kept as is and exit with [ExitCode::FAILURE].
+/// # Examples
+/// ```rust
+/// # use explicit_error_exit::{Error, from_http_error};
+/// # use problem_details::ProblemDetails;
+/// fn boot() -> Result<(), explicit_error_http::Error> {
+///     Err(explicit_error_http::HttpError::not_found(
+///         ProblemDetails::new().with_title("Tenant not found"),
+///     ))?;
+///     Ok(())
+/// }
+///
+/// fn main_logic() -> Result<(), Error> {
+///     boot().map_err(from_http_error)?;
+///     Ok(())
+/// }
+///
+/// let error = main_logic().unwrap_err().unwrap();
+/// assert_eq!(error.output.message, "Tenant not found");
+/// assert_eq!(error.output.exit_code, std::process::ExitCode::from(66));
+/// ```
+pub fn from_http_error(error: explicit_error_http::Error) -> Error {
+    match error {
+        explicit_error::Error::Domain(domain) => Error::Domain(Box::new((*domain).into())),
+        explicit_error::Error::Fault(fault) => Error::Fault(fault),
+    }
+}
+
+impl From<explicit_error_http::DomainError> for DomainError {
+    fn from(value: explicit_error_http::DomainError) -> Self {
+        let extensions = value.extensions().clone();
+        let mut output: ExitError = value.output.into();
+        output.extensions = extensions;
+
+        Self {
+            output,
+            source: value.source,
+        }
+    }
+}
+
+impl From<explicit_error_http::HttpError> for ExitError {
+    fn from(value: explicit_error_http::HttpError) -> Self {
+        let message = value
+            .user_message()
+            .map(ToString::to_string)
+            .unwrap_or_else(|| value.http_status_code.to_string());
+
+        let error = ExitError::new(message, exit_code(value.http_status_code.as_u16()));
+        match value.context {
+            Some(context) => error.with_context(context),
+            None => error,
+        }
+    }
+}
+
+fn exit_code(http_status_code: u16) -> ExitCode {
+    ExitCode::from(match http_status_code {
+        // EX_DATAERR
+        400 | 409 | 422 => 65,
+        // EX_NOINPUT
+        404 | 410 => 66,
+        // EX_NOPERM
+        401 | 403 => 77,
+        // EX_TEMPFAIL
+        408 | 429 | 503 | 504 => 75,
+        // EX_UNAVAILABLE
+        502 => 69,
+        // EX_SOFTWARE
+        500..=599 => 70,
+        _ => 1,
+    })
+}
@@ -80,7 +80,51 @@
 //! }
 //! ```
 //!
-//! Note: The [ExitError](derive::ExitError) derive implements the conversion to [Error], the impl of [Display](std::fmt::Display) and [std::error::Error].
+//! Note: The [ExitError](derive::ExitError) derive implements the conversion to [Error] and back with [TryFrom], the impl of [Display](std::fmt::Display) and [std::error::Error].
+//!
+//! Like with [thiserror](https://docs.rs/thiserror), a field marked `#[source]` is returned by [source](std::error::Error::source).
+//! `#[from]` does the same and implements the conversion from the type of the field, which must be the only one of the variant.
+//!
+//! ```rust
+//! # use explicit_error_exit::{ExitError, derive::ExitError};
+//! # use std::process::ExitCode;
+//! #[derive(ExitError, Debug)]
+//! enum ConfigError {
+//!     Read(#[from] std::io::Error),
+//!     Parse {
+//!         #[source]
+//!         source: std::num::ParseIntError,
+//!         line: usize,
+//!     },
+//! }
+//! # impl From<&ConfigError> for ExitError {
+//! #     fn from(_: &ConfigError) -> Self {
+//! #         ExitError::new("Invalid configuration", ExitCode::from(78))
+//! #     }
+//! # }
+//! ```
+//!
+//! To document exit codes, eg: in a man page, mark the struct or every variant with `#[exit(code = .., doc = "..")]`.
+//! The derive generates `exit_code_table()`, listing each code with its description, and `exit_code(&self)` to use in the conversion so the docs and the code can't drift.
+//!
+//! ```rust
+//! # use explicit_error_exit::{ExitError, derive::ExitError};
+//! #[derive(ExitError, Debug)]
+//! enum ImportError {
+//!     #[exit(code = 65, doc = "data format error")]
+//!     Format,
+//!     #[exit(code = 66, doc = "cannot open input")]
+//!     Input,
+//! }
+//!
+//! impl From<&ImportError> for ExitError {
+//!     fn from(value: &ImportError) -> Self {
+//!         ExitError::new("Import failed", value.exit_code())
+//!     }
+//! }
+//!
+//! assert_eq!(ImportError::exit_code_table(), &[(65, "data format error"), (66, "cannot open input")]);
+//! ```
 //!
 //! # Pattern matching
 //!
@@ -126,21 +170,31 @@
 //! Note: under the hood [try_map_on_source](explicit_error::ResultError::try_map_on_source) perform some downcasting.
 mod domain;
 mod error;
+#[cfg(feature = "http")]
+mod http;
 
-use std::process::{ExitCode, Termination};
+use std::{
+    io::Write,
+    process::{ExitCode, Termination},
+};
 
 pub use domain::*;
 pub use error::*;
+#[cfg(feature = "http")]
+pub use http::*;
 
 pub type Error = explicit_error::Error<DomainError>;
 pub type Result<T> = std::result::Result<T, Error>;
 pub type MainResult = std::result::Result<(), MainError>;
 
 /// Re-import from [explicit_error] crate.
-pub use explicit_error::Fault;
+pub use explicit_error::{Extensions, Fault};
+
+#[allow(deprecated)]
+pub use explicit_error::Bug;
 
 pub mod prelude {
-    pub use crate::ResultDomainWithContext;
+    pub use crate::{ErrorReport, FatalFault, ResultDomainWithContext};
     pub use explicit_error::prelude::*;
 }
 
@@ -151,10 +205,48 @@ pub mod derive {
 /// Crate's main function returned type. It implements [Termination] to properly format console error.
 ///
 /// To have your own termination custom logic, you can re-implement an equivalent of [MainError]. Have a look at source it is straightforward.
+///
+/// Errors marked as fatal with [ExitError::fatal] or [FatalFault::fatal] abort the process, after printing their report on stderr,
+/// when they are reported: by the [Termination] implementation of a [MainError] returned by `main`, or by [ErrorReport::report].
+/// Converting them to [MainError] has no side effect, and a [MainResult] returned by `main` does not report its error this way:
+/// it is printed and the process exits with [ExitCode::FAILURE].
+/// # Examples
+/// ```rust,no_run
+/// use explicit_error_exit::{prelude::*, ExitError, Result};
+/// use std::process::ExitCode;
+///
+/// fn check_journal() -> Result<()> {
+///     Err(ExitError::new("Journal is corrupted", ExitCode::FAILURE).fatal())?;
+///     Ok(())
+/// }
+///
+/// fn main() -> ExitCode {
+///     match check_journal() {
+///         Ok(()) => ExitCode::SUCCESS,
+///         Err(e) => e.report(), // Aborts the process
+///     }
+/// }
+/// ```
 pub struct MainError(Error);
 
+impl MainError {
+    fn abort_if_fatal(&self) {
+        let fatal = match &self.0 {
+            explicit_error::Error::Domain(domain) => domain.output.fatal,
+            explicit_error::Error::Fault(fault) => fault.is_fatal(),
+        };
+
+        if fatal {
+            eprintln!("Error: {self:?}");
+            std::process::abort();
+        }
+    }
+}
+
 impl Termination for MainError {
     fn report(self) -> std::process::ExitCode {
+        self.abort_if_fatal();
+
         match self.0 {
             explicit_error::Error::Domain(domain) => domain.output.exit_code,
             explicit_error::Error::Fault(_) => ExitCode::FAILURE,
@@ -162,6 +254,103 @@ impl Termination for MainError {
     }
 }
 
+/// Explicit counterpart of the [Termination] implementation of [MainError], for programs that want to control when and where the error is printed.
+///
+/// To use this trait on [Error] import the prelude `use explicit_error_exit::prelude::*`
+pub trait ErrorReport {
+    /// Print the error on stderr and return its [ExitCode]. [Fault]'s backtraces are printed only with `debug_assertions`.
+    ///
+    /// Errors marked as fatal with [ExitError::fatal] or [FatalFault::fatal] abort the process after being printed.
+    /// # Examples
+    /// ```rust,no_run
+    /// use explicit_error_exit::{prelude::*, ExitError, Result};
+    /// use std::process::ExitCode;
+    ///
+    /// fn business_logic() -> Result<()> {
+    ///     Err(ExitError::new("Something went wrong because ..", ExitCode::from(42)))?;
+    ///     Ok(())
+    /// }
+    ///
+    /// fn main() -> ExitCode {
+    ///     match business_logic() {
+    ///         Ok(()) => ExitCode::SUCCESS,
+    ///         Err(e) => e.report(),
+    ///     }
+    /// }
+    /// ```
+    fn report(self) -> ExitCode;
+
+    /// Same as [report](ErrorReport::report) but print the error to `writer`.
+    /// # Examples
+    /// ```rust
+    /// use explicit_error_exit::{prelude::*, Error, ExitError, Fault};
+    /// use std::process::ExitCode;
+    ///
+    /// let mut stderr = Vec::new();
+    /// let exit_code = Error::from(ExitError::new("Something went wrong because ..", ExitCode::from(42)))
+    ///     .report_to(&mut stderr);
+    /// assert_eq!(exit_code, ExitCode::from(42));
+    /// assert_eq!(String::from_utf8(stderr).unwrap(), "Error: Something went wrong because ..\n");
+    ///
+    /// let mut stderr = Vec::new();
+    /// let exit_code = Error::from(Fault::new().with_context("Config file is missing")).report_to(&mut stderr);
+    /// assert_eq!(exit_code, ExitCode::FAILURE);
+    /// assert!(String::from_utf8(stderr).unwrap().ends_with("Context: Config file is missing\n"));
+    /// ```
+    fn report_to(self, writer: &mut dyn Write) -> ExitCode;
+}
+
+impl ErrorReport for Error {
+    fn report(self) -> ExitCode {
+        self.report_to(&mut std::io::stderr())
+    }
+
+    fn report_to(self, writer: &mut dyn Write) -> ExitCode {
+        let (report, fatal, exit_code) = match &self {
+            explicit_error::Error::Domain(domain) => (
+                domain.output.to_string(),
+                domain.output.fatal,
+                domain.output.exit_code,
+            ),
+            explicit_error::Error::Fault(fault) => {
+                (fault_report(fault), fault.is_fatal(), ExitCode::FAILURE)
+            }
+        };
+
+        let _ = writeln!(writer, "Error: {}", report.trim_end());
+
+        if fatal {
+            let _ = writer.flush();
+            std::process::abort();
+        }
+
+        exit_code
+    }
+}
+
+#[cfg(debug_assertions)]
+fn fault_report(fault: &Fault) -> String {
+    fault.to_string()
+}
+
+#[cfg(not(debug_assertions))]
+fn fault_report(fault: &Fault) -> String {
+    let mut report = String::new();
+    if let Some(context) = fault.context() {
+        report.push_str(&format!("Context: {context}\n"));
+    }
+    if let Some(source) = &fault.source {
+        report.push_str(&format!(
+            "Source: {}\n",
+            explicit_error::redact(&format!(
+                "{}, {source}",
+                explicit_error::errors_chain_debug(source.as_ref())
+            ))
+        ));
+    }
+    report
+}
+
 impl std::fmt::Debug for MainError {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(f, "{}", self.0)
@@ -176,18 +365,18 @@ impl From<Error> for MainError {
 
 impl From<DomainError> for MainError {
     fn from(value: DomainError) -> Self {
-        Self(value.into())
+        Self::from(Error::from(value))
     }
 }
 
 impl From<ExitError> for MainError {
     fn from(value: ExitError) -> Self {
-        Self(value.into())
+        Self::from(Error::from(value))
     }
 }
 
 impl From<Fault> for MainError {
     fn from(value: Fault) -> Self {
-        Self(value.into())
+        Self::from(Error::from(value))
     }
 }
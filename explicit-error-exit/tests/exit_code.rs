@@ -0,0 +1,67 @@
+use explicit_error_exit::{Error, ExitError, derive::ExitError};
+use std::process::ExitCode;
+
+#[derive(ExitError, Debug)]
+enum ImportError {
+    #[exit(code = 65, doc = "data format error")]
+    Format { line: usize },
+    #[exit(code = 66, doc = "cannot open input")]
+    Input(#[source] std::io::Error),
+    #[exit(code = 78, doc = "configuration error")]
+    Config,
+}
+
+impl From<&ImportError> for ExitError {
+    fn from(value: &ImportError) -> Self {
+        match value {
+            ImportError::Format { line } => {
+                ExitError::new(format!("Invalid data at line {line}"), value.exit_code())
+            }
+            ImportError::Input(_) => ExitError::new("Cannot open the input", value.exit_code()),
+            ImportError::Config => ExitError::new("Invalid configuration", value.exit_code()),
+        }
+    }
+}
+
+#[derive(ExitError, Debug)]
+#[exit(code = 75, doc = "temporary failure, retry later")]
+struct LockError;
+
+impl From<&LockError> for ExitError {
+    fn from(value: &LockError) -> Self {
+        ExitError::new("Already running", value.exit_code())
+    }
+}
+
+#[test]
+fn exit_code_table() {
+    assert_eq!(
+        ImportError::exit_code_table(),
+        &[
+            (65, "data format error"),
+            (66, "cannot open input"),
+            (78, "configuration error"),
+        ]
+    );
+    assert_eq!(
+        LockError::exit_code_table(),
+        &[(75, "temporary failure, retry later")]
+    );
+}
+
+#[test]
+fn exit_code() {
+    assert_eq!(
+        ImportError::Format { line: 1 }.exit_code(),
+        ExitCode::from(65)
+    );
+    assert_eq!(
+        ImportError::Input(std::io::Error::other("io")).exit_code(),
+        ExitCode::from(66)
+    );
+    assert_eq!(ImportError::Config.exit_code(), ExitCode::from(78));
+    assert_eq!(LockError.exit_code(), ExitCode::from(75));
+
+    let error = Error::from(ImportError::Config);
+    assert_eq!(error.unwrap().output.exit_code(), ExitCode::from(78));
+}
@@ -0,0 +1,15 @@
+use explicit_error_exit::{Error, ExitError, Fault, MainError, prelude::*};
+use std::process::ExitCode;
+
+#[test]
+fn convert_fatal_to_main_error() {
+    // Aborting would end the test binary
+    let _ = MainError::from(ExitError::new("Journal is corrupted", ExitCode::FAILURE).fatal());
+    let _ = MainError::from(Fault::new().fatal());
+
+    let error: Result<(), MainError> = (|| {
+        Err(Error::from(Fault::new().fatal()))?;
+        Ok(())
+    })();
+    assert!(error.is_err());
+}
@@ -0,0 +1,68 @@
+use explicit_error_exit::{Error, ExitError, derive::ExitError};
+use std::{error::Error as StdError, process::ExitCode};
+
+#[derive(ExitError, Debug)]
+enum ConfigError {
+    Read(#[from] std::io::Error),
+    Parse {
+        #[source]
+        source: std::num::ParseIntError,
+        line: usize,
+    },
+    Missing,
+}
+
+impl From<&ConfigError> for ExitError {
+    fn from(value: &ConfigError) -> Self {
+        match value {
+            ConfigError::Read(_) => {
+                ExitError::new("Cannot read the configuration", ExitCode::from(74))
+            }
+            ConfigError::Parse { line, .. } => {
+                ExitError::new(format!("Invalid value at line {line}"), ExitCode::from(78))
+            }
+            ConfigError::Missing => ExitError::new("Missing configuration", ExitCode::from(78)),
+        }
+    }
+}
+
+fn open_config() -> std::io::Result<String> {
+    Err(std::io::Error::other("permission denied"))
+}
+
+fn read_config() -> Result<(), Error> {
+    open_config().map_err(ConfigError::from)?;
+    Ok(())
+}
+
+#[test]
+fn from() {
+    let error = read_config().unwrap_err();
+
+    assert!(error.is_domain());
+    assert!(error.is_caused_by::<std::io::Error>());
+    assert_eq!(
+        error
+            .chain()
+            .find_map(|e| e.downcast_ref::<std::io::Error>())
+            .unwrap()
+            .to_string(),
+        "permission denied"
+    );
+
+    match error.downcast_source::<ConfigError>().unwrap() {
+        ConfigError::Read(io) => assert_eq!(io.to_string(), "permission denied"),
+        other => panic!("expected ConfigError::Read, got {other:?}"),
+    }
+}
+
+#[test]
+fn source() {
+    let error = ConfigError::Parse {
+        source: "x".parse::<u8>().unwrap_err(),
+        line: 3,
+    };
+    assert!(error.source().unwrap().is::<std::num::ParseIntError>());
+
+    assert!(ConfigError::Missing.source().is_none());
+}
@@ -0,0 +1,7 @@
+#[test]
+fn ui() {
+    let t = trybuild::TestCases::new();
+    t.pass("tests/ui/source.rs");
+    t.compile_fail("tests/ui/source_*_fail.rs");
+    t.compile_fail("tests/ui/exit_code_*_fail.rs");
+}
@@ -0,0 +1,18 @@
+use explicit_error_exit::{ExitError, derive::ExitError};
+use std::process::ExitCode;
+
+#[derive(ExitError, Debug)]
+enum ImportError {
+    #[exit(code = 65, doc = "data format error")]
+    Format,
+    #[exit(code = 78)]
+    Config,
+}
+
+impl From<&ImportError> for ExitError {
+    fn from(_: &ImportError) -> Self {
+        ExitError::new("Import failed", ExitCode::from(65))
+    }
+}
+
+fn main() {}
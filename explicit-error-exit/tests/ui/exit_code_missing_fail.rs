@@ -0,0 +1,17 @@
+use explicit_error_exit::{ExitError, derive::ExitError};
+use std::process::ExitCode;
+
+#[derive(ExitError, Debug)]
+enum ImportError {
+    #[exit(code = 65, doc = "data format error")]
+    Format,
+    Config,
+}
+
+impl From<&ImportError> for ExitError {
+    fn from(_: &ImportError) -> Self {
+        ExitError::new("Import failed", ExitCode::from(65))
+    }
+}
+
+fn main() {}
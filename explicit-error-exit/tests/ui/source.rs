@@ -0,0 +1,38 @@
+use explicit_error_exit::{ExitError, derive::ExitError};
+use std::process::ExitCode;
+
+#[derive(ExitError, Debug)]
+enum ConfigError {
+    Read(#[from] std::io::Error),
+    Parse {
+        #[source]
+        source: std::num::ParseIntError,
+        line: usize,
+    },
+    Missing,
+}
+
+impl From<&ConfigError> for ExitError {
+    fn from(_: &ConfigError) -> Self {
+        ExitError::new("Invalid configuration", ExitCode::from(78))
+    }
+}
+
+#[derive(ExitError, Debug)]
+struct LockError(#[source] std::io::Error, &'static str);
+
+impl From<&LockError> for ExitError {
+    fn from(value: &LockError) -> Self {
+        ExitError::new(value.1, ExitCode::from(75))
+    }
+}
+
+fn main() {
+    let _ = ConfigError::from(std::io::Error::other("io"));
+    let _ = ConfigError::Missing;
+    let _ = ConfigError::Parse {
+        source: "x".parse::<u8>().unwrap_err(),
+        line: 1,
+    };
+    let _ = LockError(std::io::Error::other("io"), "Already running");
+}
@@ -0,0 +1,18 @@
+use explicit_error_exit::{ExitError, derive::ExitError};
+use std::process::ExitCode;
+
+#[derive(ExitError, Debug)]
+struct CopyError {
+    #[source]
+    read: std::io::Error,
+    #[source]
+    write: std::io::Error,
+}
+
+impl From<&CopyError> for ExitError {
+    fn from(_: &CopyError) -> Self {
+        ExitError::new("Copy failed", ExitCode::from(74))
+    }
+}
+
+fn main() {}
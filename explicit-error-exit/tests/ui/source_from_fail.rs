@@ -0,0 +1,15 @@
+use explicit_error_exit::{ExitError, derive::ExitError};
+use std::process::ExitCode;
+
+#[derive(ExitError, Debug)]
+enum ConfigError {
+    Read(#[from] std::io::Error, String),
+}
+
+impl From<&ConfigError> for ExitError {
+    fn from(_: &ConfigError) -> Self {
+        ExitError::new("Invalid configuration", ExitCode::from(78))
+    }
+}
+
+fn main() {}
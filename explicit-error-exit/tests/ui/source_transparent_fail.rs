@@ -0,0 +1,17 @@
+use explicit_error_exit::{ExitError, derive::ExitError};
+use std::process::ExitCode;
+
+#[derive(ExitError, Debug)]
+struct Inner;
+
+impl From<&Inner> for ExitError {
+    fn from(_: &Inner) -> Self {
+        ExitError::new("Inner", ExitCode::from(1))
+    }
+}
+
+#[derive(ExitError, Debug)]
+#[explicit_error(transparent)]
+struct Outer(#[source] Inner);
+
+fn main() {}
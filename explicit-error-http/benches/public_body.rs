@@ -0,0 +1,74 @@
+//! Compare the cost of a JSON [Value](serde_json::Value) public body stored as is, with [HttpError::from_value],
+//! and behind the trait object used for other bodies.
+//!
+//! Run with `cargo bench -p explicit-error-http --bench public_body`.
+
+use explicit_error_http::HttpError;
+use http::StatusCode;
+use serde::Serialize;
+use std::{
+    alloc::{GlobalAlloc, Layout, System},
+    hint::black_box,
+    sync::atomic::{AtomicUsize, Ordering},
+    time::{Duration, Instant},
+};
+
+const ITERATIONS: usize = 1_000_000;
+
+static ALLOCATIONS: AtomicUsize = AtomicUsize::new(0);
+
+struct CountingAllocator;
+
+unsafe impl GlobalAlloc for CountingAllocator {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        ALLOCATIONS.fetch_add(1, Ordering::Relaxed);
+        unsafe { System.alloc(layout) }
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        unsafe { System.dealloc(ptr, layout) }
+    }
+}
+
+#[global_allocator]
+static ALLOCATOR: CountingAllocator = CountingAllocator;
+
+/// Same body than a [Value](serde_json::Value) but, not being one, it is stored behind the trait object
+#[derive(Serialize, Clone)]
+#[serde(transparent)]
+struct Boxed(serde_json::Value);
+
+fn body() -> serde_json::Value {
+    serde_json::Value::String("Email already used".to_string())
+}
+
+/// Return the allocations and the duration per iteration
+fn measure(op: impl Fn() -> HttpError) -> (f64, Duration) {
+    let allocations = ALLOCATIONS.load(Ordering::Relaxed);
+    let start = Instant::now();
+    for _ in 0..ITERATIONS {
+        black_box(op());
+    }
+    let elapsed = start.elapsed();
+
+    (
+        (ALLOCATIONS.load(Ordering::Relaxed) - allocations) as f64 / ITERATIONS as f64,
+        elapsed / ITERATIONS as u32,
+    )
+}
+
+fn main() {
+    for (name, op) in [
+        (
+            "trait object",
+            (&|| HttpError::new(StatusCode::CONFLICT, Boxed(black_box(body()))))
+                as &dyn Fn() -> HttpError,
+        ),
+        ("from_value", &|| {
+            HttpError::from_value(StatusCode::CONFLICT, black_box(body()))
+        }),
+    ] {
+        let (allocations, duration) = measure(op);
+        println!("{name:>12}: {allocations:.1} allocations, {duration:?} per error");
+    }
+}
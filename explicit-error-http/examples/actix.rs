@@ -1,8 +1,9 @@
 use actix_web::{App, HttpResponse, HttpServer, get};
 use env_logger::Env;
-use explicit_error_http::{Error, Fault, HandlerError, HttpError, derive::HandlerErrorHelpers};
-use http::StatusCode;
-use log::{debug, error};
+use explicit_error_http::{
+    Error, Fault, HandlerError, HttpError, Severity, derive::HandlerErrorHelpers,
+};
+use log::{debug, error, info, trace, warn};
 use problem_details::ProblemDetails;
 use serde::Serialize;
 
@@ -31,10 +32,12 @@ impl HandlerError for MyHandlerError {
     }
 
     fn domain_response(error: &explicit_error_http::DomainError) -> impl Serialize {
-        if error.output.http_status_code.as_u16() < 500 {
-            debug!("{error}");
-        } else {
-            error!("{error}");
+        match error.severity() {
+            Severity::Trace => trace!("{error}"),
+            Severity::Debug => debug!("{error}"),
+            Severity::Info => info!("{error}"),
+            Severity::Warn => warn!("{error}"),
+            Severity::Error => error!("{error}"),
         }
 
         error
@@ -52,11 +55,7 @@ async fn domain_error() -> Result<HttpResponse, MyHandlerError> {
 async fn fault_error() -> Result<HttpResponse, MyHandlerError> {
     service::fetch_entity()?;
 
-    Err(HttpError {
-        http_status_code: StatusCode::FORBIDDEN,
-        public: Box::new(""),
-        context: None,
-    })?;
+    Err(HttpError::forbidden(""))?;
 
     Ok(HttpResponse::Ok().finish())
 }
@@ -86,25 +85,19 @@ mod service {
     impl From<&MyDomainError> for HttpError {
         fn from(value: &MyDomainError) -> Self {
             match value {
-                MyDomainError::EntityNotFound(name) => HttpError {
-                    http_status_code: StatusCode::NOT_FOUND,
-                    public: Box::new(
-                        ProblemDetails::new()
-                            .with_type(http::Uri::from_static("/errors/entity/not-found"))
-                            .with_title("Article not found.")
-                            .with_detail(format!("Name: {name}")),
-                    ),
-                    context: None,
-                },
-                MyDomainError::Validation => HttpError {
-                    http_status_code: StatusCode::BAD_REQUEST,
-                    public: Box::new(
-                        ProblemDetails::new()
-                            .with_type(http::Uri::from_static("/errors/entity/validation"))
-                            .with_title("Data provided for the operation is incorrect."),
-                    ),
-                    context: None,
-                },
+                MyDomainError::EntityNotFound(name) => HttpError::new(
+                    StatusCode::NOT_FOUND,
+                    ProblemDetails::new()
+                        .with_type(http::Uri::from_static("/errors/entity/not-found"))
+                        .with_title("Article not found.")
+                        .with_detail(format!("Name: {name}")),
+                ),
+                MyDomainError::Validation => HttpError::new(
+                    StatusCode::BAD_REQUEST,
+                    ProblemDetails::new()
+                        .with_type(http::Uri::from_static("/errors/entity/validation"))
+                        .with_title("Data provided for the operation is incorrect."),
+                ),
             }
         }
     }
@@ -116,15 +109,13 @@ mod service {
 
     impl From<&SubDomainError> for HttpError {
         fn from(value: &SubDomainError) -> Self {
-            HttpError {
-                http_status_code: StatusCode::NOT_FOUND,
-                public: Box::new(
-                    ProblemDetails::new()
-                        .with_type(http::Uri::from_static("/errors/subdomain/x99"))
-                        .with_title(value.x99),
-                ),
-                context: Some("Some usefull info to debug".to_string()),
-            }
+            HttpError::new(
+                StatusCode::NOT_FOUND,
+                ProblemDetails::new()
+                    .with_type(http::Uri::from_static("/errors/subdomain/x99"))
+                    .with_title(value.x99),
+            )
+            .with_context("Some usefull info to debug")
         }
     }
 
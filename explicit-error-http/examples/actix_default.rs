@@ -0,0 +1,36 @@
+use actix_web::{App, HttpResponse, HttpServer, get};
+use env_logger::Env;
+use explicit_error_http::{DefaultHandlerError, HttpError, Result, prelude::*};
+use http::StatusCode;
+
+#[get("/domain")]
+async fn domain_error() -> std::result::Result<HttpResponse, DefaultHandlerError> {
+    Err(HttpError::new(StatusCode::NOT_FOUND, "Article not found."))?;
+
+    Ok(HttpResponse::Ok().finish())
+}
+
+#[get("/fault")]
+async fn fault_error() -> std::result::Result<HttpResponse, DefaultHandlerError> {
+    fetch_entity()?;
+
+    Ok(HttpResponse::Ok().finish())
+}
+
+fn fetch_entity() -> Result<()> {
+    Err(sqlx::Error::PoolTimedOut)
+        .or_fault()
+        .with_context("Usefull info to help debug")?;
+
+    Ok(())
+}
+
+#[actix_web::main]
+async fn main() -> std::io::Result<()> {
+    env_logger::init_from_env(Env::default().default_filter_or("info"));
+
+    HttpServer::new(|| App::new().service(domain_error).service(fault_error))
+        .bind(("127.0.0.1", 8080))?
+        .run()
+        .await
+}
@@ -0,0 +1,36 @@
+use axum::{Router, routing::get};
+use env_logger::Env;
+use explicit_error_http::{DefaultHandlerError, HttpError, Result, prelude::*};
+use http::StatusCode;
+
+async fn domain_error() -> std::result::Result<StatusCode, DefaultHandlerError> {
+    Err(HttpError::new(StatusCode::NOT_FOUND, "Article not found."))?;
+
+    Ok(StatusCode::OK)
+}
+
+async fn fault_error() -> std::result::Result<StatusCode, DefaultHandlerError> {
+    fetch_entity()?;
+
+    Ok(StatusCode::OK)
+}
+
+fn fetch_entity() -> Result<()> {
+    Err(sqlx::Error::PoolTimedOut)
+        .or_fault()
+        .with_context("Usefull info to help debug")?;
+
+    Ok(())
+}
+
+#[tokio::main(flavor = "current_thread")]
+async fn main() {
+    env_logger::init_from_env(Env::default().default_filter_or("info"));
+
+    let app = Router::new()
+        .route("/domain", get(domain_error))
+        .route("/fault", get(fault_error));
+
+    let listener = tokio::net::TcpListener::bind("0.0.0.0:8080").await.unwrap();
+    axum::serve(listener, app).await.unwrap();
+}
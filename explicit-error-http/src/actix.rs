@@ -0,0 +1,84 @@
+//! Tools dedicated to [actix-web](https://crates.io/crates/actix-web) integration, enabled with the `actix-web` feature.
+use actix_web::{
+    body::{BodySize, MessageBody},
+    dev::{ServiceRequest, ServiceResponse},
+    middleware::Next,
+    web::Bytes,
+};
+use std::{
+    convert::Infallible,
+    pin::Pin,
+    task::{Context, Poll},
+};
+
+/// Stream the chunks of the body as they are serialized, see [json_error_body](crate::json_error_body).
+impl MessageBody for crate::JsonErrorBody {
+    type Error = Infallible;
+
+    fn size(&self) -> BodySize {
+        BodySize::Sized(self.len() as u64)
+    }
+
+    fn poll_next(
+        self: Pin<&mut Self>,
+        _: &mut Context<'_>,
+    ) -> Poll<Option<Result<Bytes, Self::Error>>> {
+        Poll::Ready(
+            self.get_mut()
+                .next_chunk()
+                .map(|chunk| Ok(Bytes::from(chunk))),
+        )
+    }
+}
+
+/// Middleware negotiating the [ErrorBodyFormat](crate::ErrorBodyFormat) of error responses from the `Accept` header of the request.
+/// Enabled with the `cbor` or `msgpack` features.
+/// # Examples
+/// ```rust
+/// # use actix_web::{App, middleware};
+/// let app = App::new().wrap(middleware::from_fn(explicit_error_http::actix::negotiate_error_body));
+/// ```
+#[cfg(any(feature = "cbor", feature = "msgpack"))]
+pub async fn negotiate_error_body(
+    request: ServiceRequest,
+    next: Next<impl MessageBody>,
+) -> Result<ServiceResponse<impl MessageBody>, actix_web::Error> {
+    let accept = request
+        .headers()
+        .get_all(actix_web::http::header::ACCEPT)
+        .filter_map(|value| value.to_str().ok())
+        .collect::<Vec<_>>()
+        .join(",");
+
+    crate::ErrorBodyFormat::from_accept(&accept)
+        .scope(next.call(request))
+        .await
+}
+
+/// Middleware populating the [ResponseContext](crate::ResponseContext) given to
+/// [public_fault_response_with_context](crate::HandlerError::public_fault_response_with_context) with the method, the path and
+/// the [request id](crate::REQUEST_ID_HEADER) of the request.
+/// # Examples
+/// ```rust
+/// # use actix_web::{App, middleware};
+/// let app = App::new().wrap(middleware::from_fn(explicit_error_http::actix::response_context));
+/// ```
+pub async fn response_context(
+    request: ServiceRequest,
+    next: Next<impl MessageBody>,
+) -> Result<ServiceResponse<impl MessageBody>, actix_web::Error> {
+    let mut context = crate::ResponseContext::new(
+        http::Method::from_bytes(request.method().as_str().as_bytes()).unwrap_or_default(),
+        request.path(),
+    );
+
+    if let Some(request_id) = request
+        .headers()
+        .get(crate::REQUEST_ID_HEADER)
+        .and_then(|value| value.to_str().ok())
+    {
+        context = context.with_request_id(request_id);
+    }
+
+    context.scope(next.call(request)).await
+}
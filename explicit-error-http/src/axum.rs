@@ -0,0 +1,201 @@
+//! Tools dedicated to [axum](https://crates.io/crates/axum) integration, enabled with the `axum` feature.
+use crate::{DomainError, HttpError};
+use axum::extract::rejection::{FormRejection, JsonRejection, PathRejection, QueryRejection};
+use http::{HeaderMap, StatusCode};
+use http_body_util::{BodyExt, combinators::WithTrailers};
+use problem_details::ProblemDetails;
+use std::{
+    convert::Infallible,
+    future::{Ready, ready},
+    pin::Pin,
+    sync::RwLock,
+    task::{Context, Poll},
+};
+
+static REJECTION_MAPPER: RwLock<fn(StatusCode, String) -> HttpError> =
+    RwLock::new(default_rejection_mapper);
+
+type Trailers<E> = Ready<Option<Result<HeaderMap, E>>>;
+
+/// Internally used by [HandlerErrorHelpers](crate::derive::HandlerErrorHelpers) derive
+/// to send [HttpError](crate::HttpError) trailers after the response body.
+#[doc(hidden)]
+pub fn with_trailers<B>(body: B, trailers: HeaderMap) -> WithTrailers<B, Trailers<B::Error>>
+where
+    B: http_body::Body,
+{
+    body.with_trailers(ready(Some(Ok(trailers))))
+}
+
+/// Stream the chunks of the body as they are serialized, see [json_error_body](crate::json_error_body).
+impl http_body::Body for crate::JsonErrorBody {
+    type Data = axum::body::Bytes;
+    type Error = Infallible;
+
+    fn poll_frame(
+        self: Pin<&mut Self>,
+        _: &mut Context<'_>,
+    ) -> Poll<Option<Result<http_body::Frame<Self::Data>, Self::Error>>> {
+        Poll::Ready(
+            self.get_mut()
+                .next_chunk()
+                .map(|chunk| Ok(http_body::Frame::data(chunk.into()))),
+        )
+    }
+
+    fn is_end_stream(&self) -> bool {
+        self.is_empty()
+    }
+
+    fn size_hint(&self) -> http_body::SizeHint {
+        http_body::SizeHint::with_exact(self.len() as u64)
+    }
+}
+
+impl axum::response::IntoResponse for crate::JsonErrorBody {
+    fn into_response(self) -> axum::response::Response {
+        axum::response::Response::new(axum::body::Body::new(self))
+    }
+}
+
+/// Middleware negotiating the [ErrorBodyFormat](crate::ErrorBodyFormat) of error responses from the `Accept` header of the request.
+/// Enabled with the `cbor` or `msgpack` features.
+/// # Examples
+/// ```rust
+/// # use axum::{Router, middleware, routing::get};
+/// let app: Router = Router::new()
+///     .route("/", get(|| async { "Hello" }))
+///     .layer(middleware::from_fn(explicit_error_http::axum::negotiate_error_body));
+/// ```
+#[cfg(any(feature = "cbor", feature = "msgpack"))]
+pub async fn negotiate_error_body(
+    request: axum::extract::Request,
+    next: axum::middleware::Next,
+) -> axum::response::Response {
+    let accept = request
+        .headers()
+        .get_all(http::header::ACCEPT)
+        .iter()
+        .filter_map(|value| value.to_str().ok())
+        .collect::<Vec<_>>()
+        .join(",");
+
+    crate::ErrorBodyFormat::from_accept(&accept)
+        .scope(next.run(request))
+        .await
+}
+
+/// Middleware populating the [ResponseContext](crate::ResponseContext) given to
+/// [public_fault_response_with_context](crate::HandlerError::public_fault_response_with_context) with the method, the path and
+/// the [request id](crate::REQUEST_ID_HEADER) of the request.
+/// # Examples
+/// ```rust
+/// # use axum::{Router, middleware, routing::get};
+/// let app: Router = Router::new()
+///     .route("/", get(|| async { "Hello" }))
+///     .layer(middleware::from_fn(explicit_error_http::axum::response_context));
+/// ```
+pub async fn response_context(
+    request: axum::extract::Request,
+    next: axum::middleware::Next,
+) -> axum::response::Response {
+    let mut context = crate::ResponseContext::new(request.method().clone(), request.uri().path());
+
+    if let Some(request_id) = request
+        .headers()
+        .get(crate::REQUEST_ID_HEADER)
+        .and_then(|value| value.to_str().ok())
+    {
+        context = context.with_request_id(request_id);
+    }
+
+    context.scope(next.run(request)).await
+}
+
+/// Set how the rejections of axum extractors (`Json`, `Path`, `Query` and `Form`) convert to [HttpError],
+/// from the HTTP status code and the message of the rejection.
+///
+/// Default to a [ProblemDetails] body with the status code and its canonical reason as title, and the message of the rejection as detail.
+/// # Examples
+/// ```rust
+/// # use explicit_error_http::HttpError;
+/// # use problem_details::ProblemDetails;
+/// explicit_error_http::axum::set_rejection_mapper(|status, _message| {
+///     HttpError::new(
+///         status,
+///         ProblemDetails::from_status_code(status)
+///             .with_type(http::Uri::from_static("/errors/malformed-request")),
+///     )
+/// });
+/// ```
+pub fn set_rejection_mapper(mapper: fn(StatusCode, String) -> HttpError) {
+    *REJECTION_MAPPER
+        .write()
+        .unwrap_or_else(|poisoned| poisoned.into_inner()) = mapper;
+}
+
+fn default_rejection_mapper(status: StatusCode, message: String) -> HttpError {
+    HttpError::new(
+        status,
+        ProblemDetails::from_status_code(status).with_detail(message),
+    )
+}
+
+fn rejection_error<R>(status: StatusCode, message: String, rejection: R) -> DomainError
+where
+    R: std::error::Error + Send + Sync + 'static,
+{
+    let mapper = *REJECTION_MAPPER
+        .read()
+        .unwrap_or_else(|poisoned| poisoned.into_inner());
+
+    DomainError {
+        output: mapper(status, message),
+        source: Some(Box::new(rejection)),
+    }
+}
+
+/// Convert the rejection of an axum extractor with the mapping set with [set_rejection_mapper], the rejection is the
+/// [source](std::error::Error::source) of the [DomainError]. So extractor failures and business errors produce consistent responses.
+/// # Examples
+/// ```rust
+/// # use axum::{Json, extract::rejection::JsonRejection};
+/// # use explicit_error_http::{DefaultHandlerError, DomainError};
+/// # use http::StatusCode;
+/// # #[derive(serde::Deserialize)]
+/// # struct User {
+/// #     name: String,
+/// # }
+/// async fn create_user(
+///     user: Result<Json<User>, JsonRejection>,
+/// ) -> Result<StatusCode, DefaultHandlerError> {
+///     let Json(user) = user.map_err(DomainError::from)?;
+///     Ok(StatusCode::CREATED)
+/// }
+/// ```
+impl From<JsonRejection> for DomainError {
+    fn from(value: JsonRejection) -> Self {
+        rejection_error(value.status(), value.body_text(), value)
+    }
+}
+
+/// Convert the rejection of an axum extractor, see `From<JsonRejection>`.
+impl From<PathRejection> for DomainError {
+    fn from(value: PathRejection) -> Self {
+        rejection_error(value.status(), value.body_text(), value)
+    }
+}
+
+/// Convert the rejection of an axum extractor, see `From<JsonRejection>`.
+impl From<QueryRejection> for DomainError {
+    fn from(value: QueryRejection) -> Self {
+        rejection_error(value.status(), value.body_text(), value)
+    }
+}
+
+/// Convert the rejection of an axum extractor, see `From<JsonRejection>`.
+impl From<FormRejection> for DomainError {
+    fn from(value: FormRejection) -> Self {
+        rejection_error(value.status(), value.body_text(), value)
+    }
+}
@@ -0,0 +1,177 @@
+#[cfg(any(feature = "log", feature = "tracing"))]
+use crate::Severity;
+use crate::{DomainError, Error, HandlerError, derive::HandlerErrorHelpers};
+use explicit_error::Fault;
+use http::{StatusCode, Uri};
+use problem_details::ProblemDetails;
+use serde::Serialize;
+use std::marker::PhantomData;
+
+/// Ready-made implementation of [HandlerError] for applications that do not need a custom one.
+/// - Domain errors respond with their [public](crate::HttpError::public) body and are logged at their [Severity].
+/// - Faults are logged as error and respond with a generic [ProblemDetails] 500 configured with [DefaultHandlerConfig].
+///
+/// Logs are emitted with [log](https://docs.rs/log), with the default `log` feature, or with [tracing](https://docs.rs/tracing)
+/// if the `tracing` feature is enabled. Nothing is logged without either of them.
+/// Override [on_fault](DefaultHandlerConfig::on_fault) and [on_domain](DefaultHandlerConfig::on_domain) to monitor errors differently.
+///
+/// To customize the responses or the monitoring, declare your own type and derive [HandlerErrorHelpers](crate::derive::HandlerErrorHelpers).
+/// # Examples
+/// ```rust
+/// # use actix_web::{HttpResponse, get};
+/// # use explicit_error_http::{DefaultHandlerError, HttpError};
+/// # use http::StatusCode;
+/// #[get("/my-handler")]
+/// async fn my_handler() -> Result<HttpResponse, DefaultHandlerError> {
+///     Err(HttpError::new(StatusCode::FORBIDDEN, "Forbidden"))?;
+///     Ok(HttpResponse::Ok().finish())
+/// }
+/// ```
+#[derive(HandlerErrorHelpers)]
+pub struct DefaultHandlerError<C: DefaultHandlerConfig = DefaultConfig>(
+    Error,
+    PhantomData<fn() -> C>,
+);
+
+/// Configuration of the fault response body and of the monitoring hooks of [DefaultHandlerError].
+/// # Examples
+/// ```rust
+/// # use explicit_error_http::{DefaultHandlerConfig, DefaultHandlerError, Fault};
+/// struct MyConfig;
+///
+/// impl DefaultHandlerConfig for MyConfig {
+///     const FAULT_TYPE: Option<&'static str> = Some("/errors/internal-server-error");
+///     const FAULT_TITLE: &'static str = "Something went wrong on our side";
+///
+///     fn on_fault(fault: &Fault) {
+///         eprintln!("{fault}");
+///     }
+/// }
+///
+/// type MyHandlerError = DefaultHandlerError<MyConfig>;
+/// ```
+pub trait DefaultHandlerConfig {
+    /// `type` member of the fault [ProblemDetails]. It must be a URI reference: a type with invalid URI characters
+    /// fails to compile, and one rejected by [Uri] anyway is left out of the body.
+    const FAULT_TYPE: Option<&'static str> = None;
+
+    /// `title` member of the fault [ProblemDetails]
+    const FAULT_TITLE: &'static str = "Internal server error";
+
+    /// Called before responding to a fault.
+    ///
+    /// Default to log it as error, in JSON in release builds.
+    fn on_fault(fault: &Fault) {
+        #[cfg(debug_assertions)]
+        log_fault(format_args!("{fault}"));
+
+        #[cfg(not(debug_assertions))]
+        log_fault(format_args!("{}", serde_json::json!(fault)));
+    }
+
+    /// Called before responding to a domain error.
+    ///
+    /// Default to log it at its [Severity].
+    fn on_domain(error: &DomainError) {
+        log_domain(error);
+    }
+}
+
+/// Default configuration of [DefaultHandlerError].
+pub struct DefaultConfig;
+
+impl DefaultHandlerConfig for DefaultConfig {}
+
+impl<C: DefaultHandlerConfig> HandlerError for DefaultHandlerError<C> {
+    fn error(&self) -> &Error {
+        &self.0
+    }
+
+    fn public_fault_response(fault: &Fault) -> impl Serialize {
+        const {
+            if let Some(fault_type) = C::FAULT_TYPE {
+                assert!(
+                    uri_characters(fault_type),
+                    "DefaultHandlerConfig::FAULT_TYPE is not a valid URI"
+                );
+            }
+        }
+
+        C::on_fault(fault);
+
+        let problem = ProblemDetails::from_status_code(StatusCode::INTERNAL_SERVER_ERROR)
+            .with_title(C::FAULT_TITLE);
+
+        match C::FAULT_TYPE.and_then(|fault_type| Uri::try_from(fault_type).ok()) {
+            Some(fault_type) => problem.with_type(fault_type),
+            None => problem,
+        }
+    }
+
+    fn domain_response(error: &DomainError) -> impl Serialize {
+        C::on_domain(error);
+        error.output.public()
+    }
+
+    fn from_error(value: Error) -> Self {
+        Self(value, PhantomData)
+    }
+}
+
+/// Return true if the URI is not empty and made of the characters allowed by RFC 3986
+const fn uri_characters(uri: &str) -> bool {
+    let uri = uri.as_bytes();
+    let mut i = 0;
+    while i < uri.len() {
+        match uri[i] {
+            b'a'..=b'z' | b'A'..=b'Z' | b'0'..=b'9' => {}
+            b'-' | b'.' | b'_' | b'~' | b'%' => {}
+            b':' | b'/' | b'?' | b'#' | b'[' | b']' | b'@' => {}
+            b'!' | b'$' | b'&' | b'\'' | b'(' | b')' | b'*' | b'+' | b',' | b';' | b'=' => {}
+            _ => return false,
+        }
+        i += 1;
+    }
+    !uri.is_empty()
+}
+
+#[cfg(feature = "tracing")]
+fn log_fault(message: std::fmt::Arguments<'_>) {
+    tracing::error!("{message}");
+}
+
+#[cfg(all(feature = "log", not(feature = "tracing")))]
+fn log_fault(message: std::fmt::Arguments<'_>) {
+    log::error!("{message}");
+}
+
+#[cfg(not(any(feature = "log", feature = "tracing")))]
+fn log_fault(_: std::fmt::Arguments<'_>) {}
+
+#[cfg(feature = "tracing")]
+fn log_domain(error: &DomainError) {
+    match error.severity() {
+        Severity::Trace => tracing::trace!("{error}"),
+        Severity::Debug => tracing::debug!("{error}"),
+        Severity::Info => tracing::info!("{error}"),
+        Severity::Warn => tracing::warn!("{error}"),
+        Severity::Error => tracing::error!("{error}"),
+    }
+}
+
+#[cfg(all(feature = "log", not(feature = "tracing")))]
+fn log_domain(error: &DomainError) {
+    match error.severity() {
+        Severity::Trace => log::trace!("{error}"),
+        Severity::Debug => log::debug!("{error}"),
+        Severity::Info => log::info!("{error}"),
+        Severity::Warn => log::warn!("{error}"),
+        Severity::Error => log::error!("{error}"),
+    }
+}
+
+#[cfg(not(any(feature = "log", feature = "tracing")))]
+fn log_domain(_: &DomainError) {}
+
+#[cfg(test)]
+mod test;
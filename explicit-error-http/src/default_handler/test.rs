@@ -0,0 +1,103 @@
+use super::*;
+use crate::HttpError;
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+#[derive(Serialize, Clone)]
+struct ErrorBody {
+    foo: &'static str,
+    bar: i64,
+}
+
+struct MyConfig;
+
+impl DefaultHandlerConfig for MyConfig {
+    const FAULT_TYPE: Option<&'static str> = Some("/errors/internal-server-error");
+    const FAULT_TITLE: &'static str = "Something went wrong";
+}
+
+#[test]
+fn domain_response() {
+    let error: DefaultHandlerError = HttpError::new(
+        StatusCode::BAD_REQUEST,
+        ErrorBody {
+            foo: "foo",
+            bar: 42,
+        },
+    )
+    .into();
+
+    match error.error() {
+        Error::Domain(d) => assert_eq!(
+            serde_json::json!(DefaultHandlerError::<DefaultConfig>::domain_response(d)),
+            serde_json::json!({"foo": "foo", "bar": 42})
+        ),
+        Error::Fault(_) => panic!("expected a domain error"),
+    }
+}
+
+#[test]
+fn public_fault_response() {
+    assert_eq!(
+        serde_json::json!(DefaultHandlerError::<DefaultConfig>::public_fault_response(
+            &Fault::new()
+        )),
+        serde_json::json!({"status": 500, "title": "Internal server error"})
+    );
+
+    assert_eq!(
+        serde_json::json!(DefaultHandlerError::<MyConfig>::public_fault_response(
+            &Fault::new()
+        )),
+        serde_json::json!({
+            "type": "/errors/internal-server-error",
+            "status": 500,
+            "title": "Something went wrong"
+        })
+    );
+}
+
+#[test]
+fn hooks() {
+    static FAULTS: AtomicUsize = AtomicUsize::new(0);
+    static DOMAINS: AtomicUsize = AtomicUsize::new(0);
+
+    struct HookConfig;
+
+    impl DefaultHandlerConfig for HookConfig {
+        fn on_fault(_: &Fault) {
+            FAULTS.fetch_add(1, Ordering::Relaxed);
+        }
+
+        fn on_domain(_: &DomainError) {
+            DOMAINS.fetch_add(1, Ordering::Relaxed);
+        }
+    }
+
+    let _ = serde_json::json!(DefaultHandlerError::<HookConfig>::public_fault_response(
+        &Fault::new()
+    ));
+    assert_eq!(FAULTS.load(Ordering::Relaxed), 1);
+    assert_eq!(DOMAINS.load(Ordering::Relaxed), 0);
+
+    let error: DefaultHandlerError<HookConfig> = HttpError::new(StatusCode::BAD_REQUEST, "").into();
+    match error.error() {
+        Error::Domain(d) => {
+            let _ = serde_json::json!(DefaultHandlerError::<HookConfig>::domain_response(d));
+        }
+        Error::Fault(_) => panic!("expected a domain error"),
+    }
+    assert_eq!(FAULTS.load(Ordering::Relaxed), 1);
+    assert_eq!(DOMAINS.load(Ordering::Relaxed), 1);
+}
+
+#[test]
+fn uri_characters() {
+    assert!(super::uri_characters("/errors/internal-server-error"));
+    assert!(super::uri_characters(
+        "https://example.com/errors?code=500#fault"
+    ));
+    assert!(super::uri_characters("about:blank"));
+    assert!(!super::uri_characters("/errors/internal server error"));
+    assert!(!super::uri_characters("/errors/é"));
+    assert!(!super::uri_characters(""));
+}
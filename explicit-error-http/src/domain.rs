@@ -1,5 +1,8 @@
-use crate::{HttpErrorDisplay, error::HttpError};
-use explicit_error::{Domain, Error};
+use crate::{
+    DynSerializeClone, HttpErrorDisplay, Severity, error::HttpError, serialize_http_status_code,
+};
+use explicit_error::{Domain, Error, Extensions, Retryable};
+use http::StatusCode;
 use serde::{Serialize, Serializer};
 use std::{error::Error as StdError, fmt::Debug};
 
@@ -100,6 +103,111 @@ pub struct DomainError {
     pub source: Option<Box<dyn StdError + Send + Sync>>,
 }
 
+impl DomainError {
+    /// Set the [Severity] used to log the [DomainError], override the default one deduced from the HTTP status code.
+    pub fn with_severity(mut self, severity: Severity) -> Self {
+        self.output = self.output.with_severity(severity);
+        self
+    }
+
+    /// Return a mutable reference to the [HttpError], eg: to rewrite the public body in
+    /// [redact_domain_error](crate::HandlerError::redact_domain_error).
+    pub fn output_mut(&mut self) -> &mut HttpError {
+        &mut self.output
+    }
+
+    /// Return the [Severity] of the [DomainError]. Usefull to choose the log level in
+    /// [domain_response](crate::HandlerError::domain_response).
+    pub fn severity(&self) -> Severity {
+        self.output.severity()
+    }
+
+    /// Decompose into the HTTP status code, the public body and the source of the [DomainError].
+    /// # Examples
+    /// ```rust
+    /// # use explicit_error_http::HttpError;
+    /// # use http::StatusCode;
+    /// let (status, body, source) = HttpError::not_found("Not found")
+    ///     .with_source(std::io::Error::other("io"))
+    ///     .split();
+    /// assert_eq!(status, StatusCode::NOT_FOUND);
+    /// assert_eq!(serde_json::json!(body), "Not found");
+    /// assert_eq!(source.unwrap().to_string(), "io");
+    /// ```
+    #[allow(clippy::type_complexity)]
+    pub fn split(
+        self,
+    ) -> (
+        StatusCode,
+        Box<dyn DynSerializeClone>,
+        Option<Box<dyn StdError + Send + Sync>>,
+    ) {
+        (
+            self.output.http_status_code,
+            self.output.into_public(),
+            self.source,
+        )
+    }
+
+    /// Iterate over the [DomainError] followed by its chain of [sources](StdError::source), see [errors_chain](explicit_error::errors_chain).
+    pub fn chain(&self) -> impl Iterator<Item = &(dyn StdError + 'static)> {
+        explicit_error::errors_chain(self)
+    }
+
+    /// Return the OpenTelemetry trace id of the span active when the [DomainError] was created
+    #[cfg(feature = "otel")]
+    pub fn trace_id(&self) -> Option<opentelemetry::trace::TraceId> {
+        self.output
+            .extensions
+            .get::<explicit_error::SpanIds>()
+            .map(|ids| ids.trace_id)
+    }
+
+    /// Return the OpenTelemetry span id of the span active when the [DomainError] was created
+    #[cfg(feature = "otel")]
+    pub fn span_id(&self) -> Option<opentelemetry::trace::SpanId> {
+        self.output
+            .extensions
+            .get::<explicit_error::SpanIds>()
+            .map(|ids| ids.span_id)
+    }
+}
+
+/// The source is not cloneable, it is dropped on clone.
+impl Clone for DomainError {
+    fn clone(&self) -> Self {
+        Self {
+            output: self.output.clone(),
+            source: None,
+        }
+    }
+}
+
+/// Two domain errors are equal if their [HttpError]s are equal and their sources debug format the same,
+/// see [sources_eq](explicit_error::sources_eq). Extensions are ignored.
+impl PartialEq for DomainError {
+    fn eq(&self, other: &Self) -> bool {
+        self.output == other.output
+            && explicit_error::sources_eq(
+                self.source.as_deref().map(|s| s as _),
+                other.source.as_deref().map(|s| s as _),
+            )
+    }
+}
+
+/// Retryable if the HTTP status code is 429 Too Many Requests, 502 Bad Gateway, 503 Service Unavailable or 504 Gateway Timeout.
+impl Retryable for DomainError {
+    fn is_retryable(&self) -> bool {
+        matches!(
+            self.output.http_status_code,
+            StatusCode::TOO_MANY_REQUESTS
+                | StatusCode::BAD_GATEWAY
+                | StatusCode::SERVICE_UNAVAILABLE
+                | StatusCode::GATEWAY_TIMEOUT
+        )
+    }
+}
+
 impl Domain for DomainError {
     fn into_source(self) -> Option<Box<dyn std::error::Error + Send + Sync>> {
         self.source
@@ -109,15 +217,26 @@ impl Domain for DomainError {
         self.output.context.as_deref()
     }
 
+    fn context_mut(&mut self) -> Option<&mut Option<String>> {
+        Some(&mut self.output.context)
+    }
+
     fn with_context(mut self, context: impl std::fmt::Display) -> Self {
         self.output = self.output.with_context(context);
         self
     }
-}
 
-impl From<DomainError> for Error<DomainError> {
-    fn from(value: DomainError) -> Self {
-        Self::Domain(Box::new(value))
+    fn extensions(&self) -> &Extensions {
+        &self.output.extensions
+    }
+
+    fn extensions_mut(&mut self) -> Option<&mut Extensions> {
+        Some(&mut self.output.extensions)
+    }
+
+    /// See [HttpError::user_message]
+    fn user_message(&self) -> Option<&str> {
+        self.output.user_message()
     }
 }
 
@@ -127,29 +246,88 @@ impl StdError for DomainError {
     }
 }
 
+/// Status code and headers of the response generated by [HandlerErrorHelpers](crate::derive::HandlerErrorHelpers), without the body.
+/// Usefull for framework-agnostic middlewares, eg: to answer a `HEAD` request.
+///
+/// The `Content-Type` header is set if the status code allows a body, see [HttpError::has_body].
+/// # Examples
+/// ```rust
+/// # use explicit_error_http::{DomainError, HttpError};
+/// # use http::StatusCode;
+/// let domain = DomainError {
+///     output: HttpError::not_found(""),
+///     source: None,
+/// };
+/// let response = http::Response::from(&domain);
+///
+/// assert_eq!(response.status(), StatusCode::NOT_FOUND);
+/// assert_eq!(response.headers()[http::header::CONTENT_TYPE], "application/json");
+/// ```
+impl From<&DomainError> for http::Response<()> {
+    fn from(value: &DomainError) -> Self {
+        let mut response = http::Response::new(());
+        *response.status_mut() = value.output.http_status_code;
+
+        let headers = response.headers_mut();
+        if value.output.has_body() {
+            headers.insert(
+                http::header::CONTENT_TYPE,
+                http::HeaderValue::from_static(value.output.content_type()),
+            );
+        }
+        if let Some(output_headers) = value.output.headers() {
+            headers.extend(output_headers.clone());
+        }
+
+        response
+    }
+}
+
+/// Fields are serialized in this order, keep it stable as log ingestion may rely on it
 #[derive(Serialize)]
 struct DomainErrorDisplay<'s> {
-    #[serde(flatten)]
-    output: HttpErrorDisplay<'s>,
+    #[serde(serialize_with = "serialize_http_status_code")]
+    http_status_code: StatusCode,
+    context: Option<&'s str>,
+    public: &'s dyn erased_serde::Serialize,
     #[serde(serialize_with = "serialize_option_source_dyn")]
     pub source: Option<&'s (dyn StdError + 's + Send + Sync)>,
+    #[cfg(feature = "otel")]
+    #[serde(flatten)]
+    extensions: &'s Extensions,
 }
 
 impl<'s> From<&'s DomainError> for DomainErrorDisplay<'s> {
     fn from(value: &'s DomainError) -> Self {
+        let HttpErrorDisplay {
+            http_status_code,
+            context,
+            public,
+        } = HttpErrorDisplay::<'s>::from(&value.output);
+
         Self {
-            output: HttpErrorDisplay::<'s>::from(&value.output),
+            http_status_code,
+            context,
+            public,
             source: value.source.as_deref(),
+            #[cfg(feature = "otel")]
+            extensions: &value.output.extensions,
         }
     }
 }
 
+/// Display the [DomainError] as JSON, with the fields `http_status_code`, `context`, `public` and `source` in this order. With the alternate flag, `{:#}`, it is displayed as its [HttpError].
 impl std::fmt::Display for DomainError {
     fn fmt<'s>(&'s self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        if f.alternate() {
+            return self.output.display_plain(f);
+        }
+
         write!(
             f,
             "{}",
-            serde_json::json!(DomainErrorDisplay::<'s>::from(self))
+            serde_json::to_string(&DomainErrorDisplay::<'s>::from(self))
+                .map_err(|_| std::fmt::Error)?
         )
     }
 }
@@ -168,6 +346,10 @@ where
     }
 
     fn display(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        if f.alternate() {
+            return Into::<HttpError>::into(self).display_plain(f);
+        }
+
         #[derive(Serialize)]
         struct S<'s> {
             #[serde(flatten)]
@@ -180,10 +362,11 @@ where
             f,
             r#"{{"output":"{}","source":}}{}"#,
             Into::<HttpError>::into(self),
-            serde_json::json!(S {
+            serde_json::to_string(&S {
                 output: self.into(),
                 source: self,
             })
+            .map_err(|_| std::fmt::Error)?
         )
     }
 }
@@ -201,6 +384,12 @@ where
     /// Err::<(), _>(Fault::new()).with_context("Foo bar");
     /// ```
     fn with_context(self, context: impl std::fmt::Display) -> std::result::Result<T, DomainError>;
+
+    /// Add a context, if there is one, to an error that convert to [Error] wrapped in a [Result::Err]
+    fn with_context_opt(
+        self,
+        context: Option<impl std::fmt::Display>,
+    ) -> std::result::Result<T, DomainError>;
 }
 
 impl<T, D> ResultDomainWithContext<T, D> for Result<T, D>
@@ -214,13 +403,65 @@ where
             Err(e) => Err(e.to_domain_error().with_context(context)),
         }
     }
+
+    fn with_context_opt(
+        self,
+        context: Option<impl std::fmt::Display>,
+    ) -> std::result::Result<T, DomainError> {
+        self.map_err(|e| e.to_domain_error().with_context_opt(context))
+    }
+}
+
+/// To use this trait on [Result] import the prelude `use explicit_error_http::prelude::*`
+pub trait ResultHttp<T, E> {
+    /// Convert the error to a [DomainError] with the HTTP status code and a minimal [ProblemDetails](problem_details::ProblemDetails)
+    /// body made of the status and the title. The error is kept as the source.
+    ///
+    /// Use [map_err](Result::map_err) with [HttpError::new] for custom bodies.
+    /// # Examples
+    /// ```rust
+    /// # use explicit_error_http::{Result, prelude::*};
+    /// # use http::StatusCode;
+    /// fn parse_page(page: &str) -> Result<u32> {
+    ///     Ok(page
+    ///         .parse()
+    ///         .map_err_to_status(StatusCode::BAD_REQUEST, "Page must be a number")?)
+    /// }
+    /// ```
+    fn map_err_to_status(
+        self,
+        http_status_code: StatusCode,
+        title: impl Into<String>,
+    ) -> Result<T, DomainError>;
+}
+
+impl<T, E> ResultHttp<T, E> for Result<T, E>
+where
+    E: StdError + 'static + Send + Sync,
+{
+    fn map_err_to_status(
+        self,
+        http_status_code: StatusCode,
+        title: impl Into<String>,
+    ) -> Result<T, DomainError> {
+        self.map_err(|e| DomainError {
+            output: HttpError::new(
+                http_status_code,
+                problem_details::ProblemDetails::from_status_code(http_status_code)
+                    .with_title(title),
+            ),
+            source: Some(Box::new(e)),
+        })
+    }
 }
 
 fn serialize_source_dyn<S>(source: &dyn StdError, s: S) -> Result<S::Ok, S::Error>
 where
     S: Serializer,
 {
-    s.serialize_str(&explicit_error::errors_chain_debug(source))
+    s.serialize_str(&explicit_error::redact(
+        &explicit_error::errors_chain_debug(source),
+    ))
 }
 
 fn serialize_option_source_dyn<S>(
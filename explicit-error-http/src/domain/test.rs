@@ -1,7 +1,7 @@
 use super::*;
-use http::StatusCode;
+use crate::Body;
 
-#[derive(Serialize)]
+#[derive(Serialize, Clone)]
 struct ErrorBody {
     foo: &'static str,
     bar: i64,
@@ -13,8 +13,12 @@ fn into_source() {
         DomainError {
             output: HttpError {
                 http_status_code: StatusCode::BAD_REQUEST,
-                public: Box::new(""),
+                public: Body::Dyn(Box::new("")),
                 context: None,
+                trailers: None,
+                severity: None,
+                headers: None,
+                extensions: Extensions::new(),
             },
             source: None,
         }
@@ -26,8 +30,12 @@ fn into_source() {
         DomainError {
             output: HttpError {
                 http_status_code: StatusCode::BAD_REQUEST,
-                public: Box::new(""),
+                public: Body::Dyn(Box::new("")),
                 context: None,
+                trailers: None,
+                severity: None,
+                headers: None,
+                extensions: Extensions::new(),
             },
             source: Some(Box::new(sqlx::Error::RowNotFound)),
         }
@@ -43,8 +51,12 @@ fn with_context() {
     let domain = DomainError {
         output: HttpError {
             http_status_code: StatusCode::BAD_REQUEST,
-            public: Box::new(""),
+            public: Body::Dyn(Box::new("")),
             context: None,
+            trailers: None,
+            severity: None,
+            headers: None,
+            extensions: Extensions::new(),
         },
         source: None,
     }
@@ -57,14 +69,32 @@ fn with_context() {
     );
 }
 
+#[test]
+fn with_context_opt() {
+    let domain = DomainError {
+        output: HttpError::bad_request(""),
+        source: None,
+    }
+    .with_context_opt(Some("context"));
+    assert_eq!(domain.context(), Some("context"));
+    assert_eq!(
+        domain.with_context_opt(None::<&str>).context(),
+        Some("context")
+    );
+}
+
 #[test]
 fn context() {
     assert!(
         DomainError {
             output: HttpError {
                 http_status_code: StatusCode::BAD_REQUEST,
-                public: Box::new(""),
+                public: Body::Dyn(Box::new("")),
                 context: None,
+                trailers: None,
+                severity: None,
+                headers: None,
+                extensions: Extensions::new(),
             },
             source: None,
         }
@@ -76,8 +106,12 @@ fn context() {
         DomainError {
             output: HttpError {
                 http_status_code: StatusCode::BAD_REQUEST,
-                public: Box::new(""),
+                public: Body::Dyn(Box::new("")),
                 context: Some("context".to_string()),
+                trailers: None,
+                severity: None,
+                headers: None,
+                extensions: Extensions::new(),
             },
             source: None,
         }
@@ -87,14 +121,115 @@ fn context() {
     );
 }
 
+#[test]
+fn partial_eq() {
+    let domain = || {
+        HttpError::new(
+            StatusCode::NOT_FOUND,
+            ErrorBody {
+                foo: "foo",
+                bar: 42,
+            },
+        )
+        .with_source(sqlx::Error::RowNotFound)
+    };
+    assert_eq!(domain(), domain());
+    assert_eq!(
+        domain().with_extension(std::sync::Arc::new(42_u32)),
+        domain()
+    );
+
+    assert_ne!(domain(), domain().with_context("context"));
+    assert_ne!(
+        domain(),
+        HttpError::new(
+            StatusCode::NOT_FOUND,
+            ErrorBody {
+                foo: "bar",
+                bar: 42,
+            },
+        )
+        .with_source(sqlx::Error::RowNotFound)
+    );
+    assert_ne!(
+        domain(),
+        HttpError::new(
+            StatusCode::NOT_FOUND,
+            ErrorBody {
+                foo: "foo",
+                bar: 42,
+            },
+        )
+        .with_source(sqlx::Error::PoolTimedOut)
+    );
+    assert_ne!(
+        domain(),
+        DomainError {
+            output: domain().output,
+            source: None,
+        }
+    );
+}
+
+#[test]
+fn clone() {
+    let domain = HttpError::new(
+        StatusCode::NOT_FOUND,
+        ErrorBody {
+            foo: "foo",
+            bar: 42,
+        },
+    )
+    .with_source(sqlx::Error::RowNotFound);
+    let clone = domain.clone();
+
+    assert_eq!(clone.output, domain.output);
+    assert!(clone.source.is_none());
+}
+
+#[test]
+fn is_retryable() {
+    assert!(
+        HttpError::new(StatusCode::SERVICE_UNAVAILABLE, "")
+            .with_source(sqlx::Error::PoolTimedOut)
+            .is_retryable()
+    );
+    assert!(
+        HttpError::new(StatusCode::TOO_MANY_REQUESTS, "")
+            .with_source(sqlx::Error::PoolTimedOut)
+            .is_retryable()
+    );
+    assert!(
+        !HttpError::new(StatusCode::NOT_FOUND, "")
+            .with_source(sqlx::Error::RowNotFound)
+            .is_retryable()
+    );
+    assert!(Error::from(HttpError::new(StatusCode::SERVICE_UNAVAILABLE, "")).is_retryable());
+    assert!(!Error::from(HttpError::new(StatusCode::BAD_REQUEST, "")).is_retryable());
+}
+
+#[test]
+fn severity() {
+    let domain = HttpError::new(StatusCode::NOT_FOUND, "").with_source(sqlx::Error::RowNotFound);
+    assert_eq!(domain.severity(), Severity::Debug);
+    assert_eq!(
+        domain.with_severity(Severity::Warn).severity(),
+        Severity::Warn
+    );
+}
+
 #[test]
 fn source() {
     assert!(
         DomainError {
             output: HttpError {
                 http_status_code: StatusCode::BAD_REQUEST,
-                public: Box::new(""),
+                public: Body::Dyn(Box::new("")),
                 context: None,
+                trailers: None,
+                severity: None,
+                headers: None,
+                extensions: Extensions::new(),
             },
             source: None,
         }
@@ -106,8 +241,12 @@ fn source() {
         DomainError {
             output: HttpError {
                 http_status_code: StatusCode::BAD_REQUEST,
-                public: Box::new(""),
+                public: Body::Dyn(Box::new("")),
                 context: None,
+                trailers: None,
+                severity: None,
+                headers: None,
+                extensions: Extensions::new(),
             },
             source: Some(Box::new(sqlx::Error::RowNotFound)),
         }
@@ -123,11 +262,15 @@ fn from_domain_for_error() {
     let domain = Error::from(DomainError {
         output: HttpError {
             http_status_code: StatusCode::BAD_REQUEST,
-            public: Box::new(ErrorBody {
+            public: Body::Dyn(Box::new(ErrorBody {
                 foo: "foo",
                 bar: 42,
-            }),
+            })),
             context: None,
+            trailers: None,
+            severity: None,
+            headers: None,
+            extensions: Extensions::new(),
         },
         source: None,
     })
@@ -137,29 +280,66 @@ fn from_domain_for_error() {
         domain.output,
         HttpError {
             http_status_code: StatusCode::BAD_REQUEST,
-            public: Box::new(ErrorBody {
+            public: Body::Dyn(Box::new(ErrorBody {
                 foo: "foo",
                 bar: 42,
-            }),
+            })),
             context: None,
+            trailers: None,
+            severity: None,
+            headers: None,
+            extensions: Extensions::new(),
         }
     );
     assert!(domain.source.is_none());
 }
 
+#[test]
+fn into_response_parts() {
+    let domain = HttpError::new(
+        StatusCode::NOT_FOUND,
+        ErrorBody {
+            foo: "foo",
+            bar: 42,
+        },
+    )
+    .with_header(
+        http::header::RETRY_AFTER,
+        http::HeaderValue::from_static("120"),
+    )
+    .with_source(sqlx::Error::RowNotFound);
+
+    let (parts, ()) = http::Response::from(&domain).into_parts();
+    assert_eq!(parts.status, StatusCode::NOT_FOUND);
+    assert_eq!(parts.headers[http::header::RETRY_AFTER], "120");
+    assert_eq!(
+        parts.headers[http::header::CONTENT_TYPE],
+        domain.output.content_type()
+    );
+
+    let domain = HttpError::new(StatusCode::NO_CONTENT, "").with_source(sqlx::Error::RowNotFound);
+    let (parts, ()) = http::Response::from(&domain).into_parts();
+    assert_eq!(parts.status, StatusCode::NO_CONTENT);
+    assert!(parts.headers.is_empty());
+}
+
 #[test]
 fn serialize() {
     assert_eq!(
         serde_json::json!(DomainError {
             output: HttpError {
                 http_status_code: StatusCode::BAD_REQUEST,
-                public: Box::new(ErrorBody {
+                public: Body::Dyn(Box::new(ErrorBody {
                     foo: "foo",
                     bar: 42
-                }),
-                context: Some("context".to_string())
+                })),
+                context: Some("context".to_string()),
+                trailers: None,
+                severity: None,
+                headers: None,
+                extensions: Extensions::new(),
             },
-            source: Some(Box::new(sqlx::Error::PoolClosed))
+            source: Some(Box::new(sqlx::Error::PoolClosed)),
         })
         .to_string(),
         r#"{"bar":42,"foo":"foo"}"#.to_string()
@@ -171,11 +351,15 @@ fn display() {
     let domain = DomainError {
         output: HttpError {
             http_status_code: StatusCode::BAD_REQUEST,
-            public: Box::new(ErrorBody {
+            public: Body::Dyn(Box::new(ErrorBody {
                 foo: "foo",
                 bar: 42,
-            }),
+            })),
             context: Some("context".to_string()),
+            trailers: None,
+            severity: None,
+            headers: None,
+            extensions: Extensions::new(),
         },
         source: Some(Box::new(sqlx::Error::PoolClosed)),
     }
@@ -183,11 +367,32 @@ fn display() {
 
     assert_eq!(
             domain,
-        r#"{"context":"context","http_status_code":400,"public":{"bar":42,"foo":"foo"},"source":"PoolClosed"}"#
+        r#"{"http_status_code":400,"context":"context","public":{"foo":"foo","bar":42},"source":"PoolClosed"}"#
             .to_string()
     );
 }
 
+#[test]
+fn display_alternate() {
+    let domain = DomainError {
+        output: HttpError::conflict(
+            problem_details::ProblemDetails::new().with_title("Already exists"),
+        )
+        .with_context("context"),
+        source: Some(Box::new(sqlx::Error::PoolClosed)),
+    };
+    assert_eq!(
+        format!("{domain:#}"),
+        "409 Conflict Already exists: context"
+    );
+    assert_eq!(
+        format!("{:#}", crate::Error::from(domain)),
+        "409 Conflict Already exists: context"
+    );
+
+    assert_eq!(format!("{MyDomainError:#}"), "400 Bad Request: context");
+}
+
 #[derive(Debug)]
 struct MyDomainError;
 
@@ -195,11 +400,15 @@ impl From<&MyDomainError> for HttpError {
     fn from(_: &MyDomainError) -> Self {
         HttpError {
             http_status_code: StatusCode::BAD_REQUEST,
-            public: Box::new(ErrorBody {
+            public: Body::Dyn(Box::new(ErrorBody {
                 foo: "foo",
                 bar: 42,
-            }),
+            })),
             context: Some("context".to_string()),
+            trailers: None,
+            severity: None,
+            headers: None,
+            extensions: Extensions::new(),
         }
     }
 }
@@ -228,11 +437,15 @@ fn to_domain_error() {
         domain_error.output,
         HttpError {
             http_status_code: StatusCode::BAD_REQUEST,
-            public: Box::new(ErrorBody {
+            public: Body::Dyn(Box::new(ErrorBody {
                 foo: "foo",
                 bar: 42,
-            }),
+            })),
             context: Some("context".to_string()),
+            trailers: None,
+            severity: None,
+            headers: None,
+            extensions: Extensions::new(),
         }
     );
     assert!(
@@ -246,7 +459,7 @@ fn to_domain_error() {
 
     assert_eq!(
         domain_error.to_string(),
-        r#"{"context":"context","http_status_code":400,"public":{"bar":42,"foo":"foo"},"source":"MyDomainError"}"#
+        r#"{"http_status_code":400,"context":"context","public":{"foo":"foo","bar":42},"source":"MyDomainError"}"#
     );
 }
 
@@ -260,11 +473,15 @@ fn result_domain_with_context() {
         domain_error.output,
         HttpError {
             http_status_code: StatusCode::BAD_REQUEST,
-            public: Box::new(ErrorBody {
+            public: Body::Dyn(Box::new(ErrorBody {
                 foo: "foo",
                 bar: 42,
-            }),
+            })),
             context: Some("context 2".to_string()),
+            trailers: None,
+            severity: None,
+            headers: None,
+            extensions: Extensions::new(),
         }
     );
     assert!(
@@ -278,6 +495,63 @@ fn result_domain_with_context() {
 
     assert_eq!(
         domain_error.to_string(),
-        r#"{"context":"context 2","http_status_code":400,"public":{"bar":42,"foo":"foo"},"source":"MyDomainError"}"#
+        r#"{"http_status_code":400,"context":"context 2","public":{"foo":"foo","bar":42},"source":"MyDomainError"}"#
+    );
+}
+
+#[test]
+fn map_err_to_status() {
+    let error = "x"
+        .parse::<u32>()
+        .map_err_to_status(StatusCode::BAD_REQUEST, "Page must be a number")
+        .unwrap_err();
+
+    assert_eq!(error.output.http_status_code, StatusCode::BAD_REQUEST);
+    assert_eq!(
+        serde_json::json!(error.output.public()),
+        serde_json::json!({"status": 400, "title": "Page must be a number"})
+    );
+    assert!(
+        error
+            .source
+            .as_ref()
+            .unwrap()
+            .is::<std::num::ParseIntError>()
+    );
+
+    assert_eq!(
+        "1".parse::<u32>()
+            .map_err_to_status(StatusCode::BAD_REQUEST, "")
+            .unwrap(),
+        1
+    );
+}
+
+#[cfg(feature = "otel")]
+#[test]
+fn span_ids() {
+    use opentelemetry::{
+        Context,
+        trace::{SpanContext, SpanId, TraceContextExt, TraceFlags, TraceId, TraceState},
+    };
+
+    let trace_id = TraceId::from_hex("4bf92f3577b34da6a3ce929d0e0e4736").unwrap();
+    let span_id = SpanId::from_hex("00f067aa0ba902b7").unwrap();
+    let _guard = Context::current()
+        .with_remote_span_context(SpanContext::new(
+            trace_id,
+            span_id,
+            TraceFlags::SAMPLED,
+            true,
+            TraceState::default(),
+        ))
+        .attach();
+
+    let domain = MyDomainError.to_domain_error();
+    assert_eq!(domain.trace_id().unwrap(), trace_id);
+    assert_eq!(domain.span_id().unwrap(), span_id);
+    assert_eq!(
+        domain.to_string(),
+        r#"{"http_status_code":400,"context":"context","public":{"foo":"foo","bar":42},"source":"MyDomainError","trace_id":"4bf92f3577b34da6a3ce929d0e0e4736","span_id":"00f067aa0ba902b7"}"#
     );
 }
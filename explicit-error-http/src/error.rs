@@ -1,7 +1,10 @@
 use crate::Error;
 use erased_serde::Serialize as DynSerialize;
-use http::StatusCode;
-use serde::{Serialize, Serializer};
+use explicit_error::Extensions;
+use http::{HeaderMap, HeaderName, HeaderValue, StatusCode, Uri};
+use problem_details::ProblemDetails;
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+use std::any::Any;
 
 /// Self-sufficient container to both log an error and generate its HTTP response.
 ///
@@ -63,8 +66,7 @@ use serde::{Serialize, Serializer};
 /// use explicit_error_http::{prelude::*, HttpError, Error};
 ///
 /// fn forbidden() -> HttpError {
-///     HttpError::new(
-///         StatusCode::FORBIDDEN,
+///     HttpError::forbidden(
 ///         ProblemDetails::new()
 ///             .with_type(Uri::from_static("/errors/generic#forbidden"))
 ///             .with_title("Forbidden."),
@@ -80,14 +82,171 @@ use serde::{Serialize, Serializer};
 ///     # Ok(())
 /// }
 /// ```
-#[derive(Serialize)]
+///
+/// [HttpError] implements [Clone] so it can be used as a template, the public body must therefore be [Clone] too.
+///
+/// It is `#[non_exhaustive]` so fields can be added without breaking changes: generate it with [new](HttpError::new)
+/// or its shortcuts and use the accessors.
+#[derive(Serialize, Clone)]
+#[non_exhaustive]
 pub struct HttpError {
     #[serde(skip)]
     pub http_status_code: StatusCode,
     #[serde(flatten)]
-    pub public: Box<dyn DynSerialize + Send + Sync>,
+    pub(crate) public: Body,
     #[serde(skip)]
     pub context: Option<String>,
+    #[serde(skip)]
+    pub trailers: Option<Box<HeaderMap>>,
+    #[serde(skip)]
+    pub severity: Option<Severity>,
+    #[serde(skip)]
+    pub headers: Option<Box<HeaderMap>>,
+    #[serde(skip)]
+    pub(crate) extensions: Extensions,
+}
+
+/// Severity of an [HttpError] to choose the level at which it is logged, independently of its HTTP status code.
+///
+/// If not set with [with_severity](HttpError::with_severity), it defaults to [Severity::Error] for
+/// HTTP status codes greater than or equal to 500 and [Severity::Debug] otherwise.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Severity {
+    Trace,
+    Debug,
+    Info,
+    Warn,
+    Error,
+}
+
+/// Type erased public body of an [HttpError]. It is implemented for any type that is [Serialize] and [Clone].
+pub trait DynSerializeClone: DynSerialize + Send + Sync {
+    fn clone_box(&self) -> Box<dyn DynSerializeClone>;
+
+    /// Usefull to downcast the public body to its concrete type
+    fn as_any(&self) -> &dyn Any;
+
+    /// Usefull to downcast the public body to its concrete type
+    fn as_any_mut(&mut self) -> &mut dyn Any;
+}
+
+impl<T> DynSerializeClone for T
+where
+    T: Serialize + Clone + Send + Sync + 'static,
+{
+    fn clone_box(&self) -> Box<dyn DynSerializeClone> {
+        Box::new(self.clone())
+    }
+
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn as_any_mut(&mut self) -> &mut dyn Any {
+        self
+    }
+}
+
+impl Clone for Box<dyn DynSerializeClone> {
+    fn clone(&self) -> Self {
+        self.as_ref().clone_box()
+    }
+}
+
+erased_serde::serialize_trait_object!(DynSerializeClone);
+
+/// Public body of an [HttpError]. A JSON [Value](serde_json::Value) is stored as is to avoid the allocation and the vtable of the trait object.
+#[derive(Clone, Serialize)]
+#[serde(untagged)]
+pub(crate) enum Body {
+    Dyn(Box<dyn DynSerializeClone>),
+    Value(serde_json::Value),
+}
+
+impl Body {
+    fn new<S: Serialize + Clone + 'static + Send + Sync>(public: S) -> Self {
+        let mut public = Some(public);
+        if let Some(value) =
+            (&mut public as &mut dyn Any).downcast_mut::<Option<serde_json::Value>>()
+        {
+            return Body::Value(value.take().unwrap_or_default());
+        }
+
+        Body::Dyn(Box::new(
+            public.expect("the body is moved only if it is a Value"),
+        ))
+    }
+
+    fn as_dyn(&self) -> &dyn DynSerializeClone {
+        match self {
+            Body::Dyn(public) => public.as_ref(),
+            Body::Value(public) => public,
+        }
+    }
+
+    fn as_dyn_mut(&mut self) -> &mut dyn DynSerializeClone {
+        match self {
+            Body::Dyn(public) => public.as_mut(),
+            Body::Value(public) => public,
+        }
+    }
+
+    fn into_dyn(self) -> Box<dyn DynSerializeClone> {
+        match self {
+            Body::Dyn(public) => public,
+            Body::Value(public) => Box::new(public),
+        }
+    }
+}
+
+/// Error of a field of a request that failed validation, see [HttpError::validation].
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct FieldError {
+    pub field: String,
+    pub message: String,
+}
+
+/// [ProblemDetails] extension listing the [FieldError]s of an [HttpError] generated by [validation](HttpError::validation).
+#[derive(Debug, Clone, PartialEq, Eq, Default, Serialize)]
+pub struct FieldErrors {
+    pub errors: Vec<FieldError>,
+}
+
+/// Wrapper marking a public body as problem details ([RFC 9457](https://www.rfc-editor.org/rfc/rfc9457)), the responses generated by
+/// [HandlerErrorHelpers](crate::derive::HandlerErrorHelpers) then have the `application/problem+json` content type.
+///
+/// It is not required for [ProblemDetails] without extensions and bodies generated by [validation](HttpError::validation), they are detected.
+/// # Examples
+/// ```rust
+/// # use explicit_error_http::{HttpError, ProblemJson};
+/// # use problem_details::ProblemDetails;
+/// # use serde::Serialize;
+/// #[derive(Serialize, Clone)]
+/// struct Balance {
+///     balance: u32,
+/// }
+///
+/// let error = HttpError::forbidden(ProblemJson::new(
+///     ProblemDetails::new()
+///         .with_title("You do not have enough credit.")
+///         .with_extensions(Balance { balance: 30 }),
+/// ));
+/// assert_eq!(error.content_type(), "application/problem+json");
+/// ```
+#[derive(Serialize, Clone)]
+#[serde(transparent)]
+pub struct ProblemJson(Box<dyn DynSerializeClone>);
+
+impl ProblemJson {
+    /// Wrap a problem details body
+    pub fn new<S: Serialize + Clone + 'static + Send + Sync>(body: S) -> Self {
+        Self(Box::new(body))
+    }
+
+    /// Return the wrapped body
+    pub fn body(&self) -> &dyn DynSerializeClone {
+        self.0.as_ref()
+    }
 }
 
 impl HttpError {
@@ -108,14 +267,348 @@ impl HttpError {
     ///     )
     /// }
     /// ```
-    pub fn new<S: Serialize + 'static + Send + Sync>(
+    pub fn new<S: Serialize + Clone + 'static + Send + Sync>(
         http_status_code: StatusCode,
         public: S,
     ) -> Self {
         Self {
             http_status_code,
-            public: Box::new(public),
+            public: Body::new(public),
+            context: None,
+            trailers: None,
+            severity: None,
+            headers: None,
+            extensions: Extensions::new(),
+        }
+    }
+
+    /// Generate an [HttpError] with a JSON [Value](serde_json::Value) as public body, eg: for services that already build their bodies dynamically.
+    /// The body is downcastable to [Value](serde_json::Value) and its `detail` or `title`, or the string itself, is the [user_message](HttpError::user_message).
+    ///
+    /// The [Value](serde_json::Value) is stored without the trait object of other bodies, saving an allocation.
+    /// [new](HttpError::new) does the same when it is given a [Value](serde_json::Value).
+    /// # Examples
+    /// ```rust
+    /// # use explicit_error_http::HttpError;
+    /// # use http::StatusCode;
+    /// let error = HttpError::from_value(
+    ///     StatusCode::CONFLICT,
+    ///     serde_json::json!({"title": "Email already used", "email": "foo@bar.com"}),
+    /// );
+    /// assert_eq!(error.user_message(), Some("Email already used"));
+    /// ```
+    pub fn from_value(http_status_code: StatusCode, public: serde_json::Value) -> Self {
+        Self {
+            http_status_code,
+            public: Body::Value(public),
             context: None,
+            trailers: None,
+            severity: None,
+            headers: None,
+            extensions: Extensions::new(),
+        }
+    }
+
+    /// Generate an [HttpError] with the standard [ProblemDetails] body of the status code: its canonical reason as `title`
+    /// and `about:blank` as `type`, which means that the problem has no additional semantics beyond the status code (RFC 9457).
+    /// # Examples
+    /// ```rust
+    /// # use explicit_error_http::HttpError;
+    /// # use http::StatusCode;
+    /// assert_eq!(
+    ///     serde_json::json!(HttpError::from_status(StatusCode::NOT_FOUND)),
+    ///     serde_json::json!({"type": "about:blank", "status": 404, "title": "Not Found"})
+    /// );
+    /// ```
+    pub fn from_status(http_status_code: StatusCode) -> Self {
+        Self::new(
+            http_status_code,
+            ProblemDetails::from_status_code(http_status_code)
+                .with_type(Uri::from_static("about:blank")),
+        )
+    }
+
+    /// Generate a 400 Bad Request [HttpError], see [new](HttpError::new).
+    /// # Examples
+    /// ```rust
+    /// # use explicit_error_http::{Result, HttpError};
+    /// # use problem_details::ProblemDetails;
+    /// # use http::Uri;
+    /// fn validate(name: &str) -> Result<()> {
+    ///     if name.is_empty() {
+    ///         Err(HttpError::bad_request(
+    ///             ProblemDetails::new()
+    ///                 .with_type(Uri::from_static("/errors/validation"))
+    ///                 .with_title("Name is required"),
+    ///         ))?;
+    ///     }
+    ///     Ok(())
+    /// }
+    /// ```
+    pub fn bad_request<S: Serialize + Clone + 'static + Send + Sync>(public: S) -> Self {
+        Self::new(StatusCode::BAD_REQUEST, public)
+    }
+
+    /// Generate a 401 Unauthorized [HttpError], see [new](HttpError::new).
+    pub fn unauthorized<S: Serialize + Clone + 'static + Send + Sync>(public: S) -> Self {
+        Self::new(StatusCode::UNAUTHORIZED, public)
+    }
+
+    /// Generate a 403 Forbidden [HttpError], see [new](HttpError::new).
+    pub fn forbidden<S: Serialize + Clone + 'static + Send + Sync>(public: S) -> Self {
+        Self::new(StatusCode::FORBIDDEN, public)
+    }
+
+    /// Generate a 404 Not Found [HttpError], see [new](HttpError::new).
+    pub fn not_found<S: Serialize + Clone + 'static + Send + Sync>(public: S) -> Self {
+        Self::new(StatusCode::NOT_FOUND, public)
+    }
+
+    /// Generate a 409 Conflict [HttpError], see [new](HttpError::new).
+    pub fn conflict<S: Serialize + Clone + 'static + Send + Sync>(public: S) -> Self {
+        Self::new(StatusCode::CONFLICT, public)
+    }
+
+    /// Generate a 422 Unprocessable Entity [HttpError], see [new](HttpError::new).
+    pub fn unprocessable_entity<S: Serialize + Clone + 'static + Send + Sync>(public: S) -> Self {
+        Self::new(StatusCode::UNPROCESSABLE_ENTITY, public)
+    }
+
+    /// Generate a 422 Unprocessable Entity [HttpError] listing the fields of the request that failed validation. More errors can be
+    /// added with [add_field_error](HttpError::add_field_error). The body is a [ProblemDetails] with an `errors` extension:
+    /// `{"status":422,"title":"Unprocessable Entity","errors":[{"field":"email","message":"must be a valid email"}]}`.
+    ///
+    /// Set [http_status_code](HttpError::http_status_code) to use another status code, eg: 400 Bad Request.
+    /// # Examples
+    /// ```rust
+    /// # use explicit_error_http::{Result, HttpError};
+    /// fn validate(email: &str, age: u8) -> Result<()> {
+    ///     let mut error = HttpError::validation(Vec::<(String, String)>::new());
+    ///
+    ///     if !email.contains('@') {
+    ///         error = error.add_field_error("email", "must be a valid email");
+    ///     }
+    ///     if age < 18 {
+    ///         error = error.add_field_error("age", "must be at least 18");
+    ///     }
+    ///
+    ///     if error.field_errors().is_some_and(|errors| !errors.is_empty()) {
+    ///         Err(error)?;
+    ///     }
+    ///     Ok(())
+    /// }
+    /// ```
+    pub fn validation<F, M>(fields: impl IntoIterator<Item = (F, M)>) -> Self
+    where
+        F: Into<String>,
+        M: Into<String>,
+    {
+        Self::new(
+            StatusCode::UNPROCESSABLE_ENTITY,
+            ProblemDetails::from_status_code(StatusCode::UNPROCESSABLE_ENTITY).with_extensions(
+                FieldErrors {
+                    errors: fields
+                        .into_iter()
+                        .map(|(field, message)| FieldError {
+                            field: field.into(),
+                            message: message.into(),
+                        })
+                        .collect(),
+                },
+            ),
+        )
+    }
+
+    /// Add a [FieldError] to an [HttpError] generated by [validation](HttpError::validation), it has no effect otherwise.
+    pub fn add_field_error(mut self, field: impl Into<String>, message: impl Into<String>) -> Self {
+        if let Some(errors) = self
+            .public
+            .as_dyn_mut()
+            .as_any_mut()
+            .downcast_mut::<ProblemDetails<FieldErrors>>()
+            .and_then(|body| body.extensions.as_mut())
+        {
+            errors.errors.push(FieldError {
+                field: field.into(),
+                message: message.into(),
+            });
+        }
+        self
+    }
+
+    /// Return the [FieldError]s of an [HttpError] generated by [validation](HttpError::validation)
+    pub fn field_errors(&self) -> Option<&[FieldError]> {
+        self.public()
+            .as_any()
+            .downcast_ref::<ProblemDetails<FieldErrors>>()
+            .and_then(|body| body.extensions.as_ref())
+            .map(|errors| errors.errors.as_slice())
+    }
+
+    /// Set the `type` of a [ProblemDetails] public body, override if one was set. It has no effect on other bodies.
+    ///
+    /// Shortcuts [with_type](HttpError::with_type), [with_title](HttpError::with_title), [with_detail](HttpError::with_detail)
+    /// and [with_status](HttpError::with_status) apply to [ProblemDetails], whatever its extensions, [HttpError::validation] bodies
+    /// and [ProblemDetails] wrapped in a [ProblemJson].
+    /// # Examples
+    /// ```rust
+    /// # use explicit_error_http::HttpError;
+    /// # use http::{StatusCode, Uri};
+    /// # use problem_details::ProblemDetails;
+    /// let error = HttpError::bad_request(ProblemDetails::new())
+    ///     .with_status(StatusCode::NOT_FOUND)
+    ///     .with_type(Uri::from_static("/errors/users/not-found"))
+    ///     .with_title("User not found")
+    ///     .with_detail("Unknown user with identifier 42.");
+    ///
+    /// assert_eq!(
+    ///     serde_json::json!(error),
+    ///     serde_json::json!({
+    ///         "type": "/errors/users/not-found",
+    ///         "status": 404,
+    ///         "title": "User not found",
+    ///         "detail": "Unknown user with identifier 42."
+    ///     })
+    /// );
+    /// ```
+    pub fn with_type(mut self, r#type: Uri) -> Self {
+        if let Some(problem) = self.problem_details_mut() {
+            *problem.r#type = Some(r#type);
+        }
+        self
+    }
+
+    /// Set the `title` of a [ProblemDetails] public body, override if one was set. It has no effect on other bodies.
+    pub fn with_title(mut self, title: impl Into<String>) -> Self {
+        if let Some(problem) = self.problem_details_mut() {
+            *problem.title = Some(title.into());
+        }
+        self
+    }
+
+    /// Set the `detail` of a [ProblemDetails] public body, override if one was set. It has no effect on other bodies.
+    pub fn with_detail(mut self, detail: impl Into<String>) -> Self {
+        if let Some(problem) = self.problem_details_mut() {
+            *problem.detail = Some(detail.into());
+        }
+        self
+    }
+
+    /// Set the HTTP status code and, for a [ProblemDetails] public body, its `status` so they are consistent.
+    pub fn with_status(mut self, http_status_code: StatusCode) -> Self {
+        self.http_status_code = http_status_code;
+        if let Some(problem) = self.problem_details_mut() {
+            *problem.status = Some(http_status_code);
+        }
+        self
+    }
+
+    fn problem_details_mut(&mut self) -> Option<ProblemDetailsMut<'_>> {
+        fn fields<Ext>(body: &mut ProblemDetails<Ext>) -> ProblemDetailsMut<'_> {
+            ProblemDetailsMut {
+                r#type: &mut body.r#type,
+                status: &mut body.status,
+                title: &mut body.title,
+                detail: &mut body.detail,
+            }
+        }
+
+        let mut public = self.public.as_dyn_mut().as_any_mut();
+        if public.is::<ProblemJson>() {
+            public = public
+                .downcast_mut::<ProblemJson>()?
+                .0
+                .as_mut()
+                .as_any_mut();
+        }
+
+        if public.is::<ProblemDetails>() {
+            public.downcast_mut::<ProblemDetails>().map(fields)
+        } else {
+            public
+                .downcast_mut::<ProblemDetails<FieldErrors>>()
+                .map(fields)
+        }
+    }
+
+    /// Generate a 500 Internal Server Error [HttpError], see [new](HttpError::new).
+    pub fn internal<S: Serialize + Clone + 'static + Send + Sync>(public: S) -> Self {
+        Self::new(StatusCode::INTERNAL_SERVER_ERROR, public)
+    }
+
+    /// Generate a 412 Precondition Failed [HttpError] with the `ETag` header set to the current entity tag
+    /// of the resource, when an `If-Match` or `If-Unmodified-Since` precondition of the request does not hold.
+    /// The body is an empty JSON object.
+    /// # Examples
+    /// ```rust
+    /// # use explicit_error_http::{Result, HttpError};
+    /// # use http::HeaderValue;
+    /// fn update(if_match: &str, current_etag: &'static str) -> Result<()> {
+    ///     if if_match != current_etag {
+    ///         Err(HttpError::precondition_failed(HeaderValue::from_static(current_etag)))?;
+    ///     }
+    ///     Ok(())
+    /// }
+    /// ```
+    pub fn precondition_failed(etag: HeaderValue) -> Self {
+        Self::from_value(StatusCode::PRECONDITION_FAILED, serde_json::json!({}))
+            .with_header(http::header::ETAG, etag)
+    }
+
+    /// Generate a 304 Not Modified [HttpError] with the `ETag` header set to the current entity tag
+    /// of the resource, when it matches the `If-None-Match` header of the request.
+    /// The response has no body, see [has_body](HttpError::has_body).
+    /// # Examples
+    /// ```rust
+    /// # use explicit_error_http::{Result, HttpError};
+    /// # use http::HeaderValue;
+    /// fn fetch(if_none_match: Option<&str>, current_etag: &'static str) -> Result<()> {
+    ///     if if_none_match == Some(current_etag) {
+    ///         Err(HttpError::not_modified(HeaderValue::from_static(current_etag)))?;
+    ///     }
+    ///     Ok(())
+    /// }
+    /// ```
+    pub fn not_modified(etag: HeaderValue) -> Self {
+        Self::new(StatusCode::NOT_MODIFIED, ()).with_header(http::header::ETAG, etag)
+    }
+
+    /// Return a single human-readable sentence for the user: the `detail`, or else the `title`, of a [ProblemDetails] public body
+    /// or the public body itself if it is a string. It is None for other bodies, including [ProblemDetails] with custom extensions
+    /// except the one of [validation](HttpError::validation).
+    /// # Examples
+    /// ```rust
+    /// # use explicit_error_http::HttpError;
+    /// # use problem_details::ProblemDetails;
+    /// let error = HttpError::not_found(
+    ///     ProblemDetails::new()
+    ///         .with_title("Not found")
+    ///         .with_detail("Unknown user with identifier 42."),
+    /// );
+    /// assert_eq!(error.user_message(), Some("Unknown user with identifier 42."));
+    /// ```
+    pub fn user_message(&self) -> Option<&str> {
+        fn problem_message<Ext>(body: &ProblemDetails<Ext>) -> Option<&str> {
+            body.detail.as_deref().or(body.title.as_deref())
+        }
+
+        let mut public = self.public().as_any();
+        if let Some(body) = public.downcast_ref::<ProblemJson>() {
+            public = body.body().as_any();
+        }
+
+        if let Some(body) = public.downcast_ref::<ProblemDetails>() {
+            problem_message(body)
+        } else if let Some(body) = public.downcast_ref::<ProblemDetails<FieldErrors>>() {
+            problem_message(body)
+        } else if let Some(body) = public.downcast_ref::<&'static str>() {
+            Some(body)
+        } else if let Some(body) = public.downcast_ref::<serde_json::Value>() {
+            body.as_str()
+                .or_else(|| body.get("detail").and_then(serde_json::Value::as_str))
+                .or_else(|| body.get("title").and_then(serde_json::Value::as_str))
+        } else {
+            public.downcast_ref::<String>().map(String::as_str)
         }
     }
 
@@ -148,6 +641,171 @@ impl HttpError {
         self
     }
 
+    /// Add a context to an [HttpError] if there is one, override if one was set.
+    pub fn with_context_opt(self, context: Option<impl std::fmt::Display>) -> Self {
+        match context {
+            Some(context) => self.with_context(context),
+            None => self,
+        }
+    }
+
+    /// Add an HTTP trailer sent after the response body. Usefull to signal an error
+    /// after a streamed body (eg: gRPC over HTTP/2).
+    ///
+    /// Trailers are only emitted with the `axum` feature, actix-web does not support them.
+    /// Note that HTTP/1.1 clients only receive trailers with chunked transfer encoding.
+    /// # Examples
+    /// ```rust
+    /// # use explicit_error_http::HttpError;
+    /// # use http::{StatusCode, HeaderName, HeaderValue};
+    /// HttpError::new(StatusCode::INTERNAL_SERVER_ERROR, "")
+    ///     .with_trailer(HeaderName::from_static("grpc-status"), HeaderValue::from_static("13"));
+    /// ```
+    pub fn with_trailer(mut self, name: HeaderName, value: HeaderValue) -> Self {
+        self.trailers
+            .get_or_insert_with(Default::default)
+            .append(name, value);
+        self
+    }
+
+    /// Add an HTTP header to the response, eg: `ETag`, `Retry-After` or `WWW-Authenticate`.
+    /// # Examples
+    /// ```rust
+    /// # use explicit_error_http::HttpError;
+    /// # use http::{StatusCode, HeaderValue, header};
+    /// HttpError::new(StatusCode::SERVICE_UNAVAILABLE, "")
+    ///     .with_header(header::RETRY_AFTER, HeaderValue::from_static("120"));
+    /// ```
+    pub fn with_header(mut self, name: HeaderName, value: HeaderValue) -> Self {
+        self.headers
+            .get_or_insert_with(Default::default)
+            .append(name, value);
+        self
+    }
+
+    /// Set the `Location` header of the response, override if one was set.
+    ///
+    /// Errors are rarely redirections, it is meant for the few cases where it is, eg: a 303 See Other to the existing
+    /// resource after a conflict on creation.
+    /// # Examples
+    /// ```rust
+    /// # use explicit_error_http::HttpError;
+    /// # use http::{StatusCode, Uri};
+    /// HttpError::new(StatusCode::SEE_OTHER, "")
+    ///     .with_location(Uri::from_static("/orders/42"));
+    /// ```
+    pub fn with_location(mut self, uri: Uri) -> Self {
+        self.headers.get_or_insert_with(Default::default).insert(
+            http::header::LOCATION,
+            HeaderValue::try_from(uri.to_string()).expect("a URI is a valid header value"),
+        );
+        self
+    }
+
+    /// Return false if the HTTP status code forbids a response body (204 No Content and 304 Not Modified),
+    /// the response generated by [HandlerErrorHelpers](crate::derive::HandlerErrorHelpers) is then empty.
+    pub fn has_body(&self) -> bool {
+        !matches!(
+            self.http_status_code,
+            StatusCode::NO_CONTENT | StatusCode::NOT_MODIFIED
+        )
+    }
+
+    /// Return the content type of the responses generated by [HandlerErrorHelpers](crate::derive::HandlerErrorHelpers):
+    /// `application/problem+json` if the public body is a [ProblemDetails], is generated by [validation](HttpError::validation)
+    /// or is wrapped in [ProblemJson], otherwise the one set with [set_error_content_type](crate::set_error_content_type).
+    /// # Examples
+    /// ```rust
+    /// # use explicit_error_http::HttpError;
+    /// # use problem_details::ProblemDetails;
+    /// assert_eq!(
+    ///     HttpError::not_found(ProblemDetails::new()).content_type(),
+    ///     "application/problem+json"
+    /// );
+    /// assert_eq!(HttpError::not_found("Not found").content_type(), "application/json");
+    /// ```
+    pub fn content_type(&self) -> &'static str {
+        let public = self.public().as_any();
+        if public.is::<ProblemJson>()
+            || public.is::<ProblemDetails>()
+            || public.is::<ProblemDetails<FieldErrors>>()
+        {
+            "application/problem+json"
+        } else {
+            crate::error_content_type()
+        }
+    }
+
+    /// Set the [Severity] used to log the [HttpError], override the default one deduced from the HTTP status code.
+    /// # Examples
+    /// ```rust
+    /// # use explicit_error_http::{HttpError, Severity};
+    /// # use http::StatusCode;
+    /// // Rare and unexpected
+    /// HttpError::new(StatusCode::UNPROCESSABLE_ENTITY, "").with_severity(Severity::Warn);
+    /// ```
+    pub fn with_severity(mut self, severity: Severity) -> Self {
+        self.severity = Some(severity);
+        self
+    }
+
+    /// Return the [Severity] set with [with_severity](HttpError::with_severity) or the default one
+    /// deduced from the HTTP status code.
+    pub fn severity(&self) -> Severity {
+        self.severity
+            .unwrap_or(if self.http_status_code.is_server_error() {
+                Severity::Error
+            } else {
+                Severity::Debug
+            })
+    }
+
+    /// Return the HTTP status code
+    pub fn http_status_code(&self) -> StatusCode {
+        self.http_status_code
+    }
+
+    /// Set the HTTP status code, the public body is left untouched. Use [with_status](HttpError::with_status)
+    /// to keep a [ProblemDetails] body consistent.
+    pub fn set_http_status_code(&mut self, http_status_code: StatusCode) {
+        self.http_status_code = http_status_code;
+    }
+
+    /// Return the public body
+    pub fn public(&self) -> &dyn DynSerializeClone {
+        self.public.as_dyn()
+    }
+
+    /// Replace the public body
+    pub fn set_public<S: Serialize + Clone + 'static + Send + Sync>(&mut self, public: S) {
+        self.public = Body::new(public);
+    }
+
+    /// Take the public body, eg: to [split](crate::DomainError::split) the error
+    pub(crate) fn into_public(self) -> Box<dyn DynSerializeClone> {
+        self.public.into_dyn()
+    }
+
+    /// Return the context
+    pub fn context(&self) -> Option<&str> {
+        self.context.as_deref()
+    }
+
+    /// Set or remove the context
+    pub fn set_context(&mut self, context: Option<impl std::fmt::Display>) {
+        self.context = context.map(|context| context.to_string());
+    }
+
+    /// Return the headers added with [with_header](HttpError::with_header)
+    pub fn headers(&self) -> Option<&HeaderMap> {
+        self.headers.as_deref()
+    }
+
+    /// Return the trailers added with [with_trailer](HttpError::with_trailer)
+    pub fn trailers(&self) -> Option<&HeaderMap> {
+        self.trailers.as_deref()
+    }
+
     /// Add a source to an [HttpError] by converting it on the fly to a [crate::DomainError]
     /// # Example
     /// ```rust
@@ -171,12 +829,148 @@ impl HttpError {
     }
 }
 
+/// Internally used by [HttpError](crate::derive::HttpError) derive for the variants of `#[explicit_error(map(..))]`.
+/// The status code is checked by the derive.
+#[doc(hidden)]
+pub fn mapped_http_error(
+    http_status_code: u16,
+    r#type: &'static str,
+    title: &'static str,
+) -> HttpError {
+    let http_status_code =
+        StatusCode::from_u16(http_status_code).unwrap_or(StatusCode::INTERNAL_SERVER_ERROR);
+
+    HttpError::new(http_status_code, ProblemDetails::new())
+        .with_status(http_status_code)
+        .with_type(Uri::from_static(r#type))
+        .with_title(title)
+}
+
+/// Internally used by [problem_type!](crate::problem_type), the URI is checked by the macro.
+#[doc(hidden)]
+pub fn problem_type_uri(r#type: &'static str) -> Uri {
+    Uri::from_static(r#type)
+}
+
+/// Internally used by [problem_type!](crate::problem_type) to check at compile time that the type is registered.
+#[doc(hidden)]
+pub const fn problem_type_registered(registry: &[&str], r#type: &str) -> bool {
+    let mut i = 0;
+    while i < registry.len() {
+        let registered = registry[i].as_bytes();
+        let r#type = r#type.as_bytes();
+        if registered.len() == r#type.len() {
+            let mut j = 0;
+            while j < r#type.len() && registered[j] == r#type[j] {
+                j += 1;
+            }
+            if j == r#type.len() {
+                return true;
+            }
+        }
+        i += 1;
+    }
+    false
+}
+
+/// To use this trait on [Error](crate::Error) import the prelude `use explicit_error_http::prelude::*`
+pub trait ErrorStatusCode {
+    /// Return the HTTP status code of the response: the one of the [HttpError] for domain errors, 500 for faults.
+    /// # Examples
+    /// ```rust
+    /// # use explicit_error_http::{prelude::*, Error, Fault, HttpError};
+    /// # use http::StatusCode;
+    /// assert_eq!(Error::from(HttpError::not_found("")).status_code(), StatusCode::NOT_FOUND);
+    /// assert_eq!(Error::from(Fault::new()).status_code(), StatusCode::INTERNAL_SERVER_ERROR);
+    /// ```
+    fn status_code(&self) -> StatusCode;
+
+    /// Override the HTTP status code of the response, eg: always 503 Service Unavailable during maintenance.
+    /// The [HttpError] of domain errors is rewritten with [with_status](HttpError::with_status) while faults become the source of
+    /// a domain error with this status and a generic [ProblemDetails] body.
+    /// # Examples
+    /// ```rust
+    /// # use explicit_error_http::{prelude::*, Error, Fault, HttpError};
+    /// # use http::StatusCode;
+    /// let error = Error::from(Fault::new()).with_status(StatusCode::SERVICE_UNAVAILABLE);
+    /// assert!(error.is_domain());
+    /// assert_eq!(error.status_code(), StatusCode::SERVICE_UNAVAILABLE);
+    /// ```
+    fn with_status(self, http_status_code: StatusCode) -> Self;
+
+    /// Decompose into the HTTP status code, the public body and the source, eg: to build a response without a [HandlerError](crate::HandlerError).
+    /// Domain errors are [split](crate::DomainError::split) while faults give a 500 Internal Server Error, a generic
+    /// [ProblemDetails] body and the [Fault](crate::Fault) itself as source to be logged.
+    /// # Examples
+    /// ```rust
+    /// # use explicit_error_http::{prelude::*, Error, Fault};
+    /// # use http::StatusCode;
+    /// let (status, body, source) = Error::from(Fault::new()).split();
+    /// assert_eq!(status, StatusCode::INTERNAL_SERVER_ERROR);
+    /// assert_eq!(serde_json::json!(body)["status"], 500);
+    /// assert!(source.unwrap().is::<Fault>());
+    /// ```
+    #[allow(clippy::type_complexity)]
+    fn split(
+        self,
+    ) -> (
+        StatusCode,
+        Box<dyn DynSerializeClone>,
+        Option<Box<dyn std::error::Error + Send + Sync>>,
+    );
+}
+
+impl ErrorStatusCode for Error {
+    fn status_code(&self) -> StatusCode {
+        match self {
+            Error::Domain(d) => d.output.http_status_code,
+            Error::Fault(_) => StatusCode::INTERNAL_SERVER_ERROR,
+        }
+    }
+
+    fn with_status(self, http_status_code: StatusCode) -> Self {
+        match self {
+            Error::Domain(mut d) => {
+                d.output = d.output.with_status(http_status_code);
+                Error::Domain(d)
+            }
+            Error::Fault(fault) => Error::new_domain(super::DomainError {
+                output: HttpError::new(
+                    http_status_code,
+                    ProblemDetails::from_status_code(http_status_code),
+                )
+                .with_context_opt(fault.context()),
+                source: Some(Box::new(fault)),
+            }),
+        }
+    }
+
+    fn split(
+        self,
+    ) -> (
+        StatusCode,
+        Box<dyn DynSerializeClone>,
+        Option<Box<dyn std::error::Error + Send + Sync>>,
+    ) {
+        match self {
+            Error::Domain(d) => d.split(),
+            Error::Fault(fault) => (
+                StatusCode::INTERNAL_SERVER_ERROR,
+                Box::new(ProblemDetails::from_status_code(
+                    StatusCode::INTERNAL_SERVER_ERROR,
+                )),
+                Some(Box::new(fault)),
+            ),
+        }
+    }
+}
+
 impl From<HttpError> for Error {
     fn from(value: HttpError) -> Self {
-        Error::Domain(Box::new(super::DomainError {
+        Error::new_domain(super::DomainError {
             output: value,
             source: None,
-        }))
+        })
     }
 }
 
@@ -184,38 +978,117 @@ impl PartialEq for HttpError {
     fn eq(&self, other: &Self) -> bool {
         self.context == other.context
             && self.http_status_code == other.http_status_code
-            && serde_json::json!(self.public) == serde_json::json!(other)
+            && self.trailers == other.trailers
+            && self.severity == other.severity
+            && self.headers == other.headers
+            && serde_json::json!(self.public) == serde_json::json!(other.public)
     }
 }
 
+/// Fields are serialized in this order, keep it stable as log ingestion may rely on it
 #[derive(Serialize)]
 pub(crate) struct HttpErrorDisplay<'s> {
     #[serde(serialize_with = "serialize_http_status_code")]
     pub http_status_code: http::StatusCode,
-    pub public: &'s dyn DynSerialize,
     pub context: Option<&'s str>,
+    pub public: &'s dyn DynSerialize,
 }
 
 impl<'s> From<&'s HttpError> for HttpErrorDisplay<'s> {
     fn from(value: &'s HttpError) -> Self {
         Self {
             http_status_code: value.http_status_code,
-            public: value.public.as_ref(),
             context: value.context.as_deref(),
+            public: value.public(),
+        }
+    }
+}
+
+/// Owned and deserializable mirror of an [HttpError], useful to compare errors in tests without brittle string comparisons.
+///
+/// It deserializes from the JSON [Display](std::fmt::Display) of an [HttpError] or of a [DomainError](crate::DomainError).
+/// # Examples
+/// ```rust
+/// # use explicit_error_http::{HttpError, HttpErrorSnapshot};
+/// let error = HttpError::not_found("User not found").with_context("user 42");
+///
+/// let snapshot: HttpErrorSnapshot = serde_json::from_str(&error.to_string()).unwrap();
+/// assert_eq!(snapshot, HttpErrorSnapshot::from(&error));
+/// assert_eq!(snapshot.public, serde_json::json!("User not found"));
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct HttpErrorSnapshot {
+    #[serde(
+        serialize_with = "serialize_http_status_code",
+        deserialize_with = "deserialize_http_status_code"
+    )]
+    pub http_status_code: StatusCode,
+    pub context: Option<String>,
+    pub public: serde_json::Value,
+}
+
+impl From<&HttpError> for HttpErrorSnapshot {
+    fn from(value: &HttpError) -> Self {
+        Self {
+            http_status_code: value.http_status_code,
+            context: value.context.clone(),
+            public: serde_json::json!(value.public),
         }
     }
 }
 
+impl From<&crate::DomainError> for HttpErrorSnapshot {
+    fn from(value: &crate::DomainError) -> Self {
+        Self::from(&value.output)
+    }
+}
+
+/// Fields of a [ProblemDetails] body shared whatever its extensions
+struct ProblemDetailsMut<'s> {
+    r#type: &'s mut Option<Uri>,
+    status: &'s mut Option<StatusCode>,
+    title: &'s mut Option<String>,
+    detail: &'s mut Option<String>,
+}
+
+/// Display the [HttpError] as JSON, with the fields `http_status_code`, `context` and `public` in this order. With the alternate flag, `{:#}`, it is a plain text one-liner `{status} {title}: {context}`
+/// friendlier in a terminal or in non-JSON logs. The title is read from the `title` field of the public body, or is the body itself if it is a string.
 impl std::fmt::Display for HttpError {
     fn fmt<'s>(&'s self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        if f.alternate() {
+            return self.display_plain(f);
+        }
+
         write!(
             f,
             "{}",
-            serde_json::json!(HttpErrorDisplay::<'s>::from(self))
+            serde_json::to_string(&HttpErrorDisplay::<'s>::from(self))
+                .map_err(|_| std::fmt::Error)?
         )
     }
 }
 
+impl HttpError {
+    pub(crate) fn display_plain(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.http_status_code)?;
+
+        let public = serde_json::json!(self.public);
+        let title = match &public {
+            serde_json::Value::String(title) => Some(title.as_str()),
+            public => public.get("title").and_then(serde_json::Value::as_str),
+        };
+        if let Some(title) = title.filter(|title| !title.is_empty()) {
+            write!(f, " {title}")?;
+        }
+
+        if let Some(context) = &self.context {
+            write!(f, ": {context}")?;
+        }
+
+        Ok(())
+    }
+}
+
 impl std::fmt::Debug for HttpError {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(f, "HttpError{}", self)
@@ -232,5 +1105,12 @@ where
     s.serialize_u16(status_code.as_u16())
 }
 
+fn deserialize_http_status_code<'de, D>(d: D) -> Result<StatusCode, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    StatusCode::from_u16(u16::deserialize(d)?).map_err(serde::de::Error::custom)
+}
+
 #[cfg(test)]
 mod test;
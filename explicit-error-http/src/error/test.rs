@@ -1,7 +1,7 @@
 use super::*;
 use http::StatusCode;
 
-#[derive(Serialize)]
+#[derive(Serialize, Clone)]
 struct ErrorBody {
     foo: &'static str,
     bar: i64,
@@ -24,15 +24,136 @@ fn new() {
     );
 }
 
+#[test]
+fn status_code_constructors() {
+    assert_eq!(
+        HttpError::bad_request("").http_status_code,
+        StatusCode::BAD_REQUEST
+    );
+    assert_eq!(
+        HttpError::unauthorized("").http_status_code,
+        StatusCode::UNAUTHORIZED
+    );
+    assert_eq!(
+        HttpError::forbidden("").http_status_code,
+        StatusCode::FORBIDDEN
+    );
+    assert_eq!(
+        HttpError::not_found("").http_status_code,
+        StatusCode::NOT_FOUND
+    );
+    assert_eq!(
+        HttpError::conflict("").http_status_code,
+        StatusCode::CONFLICT
+    );
+    assert_eq!(
+        HttpError::unprocessable_entity("").http_status_code,
+        StatusCode::UNPROCESSABLE_ENTITY
+    );
+    assert_eq!(
+        HttpError::internal("").http_status_code,
+        StatusCode::INTERNAL_SERVER_ERROR
+    );
+}
+
+#[test]
+fn status_code() {
+    assert_eq!(
+        Error::from(HttpError::new(StatusCode::CONFLICT, "")).status_code(),
+        StatusCode::CONFLICT
+    );
+    assert_eq!(
+        Error::from(explicit_error::Fault::new()).status_code(),
+        StatusCode::INTERNAL_SERVER_ERROR
+    );
+}
+
+#[test]
+fn error_with_status() {
+    let error = Error::from(HttpError::not_found(ProblemDetails::new()))
+        .with_status(StatusCode::SERVICE_UNAVAILABLE);
+    assert_eq!(error.status_code(), StatusCode::SERVICE_UNAVAILABLE);
+    assert_eq!(
+        serde_json::json!(error.unwrap().output)["status"],
+        StatusCode::SERVICE_UNAVAILABLE.as_u16()
+    );
+
+    let error = Error::from(
+        explicit_error::Fault::new()
+            .with_source(std::io::Error::other("io"))
+            .with_context("context"),
+    )
+    .with_status(StatusCode::SERVICE_UNAVAILABLE);
+    assert_eq!(error.status_code(), StatusCode::SERVICE_UNAVAILABLE);
+    assert_eq!(error.context(), Some("context"));
+    let domain = error.unwrap();
+    assert_eq!(
+        serde_json::json!(domain.output),
+        serde_json::json!(ProblemDetails::from_status_code(
+            StatusCode::SERVICE_UNAVAILABLE
+        ))
+    );
+    assert!(
+        domain
+            .source
+            .unwrap()
+            .downcast::<explicit_error::Fault>()
+            .is_ok()
+    );
+}
+
+#[test]
+fn error_split() {
+    let (status, body, source) = Error::from(
+        HttpError::conflict(ErrorBody {
+            foo: "foo",
+            bar: 42,
+        })
+        .with_source(std::io::Error::other("io")),
+    )
+    .split();
+    assert_eq!(status, StatusCode::CONFLICT);
+    assert_eq!(
+        serde_json::json!(body),
+        serde_json::json!({"foo": "foo", "bar": 42})
+    );
+    assert_eq!(source.unwrap().to_string(), "io");
+
+    let (status, body, source) =
+        Error::from(explicit_error::Fault::new().with_context("context")).split();
+    assert_eq!(status, StatusCode::INTERNAL_SERVER_ERROR);
+    assert_eq!(
+        serde_json::json!(body),
+        serde_json::json!(ProblemDetails::from_status_code(
+            StatusCode::INTERNAL_SERVER_ERROR
+        ))
+    );
+    assert_eq!(
+        source
+            .unwrap()
+            .downcast::<explicit_error::Fault>()
+            .unwrap()
+            .context(),
+        Some("context")
+    );
+
+    let (_, _, source) = Error::from(HttpError::conflict("")).split();
+    assert!(source.is_none());
+}
+
 #[test]
 fn with_context() {
     let error = HttpError {
         http_status_code: StatusCode::BAD_REQUEST,
-        public: Box::new(ErrorBody {
+        public: Body::Dyn(Box::new(ErrorBody {
             foo: "foo",
             bar: 42,
-        }),
+        })),
         context: None,
+        trailers: None,
+        severity: None,
+        headers: None,
+        extensions: Extensions::new(),
     }
     .with_context("context");
     assert_eq!(error.context.as_deref().unwrap(), "context");
@@ -42,15 +163,142 @@ fn with_context() {
     )
 }
 
+#[test]
+fn with_context_opt() {
+    let error = HttpError::bad_request("").with_context_opt(Some("context"));
+    assert_eq!(error.context.as_deref(), Some("context"));
+    assert_eq!(
+        error.with_context_opt(None::<&str>).context.as_deref(),
+        Some("context")
+    );
+}
+
+#[test]
+fn with_trailer() {
+    let error = HttpError::new(StatusCode::BAD_REQUEST, "")
+        .with_trailer(
+            http::HeaderName::from_static("grpc-status"),
+            http::HeaderValue::from_static("13"),
+        )
+        .with_trailer(
+            http::HeaderName::from_static("grpc-message"),
+            http::HeaderValue::from_static("internal"),
+        );
+    let trailers = error.trailers.unwrap();
+    assert_eq!(trailers.len(), 2);
+    assert_eq!(trailers.get("grpc-status").unwrap(), "13");
+    assert_eq!(trailers.get("grpc-message").unwrap(), "internal");
+}
+
+#[test]
+fn with_header() {
+    let error = HttpError::new(StatusCode::SERVICE_UNAVAILABLE, "").with_header(
+        http::header::RETRY_AFTER,
+        http::HeaderValue::from_static("120"),
+    );
+    assert_eq!(
+        error
+            .headers
+            .unwrap()
+            .get(http::header::RETRY_AFTER)
+            .unwrap(),
+        "120"
+    );
+}
+
+#[test]
+fn conditional_request_constructors() {
+    let error = HttpError::precondition_failed(http::HeaderValue::from_static("\"33a64df5\""));
+    assert_eq!(error.http_status_code, StatusCode::PRECONDITION_FAILED);
+    assert_eq!(
+        error
+            .headers
+            .as_ref()
+            .unwrap()
+            .get(http::header::ETAG)
+            .unwrap(),
+        "\"33a64df5\""
+    );
+    assert!(error.has_body());
+    assert_eq!(serde_json::json!(error), serde_json::json!({}));
+    assert_eq!(serde_json::to_string(error.public()).unwrap(), "{}");
+
+    let error = HttpError::not_modified(http::HeaderValue::from_static("W/\"33a64df5\""));
+    assert_eq!(error.http_status_code, StatusCode::NOT_MODIFIED);
+    assert_eq!(
+        error
+            .headers
+            .as_ref()
+            .unwrap()
+            .get(http::header::ETAG)
+            .unwrap(),
+        "W/\"33a64df5\""
+    );
+    assert!(!error.has_body());
+}
+
+#[test]
+fn with_severity() {
+    let error = HttpError::new(StatusCode::NOT_FOUND, "").with_severity(Severity::Warn);
+    assert_eq!(error.severity, Some(Severity::Warn));
+    assert_eq!(
+        error.with_severity(Severity::Trace).severity,
+        Some(Severity::Trace)
+    );
+}
+
+#[test]
+fn severity() {
+    assert_eq!(
+        HttpError::new(StatusCode::NOT_FOUND, "").severity(),
+        Severity::Debug
+    );
+    assert_eq!(
+        HttpError::new(StatusCode::SERVICE_UNAVAILABLE, "").severity(),
+        Severity::Error
+    );
+    assert_eq!(
+        HttpError::new(StatusCode::UNPROCESSABLE_ENTITY, "")
+            .with_severity(Severity::Warn)
+            .severity(),
+        Severity::Warn
+    );
+    assert_eq!(
+        HttpError::new(StatusCode::INTERNAL_SERVER_ERROR, "")
+            .with_severity(Severity::Info)
+            .severity(),
+        Severity::Info
+    );
+}
+
+#[test]
+fn clone() {
+    let error = HttpError::new(
+        StatusCode::BAD_REQUEST,
+        ErrorBody {
+            foo: "foo",
+            bar: 42,
+        },
+    )
+    .with_context("context")
+    .with_severity(Severity::Warn);
+
+    assert_eq!(error.clone(), error);
+}
+
 #[test]
 fn with_source() {
     let error = HttpError {
         http_status_code: StatusCode::BAD_REQUEST,
-        public: Box::new(ErrorBody {
+        public: Body::Dyn(Box::new(ErrorBody {
             foo: "foo",
             bar: 42,
-        }),
+        })),
         context: None,
+        trailers: None,
+        severity: None,
+        headers: None,
+        extensions: Extensions::new(),
     }
     .with_source(sqlx::Error::RowNotFound);
 
@@ -64,21 +312,29 @@ fn with_source() {
 fn from_http_error_for_error() {
     let domain_error = crate::Error::from(HttpError {
         http_status_code: StatusCode::BAD_REQUEST,
-        public: Box::new(ErrorBody {
+        public: Body::Dyn(Box::new(ErrorBody {
             foo: "foo",
             bar: 42,
-        }),
+        })),
         context: None,
+        trailers: None,
+        severity: None,
+        headers: None,
+        extensions: Extensions::new(),
     })
     .unwrap();
     assert_eq!(
         HttpError {
             http_status_code: StatusCode::BAD_REQUEST,
-            public: Box::new(ErrorBody {
+            public: Body::Dyn(Box::new(ErrorBody {
                 foo: "foo",
                 bar: 42,
-            }),
+            })),
             context: None,
+            trailers: None,
+            severity: None,
+            headers: None,
+            extensions: Extensions::new(),
         },
         domain_error.output
     );
@@ -90,11 +346,15 @@ fn serialize() {
     assert_eq!(
         serde_json::json!(HttpError {
             http_status_code: StatusCode::BAD_REQUEST,
-            public: Box::new(ErrorBody {
+            public: Body::Dyn(Box::new(ErrorBody {
                 foo: "foo",
                 bar: 42
-            }),
-            context: Some("context".to_string())
+            })),
+            context: Some("context".to_string()),
+            trailers: None,
+            severity: None,
+            headers: None,
+            extensions: Extensions::new(),
         })
         .to_string(),
         r#"{"bar":42,"foo":"foo"}"#.to_string()
@@ -105,17 +365,305 @@ fn serialize() {
 fn display() {
     let error = HttpError {
         http_status_code: StatusCode::BAD_REQUEST,
-        public: Box::new(ErrorBody {
+        public: Body::Dyn(Box::new(ErrorBody {
             foo: "foo",
             bar: 42,
-        }),
+        })),
         context: Some("context".to_string()),
+        trailers: None,
+        severity: None,
+        headers: None,
+        extensions: Extensions::new(),
     }
     .to_string();
 
     assert_eq!(
         error,
-        r#"{"context":"context","http_status_code":400,"public":{"bar":42,"foo":"foo"}}"#
+        r#"{"http_status_code":400,"context":"context","public":{"foo":"foo","bar":42}}"#
             .to_string()
     );
 }
+
+#[test]
+fn display_alternate() {
+    let error =
+        HttpError::not_found(problem_details::ProblemDetails::new().with_title("User not found"))
+            .with_context("user_id: 42");
+    assert_eq!(
+        format!("{error:#}"),
+        "404 Not Found User not found: user_id: 42"
+    );
+
+    let error = HttpError::forbidden("Forbidden access");
+    assert_eq!(format!("{error:#}"), "403 Forbidden Forbidden access");
+
+    let error = HttpError::bad_request(ErrorBody {
+        foo: "foo",
+        bar: 42,
+    })
+    .with_context("context");
+    assert_eq!(format!("{error:#}"), "400 Bad Request: context");
+    assert_eq!(
+        format!("{error}"),
+        r#"{"http_status_code":400,"context":"context","public":{"foo":"foo","bar":42}}"#
+    );
+}
+
+#[test]
+fn validation() {
+    let error = HttpError::validation(vec![("email", "must be a valid email")])
+        .add_field_error("age", "must be at least 18");
+
+    assert_eq!(error.http_status_code, StatusCode::UNPROCESSABLE_ENTITY);
+    assert_eq!(
+        error.field_errors().unwrap(),
+        [
+            FieldError {
+                field: "email".to_string(),
+                message: "must be a valid email".to_string(),
+            },
+            FieldError {
+                field: "age".to_string(),
+                message: "must be at least 18".to_string(),
+            },
+        ]
+    );
+    assert_eq!(
+        serde_json::json!(error),
+        serde_json::json!({
+            "status": 422,
+            "title": "Unprocessable Entity",
+            "errors": [
+                {"field": "email", "message": "must be a valid email"},
+                {"field": "age", "message": "must be at least 18"},
+            ],
+        })
+    );
+
+    let error = HttpError::bad_request("").add_field_error("age", "must be at least 18");
+    assert!(error.field_errors().is_none());
+}
+
+#[test]
+fn from_status() {
+    for status in [
+        StatusCode::BAD_REQUEST,
+        StatusCode::NOT_FOUND,
+        StatusCode::CONFLICT,
+        StatusCode::TOO_MANY_REQUESTS,
+        StatusCode::SERVICE_UNAVAILABLE,
+    ] {
+        let error = HttpError::from_status(status);
+        assert_eq!(error.http_status_code, status);
+        assert_eq!(
+            serde_json::json!(error),
+            serde_json::json!({
+                "type": "about:blank",
+                "status": status.as_u16(),
+                "title": status.canonical_reason().unwrap(),
+            })
+        );
+    }
+}
+
+#[test]
+fn from_value() {
+    let error = HttpError::from_value(
+        StatusCode::CONFLICT,
+        serde_json::json!({"title": "Conflict", "detail": "Email already used"}),
+    );
+    assert_eq!(error.http_status_code, StatusCode::CONFLICT);
+    assert_eq!(
+        serde_json::json!(error),
+        serde_json::json!({"title": "Conflict", "detail": "Email already used"})
+    );
+    assert!(matches!(error.public, Body::Value(_)));
+    assert!(matches!(
+        HttpError::new(StatusCode::CONFLICT, serde_json::json!("Conflict")).public,
+        Body::Value(_)
+    ));
+    assert!(matches!(
+        HttpError::new(StatusCode::CONFLICT, ProblemDetails::new()).public,
+        Body::Dyn(_)
+    ));
+    assert!(
+        error
+            .public()
+            .as_any()
+            .downcast_ref::<serde_json::Value>()
+            .is_some()
+    );
+    assert_eq!(error.user_message(), Some("Email already used"));
+    assert_eq!(
+        HttpError::from_value(
+            StatusCode::CONFLICT,
+            serde_json::json!({"title": "Conflict"})
+        )
+        .user_message(),
+        Some("Conflict")
+    );
+    assert_eq!(
+        HttpError::from_value(StatusCode::CONFLICT, serde_json::json!("Conflict")).user_message(),
+        Some("Conflict")
+    );
+    assert_eq!(
+        HttpError::from_value(StatusCode::CONFLICT, serde_json::json!(42)).user_message(),
+        None
+    );
+}
+
+#[test]
+fn user_message() {
+    assert_eq!(
+        HttpError::not_found(
+            ProblemDetails::new()
+                .with_title("Not found")
+                .with_detail("Unknown user")
+        )
+        .user_message(),
+        Some("Unknown user")
+    );
+    assert_eq!(
+        HttpError::not_found(ProblemDetails::new().with_title("Not found")).user_message(),
+        Some("Not found")
+    );
+    assert_eq!(
+        HttpError::validation(vec![("age", "must be at least 18")]).user_message(),
+        Some("Unprocessable Entity")
+    );
+    assert_eq!(
+        HttpError::forbidden("Forbidden access").user_message(),
+        Some("Forbidden access")
+    );
+    assert_eq!(
+        HttpError::forbidden("Forbidden access".to_string()).user_message(),
+        Some("Forbidden access")
+    );
+    assert_eq!(
+        HttpError::bad_request(ErrorBody {
+            foo: "foo",
+            bar: 42
+        })
+        .user_message(),
+        None
+    );
+    assert_eq!(
+        crate::Error::from(HttpError::forbidden("Forbidden access")).user_message(),
+        Some("Forbidden access")
+    );
+}
+
+#[test]
+fn snapshot() {
+    let error = HttpError::bad_request(ErrorBody {
+        foo: "foo",
+        bar: 42,
+    })
+    .with_context("context");
+    let snapshot = HttpErrorSnapshot::from(&error);
+
+    assert_eq!(
+        snapshot,
+        HttpErrorSnapshot {
+            http_status_code: StatusCode::BAD_REQUEST,
+            public: serde_json::json!({"foo": "foo", "bar": 42}),
+            context: Some("context".to_string()),
+        }
+    );
+    assert_eq!(
+        serde_json::from_str::<HttpErrorSnapshot>(&error.to_string()).unwrap(),
+        snapshot
+    );
+    assert_eq!(
+        serde_json::from_value::<HttpErrorSnapshot>(serde_json::json!(snapshot)).unwrap(),
+        snapshot
+    );
+
+    let domain_error = crate::DomainError {
+        output: error,
+        source: Some(Box::new(std::io::Error::other("io"))),
+    };
+    assert_eq!(
+        serde_json::from_str::<HttpErrorSnapshot>(&domain_error.to_string()).unwrap(),
+        HttpErrorSnapshot::from(&domain_error)
+    );
+
+    assert!(
+        serde_json::from_str::<HttpErrorSnapshot>(
+            r#"{"http_status_code":42,"public":null,"context":null}"#
+        )
+        .is_err()
+    );
+}
+
+#[test]
+fn problem_details_shortcuts() {
+    let error = HttpError::not_found(
+        ProblemDetails::new()
+            .with_type(http::Uri::from_static("/errors/not-found"))
+            .with_title("Not found")
+            .with_detail("Unknown user."),
+    )
+    .with_type(http::Uri::from_static("/errors/users/not-found"))
+    .with_title("User not found")
+    .with_detail("Unknown user with identifier 42.")
+    .with_status(StatusCode::GONE);
+    assert_eq!(error.http_status_code, StatusCode::GONE);
+    assert_eq!(
+        serde_json::json!(error),
+        serde_json::json!({
+            "type": "/errors/users/not-found",
+            "status": 410,
+            "title": "User not found",
+            "detail": "Unknown user with identifier 42."
+        })
+    );
+
+    let error = HttpError::validation(vec![("age", "must be at least 18")])
+        .with_detail("Invalid user.")
+        .add_field_error("name", "is required");
+    assert_eq!(error.user_message(), Some("Invalid user."));
+    assert_eq!(error.field_errors().unwrap().len(), 2);
+
+    let error = HttpError::bad_request(crate::ProblemJson::new(ProblemDetails::new()))
+        .with_title("Bad request");
+    assert_eq!(error.user_message(), Some("Bad request"));
+
+    let error = HttpError::bad_request("Bad request")
+        .with_title("Ignored")
+        .with_status(StatusCode::CONFLICT);
+    assert_eq!(error.http_status_code, StatusCode::CONFLICT);
+    assert_eq!(error.user_message(), Some("Bad request"));
+}
+
+#[test]
+fn accessors() {
+    let mut error = HttpError::bad_request("Bad request")
+        .with_context("context")
+        .with_header(
+            http::header::RETRY_AFTER,
+            http::HeaderValue::from_static("120"),
+        );
+    assert_eq!(error.http_status_code(), StatusCode::BAD_REQUEST);
+    assert_eq!(error.context(), Some("context"));
+    assert_eq!(
+        error
+            .headers()
+            .unwrap()
+            .get(http::header::RETRY_AFTER)
+            .unwrap(),
+        "120"
+    );
+    assert!(error.trailers().is_none());
+    assert_eq!(
+        error.public().as_any().downcast_ref::<&str>(),
+        Some(&"Bad request")
+    );
+
+    error.set_http_status_code(StatusCode::CONFLICT);
+    error.set_public("Conflict");
+    error.set_context(None::<String>);
+    assert_eq!(error.http_status_code(), StatusCode::CONFLICT);
+    assert_eq!(error.user_message(), Some("Conflict"));
+    assert_eq!(error.context(), None);
+}
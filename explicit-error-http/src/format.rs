@@ -0,0 +1,144 @@
+use serde::Serialize;
+use std::future::Future;
+
+tokio::task_local! {
+    pub(crate) static ERROR_BODY_FORMAT: ErrorBodyFormat;
+}
+
+/// Format of the body of error responses generated by [HandlerErrorHelpers](crate::derive::HandlerErrorHelpers),
+/// negotiated from the `Accept` header of the request. Enabled with the `cbor` and `msgpack` features.
+///
+/// Use the middleware of your web framework to negotiate it:
+/// [axum::negotiate_error_body](crate::axum::negotiate_error_body) or [actix::negotiate_error_body](crate::actix::negotiate_error_body).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum ErrorBodyFormat {
+    /// Serialized with [serde_json] using the [error content type](crate::set_error_content_type)
+    #[default]
+    Json,
+    /// Serialized with [ciborium], `application/cbor`
+    #[cfg(feature = "cbor")]
+    Cbor,
+    /// Serialized with [rmp_serde], `application/msgpack`
+    #[cfg(feature = "msgpack")]
+    MessagePack,
+}
+
+impl ErrorBodyFormat {
+    /// Return the format with the highest quality among the media ranges of an `Accept` header value.
+    /// Fallback to [ErrorBodyFormat::Json] if none is supported.
+    /// # Examples
+    /// ```rust
+    /// # use explicit_error_http::ErrorBodyFormat;
+    /// assert_eq!(ErrorBodyFormat::from_accept("text/html, */*;q=0.1"), ErrorBodyFormat::Json);
+    /// # #[cfg(feature = "cbor")]
+    /// assert_eq!(
+    ///     ErrorBodyFormat::from_accept("application/json;q=0.5, application/cbor"),
+    ///     ErrorBodyFormat::Cbor
+    /// );
+    /// ```
+    pub fn from_accept(accept: &str) -> Self {
+        let mut negotiated = None::<(Self, f32)>;
+
+        for range in accept.split(',') {
+            let mut params = range.split(';');
+            let media_type = params.next().unwrap_or_default().trim();
+            let quality = match params.find_map(|param| param.trim().strip_prefix("q=")) {
+                Some(quality) => quality.trim().parse().unwrap_or(0.0),
+                None => 1.0,
+            };
+
+            let Some(format) = Self::from_media_type(media_type) else {
+                continue;
+            };
+
+            if quality > 0.0 && negotiated.is_none_or(|(_, best)| quality > best) {
+                negotiated = Some((format, quality));
+            }
+        }
+
+        negotiated.map(|(format, _)| format).unwrap_or_default()
+    }
+
+    fn from_media_type(media_type: &str) -> Option<Self> {
+        match media_type.to_ascii_lowercase().as_str() {
+            "application/json" | "application/problem+json" | "application/*" | "*/*" => {
+                Some(Self::Json)
+            }
+            #[cfg(feature = "cbor")]
+            "application/cbor" => Some(Self::Cbor),
+            #[cfg(feature = "msgpack")]
+            "application/msgpack" | "application/x-msgpack" | "application/vnd.msgpack" => {
+                Some(Self::MessagePack)
+            }
+            _ => None,
+        }
+    }
+
+    /// Return the content type of the format, for JSON it is the one set with [set_error_content_type](crate::set_error_content_type).
+    pub fn content_type(&self) -> &'static str {
+        match self {
+            Self::Json => crate::error_content_type(),
+            #[cfg(feature = "cbor")]
+            Self::Cbor => "application/cbor",
+            #[cfg(feature = "msgpack")]
+            Self::MessagePack => "application/msgpack",
+        }
+    }
+
+    /// Run the future with this format used for error responses generated by [HandlerErrorHelpers](crate::derive::HandlerErrorHelpers).
+    /// Usefull to write a middleware for web frameworks that are not integrated.
+    pub async fn scope<F: Future>(self, future: F) -> F::Output {
+        ERROR_BODY_FORMAT.scope(self, future).await
+    }
+
+    /// Return the format of the current [scope](ErrorBodyFormat::scope), [ErrorBodyFormat::Json] outside of it.
+    pub fn current() -> Self {
+        ERROR_BODY_FORMAT
+            .try_with(|format| *format)
+            .unwrap_or_default()
+    }
+}
+
+pub(crate) fn encode<S: Serialize>(body: &S) -> Option<(&'static str, Vec<u8>)> {
+    let format = ErrorBodyFormat::current();
+    if format == ErrorBodyFormat::Json {
+        return None;
+    }
+
+    // The limit is checked against the encoded body, encoded once
+    let encoded =
+        encode_as(format, body).and_then(
+            |bytes| match crate::handler::exceeds_max_error_body_bytes(bytes.len()) {
+                true => encode_as(format, &serde_json::Map::new()),
+                false => Ok(bytes),
+            },
+        );
+
+    match encoded {
+        Ok(bytes) => Some((format.content_type(), bytes)),
+        Err(e) => {
+            crate::handler::warn(format_args!(
+                "Failed to encode the error response body as {format:?}, fallback to JSON: {e}"
+            ));
+            None
+        }
+    }
+}
+
+fn encode_as<S: Serialize>(format: ErrorBodyFormat, body: &S) -> Result<Vec<u8>, String> {
+    match format {
+        ErrorBodyFormat::Json => serde_json::to_vec(body).map_err(|e| e.to_string()),
+        #[cfg(feature = "cbor")]
+        ErrorBodyFormat::Cbor => {
+            let mut bytes = Vec::new();
+            ciborium::into_writer(body, &mut bytes)
+                .map(|_| bytes)
+                .map_err(|e| e.to_string())
+        }
+        #[cfg(feature = "msgpack")]
+        ErrorBodyFormat::MessagePack => rmp_serde::to_vec_named(body).map_err(|e| e.to_string()),
+    }
+}
+
+#[cfg(test)]
+mod test;
@@ -0,0 +1,39 @@
+use super::*;
+
+#[test]
+fn from_accept() {
+    assert_eq!(ErrorBodyFormat::from_accept(""), ErrorBodyFormat::Json);
+    assert_eq!(
+        ErrorBodyFormat::from_accept("text/html"),
+        ErrorBodyFormat::Json
+    );
+    assert_eq!(ErrorBodyFormat::from_accept("*/*"), ErrorBodyFormat::Json);
+
+    #[cfg(feature = "cbor")]
+    {
+        assert_eq!(
+            ErrorBodyFormat::from_accept("Application/CBOR"),
+            ErrorBodyFormat::Cbor
+        );
+        assert_eq!(
+            ErrorBodyFormat::from_accept("application/cbor;q=0.4, application/json;q=0.8"),
+            ErrorBodyFormat::Json
+        );
+        assert_eq!(
+            ErrorBodyFormat::from_accept("application/cbor;q=0, */*;q=0.1"),
+            ErrorBodyFormat::Json
+        );
+    }
+
+    #[cfg(feature = "msgpack")]
+    assert_eq!(
+        ErrorBodyFormat::from_accept("text/html, application/msgpack;q=0.9, */*;q=0.1"),
+        ErrorBodyFormat::MessagePack
+    );
+}
+
+#[test]
+fn current() {
+    assert_eq!(ErrorBodyFormat::current(), ErrorBodyFormat::Json);
+    assert!(encode(&"body").is_none());
+}
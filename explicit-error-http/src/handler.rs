@@ -1,14 +1,25 @@
 use crate::{DomainError, Error};
 use explicit_error::Fault;
 use serde::Serialize;
+use std::{
+    collections::VecDeque,
+    sync::{
+        RwLock,
+        atomic::{AtomicUsize, Ordering},
+    },
+};
+
+static MAX_ERROR_BODY_BYTES: AtomicUsize = AtomicUsize::new(usize::MAX);
+static ERROR_CONTENT_TYPE: RwLock<&'static str> = RwLock::new("application/json");
+const ERROR_BODY_CHUNK_BYTES: usize = 8 * 1024;
 
 /// The type [Error] cannot directly be used as handlers or middlewares returned [Err] variant. A dedicated type is required.
 /// The easiest implementation is to declare a [Newtype](https://doc.rust-lang.org/rust-by-example/generics/new_types.html),
 /// derive it with the [HandlerErrorHelpers](crate::derive::HandlerErrorHelpers) and implement the [HandlerError] trait.
 /// ```rust
 /// # use actix_web::{App, HttpResponse, HttpServer, get};
-/// # use explicit_error_http::{Fault, Error, HandlerError, derive::HandlerErrorHelpers};
-/// # use log::{debug, error};
+/// # use explicit_error_http::{Fault, Error, HandlerError, Severity, derive::HandlerErrorHelpers};
+/// # use log::{debug, error, info, trace, warn};
 /// # use problem_details::ProblemDetails;
 /// # use serde::Serialize;
 /// #[derive(HandlerErrorHelpers)]
@@ -39,10 +50,12 @@ use serde::Serialize;
 ///
 ///     // Monitor domain variant of your errors and eventually override their body
 ///     fn domain_response(error: &explicit_error_http::DomainError) -> impl Serialize {
-///         if error.output.http_status_code.as_u16() < 500 {
-///             debug!("{error}");
-///         } else {
-///             error!("{error}");
+///         match error.severity() {
+///             Severity::Trace => trace!("{error}"),
+///             Severity::Debug => debug!("{error}"),
+///             Severity::Info => info!("{error}"),
+///             Severity::Warn => warn!("{error}"),
+///             Severity::Error => error!("{error}"),
 ///         }
 ///         error
 ///     }
@@ -53,6 +66,18 @@ use serde::Serialize;
 ///     Ok(HttpResponse::Ok().finish())
 /// }
 /// ```
+///
+/// Enums can derive [HandlerErrorHelpers](crate::derive::HandlerErrorHelpers) too, eg: to also return rejections of the web framework.
+/// Each variant has a single field that generates its response and the variant marked `#[explicit_error]` wraps a
+/// [HandlerError], like [DefaultHandlerError](crate::DefaultHandlerError), which errors are converted to.
+/// ```rust,ignore
+/// #[derive(HandlerErrorHelpers)]
+/// enum ApiError {
+///     Json(JsonRejection),
+///     #[explicit_error]
+///     Explicit(DefaultHandlerError),
+/// }
+/// ```
 pub trait HandlerError
 where
     Self: std::fmt::Debug + std::fmt::Display,
@@ -81,22 +106,245 @@ where
     /// ```
     fn public_fault_response(fault: &Fault) -> impl Serialize;
 
+    /// Same as [public_fault_response](HandlerError::public_fault_response) with the [ResponseContext](crate::ResponseContext) of the request,
+    /// called instead of it when the middleware of your web framework populates the context:
+    /// [axum::response_context](crate::axum::response_context) or [actix::response_context](crate::actix::response_context).
+    ///
+    /// Default to [public_fault_response](HandlerError::public_fault_response), ignoring the context.
+    /// # Examples
+    /// ```rust
+    /// # use explicit_error_http::{Fault, ResponseContext};
+    /// # use log::error;
+    /// # use problem_details::ProblemDetails;
+    /// # use serde::Serialize;
+    /// fn public_fault_response_with_context(fault: &Fault, ctx: &ResponseContext) -> impl Serialize {
+    ///     error!("{} {}: {fault}", ctx.method, ctx.path);
+    ///
+    ///     ProblemDetails::new()
+    ///         .with_type(http::Uri::from_static("/errors/internal-server-error"))
+    ///         .with_title("Internal server error")
+    ///         .with_instance(http::Uri::try_from(ctx.path.as_str()).unwrap_or_default())
+    /// }
+    /// ```
+    #[cfg(any(feature = "actix-web", feature = "axum"))]
+    fn public_fault_response_with_context(
+        fault: &Fault,
+        _ctx: &crate::ResponseContext,
+    ) -> impl Serialize {
+        Self::public_fault_response(fault)
+    }
+
     /// Monitor domain variant of your errors and eventually override their body
     /// # Examples
     /// ```rust
-    /// # use log::{debug, error};
+    /// # use explicit_error_http::Severity;
+    /// # use log::{debug, error, info, trace, warn};
     /// # use serde::Serialize;
     /// fn domain_response(error: &explicit_error_http::DomainError) -> impl Serialize {
-    ///     if error.output.http_status_code.as_u16() < 500 {
-    ///         debug!("{error}");
-    ///     } else {
-    ///         error!("{error}");
+    ///     match error.severity() {
+    ///         Severity::Trace => trace!("{error}"),
+    ///         Severity::Debug => debug!("{error}"),
+    ///         Severity::Info => info!("{error}"),
+    ///         Severity::Warn => warn!("{error}"),
+    ///         Severity::Error => error!("{error}"),
     ///     }
     ///     error
     /// }
     /// ```
     fn domain_response(error: &DomainError) -> impl Serialize;
 
+    /// Rewrite domain errors when they are converted to the handler error by [HandlerErrorHelpers](crate::derive::HandlerErrorHelpers),
+    /// before [domain_response](HandlerError::domain_response) is called. Use it to enforce a policy on every public body, eg: never leak `detail` in production.
+    ///
+    /// Default to no-op.
+    /// # Examples
+    /// ```rust
+    /// # use problem_details::ProblemDetails;
+    /// fn redact_domain_error(error: &mut explicit_error_http::DomainError) {
+    ///     let status = error.output.http_status_code;
+    ///     error.output_mut().set_public(ProblemDetails::from_status_code(status));
+    /// }
+    /// ```
+    fn redact_domain_error(_error: &mut DomainError) {}
+
     /// Used by the derive for conversion
     fn from_error(value: Error) -> Self;
+
+    /// Additional labels of the `explicit_error_faults_total` and `explicit_error_domain_total` counters
+    /// incremented by [HandlerErrorHelpers](crate::derive::HandlerErrorHelpers), enabled with the `metrics` feature.
+    ///
+    /// Default to none.
+    /// # Examples
+    /// Label with the route, set as an extension of the error
+    /// ```rust
+    /// # use explicit_error_http::Error;
+    /// struct Route(&'static str);
+    ///
+    /// fn metrics_labels(error: &Error) -> Vec<metrics::Label> {
+    ///     error
+    ///         .extension::<Route>()
+    ///         .map(|route| vec![metrics::Label::new("route", route.0)])
+    ///         .unwrap_or_default()
+    /// }
+    /// ```
+    #[cfg(feature = "metrics")]
+    fn metrics_labels(_error: &Error) -> Vec<metrics::Label> {
+        Vec::new()
+    }
+}
+
+/// Set the maximum size, in bytes, of the encoded body of error responses generated by
+/// [HandlerErrorHelpers](crate::derive::HandlerErrorHelpers). Bodies exceeding it are replaced by an empty object
+/// and a warning is logged with the `log` or `tracing` feature. It prevents sending a huge body by mistake (eg: echoing a large input).
+///
+/// There is no limit by default.
+/// # Examples
+/// ```rust
+/// explicit_error_http::set_max_error_body_bytes(64 * 1024);
+/// ```
+pub fn set_max_error_body_bytes(max: usize) {
+    MAX_ERROR_BODY_BYTES.store(max, Ordering::Relaxed);
+}
+
+/// Set the `Content-Type` header of error responses generated by [HandlerErrorHelpers](crate::derive::HandlerErrorHelpers),
+/// eg: `application/problem+json` or a vendor-specific media type. The body is still serialized in JSON.
+///
+/// Default to `application/json`.
+/// # Examples
+/// ```rust
+/// explicit_error_http::set_error_content_type("application/vnd.acme.error+json");
+/// ```
+pub fn set_error_content_type(content_type: &'static str) {
+    *ERROR_CONTENT_TYPE
+        .write()
+        .unwrap_or_else(|poisoned| poisoned.into_inner()) = content_type;
+}
+
+/// Internally used by [HandlerErrorHelpers](crate::derive::HandlerErrorHelpers) derive.
+#[doc(hidden)]
+pub fn error_content_type() -> &'static str {
+    *ERROR_CONTENT_TYPE
+        .read()
+        .unwrap_or_else(|poisoned| poisoned.into_inner())
+}
+
+/// Internally used by [HandlerErrorHelpers](crate::derive::HandlerErrorHelpers) derive.
+/// Return the content type and the encoded body, replaced by an empty map if it exceeds the [maximum size](set_max_error_body_bytes),
+/// if a binary format was negotiated, [None] for JSON.
+#[doc(hidden)]
+pub fn encode_error_body<S: Serialize>(body: &S) -> Option<(&'static str, Vec<u8>)> {
+    #[cfg(any(feature = "cbor", feature = "msgpack"))]
+    {
+        crate::format::encode(body)
+    }
+    #[cfg(not(any(feature = "cbor", feature = "msgpack")))]
+    {
+        let _ = body;
+        None
+    }
+}
+
+/// Internally used by [HandlerErrorHelpers](crate::derive::HandlerErrorHelpers) derive.
+/// JSON body of an error response, serialized in chunks of at most 8 KiB that the web framework sends one after the other,
+/// releasing each of them once sent. It avoids holding the whole body in a single buffer growing as it is serialized.
+#[doc(hidden)]
+pub struct JsonErrorBody {
+    chunks: VecDeque<Vec<u8>>,
+    len: usize,
+}
+
+impl JsonErrorBody {
+    /// Number of bytes not sent yet
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Take the next chunk to send
+    pub fn next_chunk(&mut self) -> Option<Vec<u8>> {
+        let chunk = self.chunks.pop_front()?;
+        self.len -= chunk.len();
+        Some(chunk)
+    }
+
+    fn empty_object() -> Self {
+        Self {
+            chunks: VecDeque::from([b"{}".to_vec()]),
+            len: 2,
+        }
+    }
+}
+
+impl std::io::Write for JsonErrorBody {
+    fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+        match self.chunks.back_mut() {
+            Some(chunk) if chunk.len() < ERROR_BODY_CHUNK_BYTES => {
+                let written = buf.len().min(ERROR_BODY_CHUNK_BYTES - chunk.len());
+                chunk.extend_from_slice(&buf[..written]);
+                self.len += written;
+                Ok(written)
+            }
+            _ => {
+                self.chunks.push_back(Vec::new());
+                self.write(buf)
+            }
+        }
+    }
+
+    fn flush(&mut self) -> std::io::Result<()> {
+        Ok(())
+    }
+}
+
+/// Internally used by [HandlerErrorHelpers](crate::derive::HandlerErrorHelpers) derive.
+/// Serialize the body with [serde_json::to_writer] into the chunks of the response body. If a [maximum size](set_max_error_body_bytes)
+/// is set, the body is replaced by an empty JSON object when it exceeds it, which is known once it is fully serialized.
+#[doc(hidden)]
+pub fn json_error_body<S: Serialize>(body: &S) -> JsonErrorBody {
+    let mut json = JsonErrorBody {
+        chunks: VecDeque::new(),
+        len: 0,
+    };
+    if let Err(e) = serde_json::to_writer(&mut json, body) {
+        warn(format_args!(
+            "Failed to serialize the error response body, it is replaced by an empty body: {e}"
+        ));
+        return JsonErrorBody::empty_object();
+    }
+
+    match exceeds_max_error_body_bytes(json.len) {
+        true => JsonErrorBody::empty_object(),
+        false => json,
+    }
 }
+
+/// Return true, and log a warning, if an encoded error body exceeds the [maximum size](set_max_error_body_bytes)
+pub(crate) fn exceeds_max_error_body_bytes(len: usize) -> bool {
+    let max = MAX_ERROR_BODY_BYTES.load(Ordering::Relaxed);
+    if len <= max {
+        return false;
+    }
+
+    warn(format_args!(
+        "Error response body of {len} bytes exceeds the maximum of {max} bytes, it is replaced by an empty body"
+    ));
+    true
+}
+
+/// Log a warning with [tracing](https://docs.rs/tracing) or [log](https://docs.rs/log) depending on the enabled features
+pub(crate) fn warn(message: std::fmt::Arguments<'_>) {
+    #[cfg(feature = "tracing")]
+    tracing::warn!("{message}");
+
+    #[cfg(all(feature = "log", not(feature = "tracing")))]
+    log::warn!("{message}");
+
+    #[cfg(not(any(feature = "log", feature = "tracing")))]
+    let _ = message;
+}
+
+#[cfg(test)]
+mod test;
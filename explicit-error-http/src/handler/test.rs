@@ -0,0 +1,56 @@
+use super::*;
+
+fn collect(mut body: JsonErrorBody) -> Vec<u8> {
+    std::iter::from_fn(|| body.next_chunk()).flatten().collect()
+}
+
+#[test]
+fn max_error_body_bytes() {
+    let body = serde_json::json!({"errors": vec!["a".repeat(100); 1000]});
+    let mut json = super::json_error_body(&body);
+    let len = json.len();
+    assert_eq!(len, serde_json::to_vec(&body).unwrap().len());
+
+    let chunks = std::iter::from_fn(|| json.next_chunk()).collect::<Vec<_>>();
+    assert!(json.is_empty());
+    assert_eq!(chunks.len(), len.div_ceil(ERROR_BODY_CHUNK_BYTES));
+    assert!(
+        chunks
+            .iter()
+            .all(|chunk| chunk.len() <= ERROR_BODY_CHUNK_BYTES)
+    );
+    assert_eq!(
+        serde_json::from_slice::<serde_json::Value>(&chunks.concat()).unwrap(),
+        body
+    );
+
+    set_max_error_body_bytes(10);
+    assert_eq!(collect(super::json_error_body(&"a".repeat(100))), b"{}");
+    assert_eq!(collect(super::json_error_body(&"a")), br#""a""#);
+
+    // The limit applies to the encoded body
+    #[cfg(feature = "cbor")]
+    crate::format::ERROR_BODY_FORMAT.sync_scope(crate::ErrorBodyFormat::Cbor, || {
+        let (_, bytes) = crate::format::encode(&"a".repeat(9)).unwrap();
+        assert_eq!(bytes.len(), 10);
+        assert_eq!(
+            ciborium::from_reader::<String, _>(bytes.as_slice()).unwrap(),
+            "a".repeat(9)
+        );
+
+        let (_, bytes) = crate::format::encode(&"a".repeat(10)).unwrap();
+        assert_eq!(
+            ciborium::from_reader::<serde_json::Value, _>(bytes.as_slice()).unwrap(),
+            serde_json::json!({})
+        );
+    });
+    set_max_error_body_bytes(usize::MAX);
+}
+
+#[test]
+fn error_content_type() {
+    assert_eq!(super::error_content_type(), "application/json");
+    set_error_content_type("application/problem+json");
+    assert_eq!(super::error_content_type(), "application/problem+json");
+    set_error_content_type("application/json");
+}
@@ -0,0 +1,144 @@
+//! [JSON:API](https://jsonapi.org/format/#error-objects) error objects as [HttpError] body, enabled with the `jsonapi` feature.
+use crate::HttpError;
+use http::StatusCode;
+use serde::Serialize;
+
+/// Top level document of a JSON:API error response: `{ "errors": [...] }`.
+#[derive(Debug, Clone, Default, PartialEq, Serialize)]
+pub struct JsonApiErrors {
+    pub errors: Vec<JsonApiError>,
+}
+
+/// A JSON:API [error object](https://jsonapi.org/format/#error-objects). Members not set are not serialized.
+/// # Examples
+/// ```rust
+/// # use explicit_error_http::{HttpError, jsonapi::JsonApiError};
+/// # use http::StatusCode;
+/// HttpError::jsonapi(
+///     StatusCode::UNPROCESSABLE_ENTITY,
+///     JsonApiError::new()
+///         .with_code("invalid-attribute")
+///         .with_title("Invalid attribute")
+///         .with_detail("First name must contain at least two characters.")
+///         .with_source_pointer("/data/attributes/firstName"),
+/// );
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Serialize)]
+pub struct JsonApiError {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub id: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub status: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub code: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub title: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub detail: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub source: Option<JsonApiErrorSource>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub meta: Option<serde_json::Value>,
+}
+
+/// References to the primary source of a [JsonApiError].
+#[derive(Debug, Clone, Default, PartialEq, Serialize)]
+pub struct JsonApiErrorSource {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub pointer: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub parameter: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub header: Option<String>,
+}
+
+impl JsonApiError {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Unique identifier for this particular occurrence of the problem
+    pub fn with_id(mut self, id: impl Into<String>) -> Self {
+        self.id = Some(id.into());
+        self
+    }
+
+    /// HTTP status code applicable to this problem. It is set by [HttpError::jsonapi] if missing.
+    pub fn with_status(mut self, status: StatusCode) -> Self {
+        self.status = Some(status.as_u16().to_string());
+        self
+    }
+
+    /// Application-specific error code
+    pub fn with_code(mut self, code: impl Into<String>) -> Self {
+        self.code = Some(code.into());
+        self
+    }
+
+    /// Short, human-readable summary of the problem
+    pub fn with_title(mut self, title: impl Into<String>) -> Self {
+        self.title = Some(title.into());
+        self
+    }
+
+    /// Human-readable explanation specific to this occurrence of the problem
+    pub fn with_detail(mut self, detail: impl Into<String>) -> Self {
+        self.detail = Some(detail.into());
+        self
+    }
+
+    /// JSON Pointer to the value in the request document that caused the error
+    pub fn with_source_pointer(mut self, pointer: impl Into<String>) -> Self {
+        self.source.get_or_insert_with(Default::default).pointer = Some(pointer.into());
+        self
+    }
+
+    /// Name of the URI query parameter that caused the error
+    pub fn with_source_parameter(mut self, parameter: impl Into<String>) -> Self {
+        self.source.get_or_insert_with(Default::default).parameter = Some(parameter.into());
+        self
+    }
+
+    /// Name of the request header that caused the error
+    pub fn with_source_header(mut self, header: impl Into<String>) -> Self {
+        self.source.get_or_insert_with(Default::default).header = Some(header.into());
+        self
+    }
+
+    /// Non-standard meta-information about the error
+    pub fn with_meta(mut self, meta: serde_json::Value) -> Self {
+        self.meta = Some(meta);
+        self
+    }
+}
+
+impl HttpError {
+    /// Generate an [HttpError] with a JSON:API body holding one error object.
+    /// The error object's status is set from `http_status_code` if missing.
+    /// # Examples
+    /// ```rust
+    /// # use explicit_error_http::{HttpError, jsonapi::JsonApiError};
+    /// # use http::StatusCode;
+    /// fn not_found() -> HttpError {
+    ///     HttpError::jsonapi(
+    ///         StatusCode::NOT_FOUND,
+    ///         JsonApiError::new().with_title("Not found"),
+    ///     )
+    /// }
+    /// ```
+    pub fn jsonapi(http_status_code: StatusCode, mut error: JsonApiError) -> Self {
+        if error.status.is_none() {
+            error = error.with_status(http_status_code);
+        }
+
+        Self::new(
+            http_status_code,
+            JsonApiErrors {
+                errors: vec![error],
+            },
+        )
+    }
+}
+
+#[cfg(test)]
+mod test;
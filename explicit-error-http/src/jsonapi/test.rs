@@ -0,0 +1,59 @@
+use super::*;
+
+#[test]
+fn new() {
+    assert_eq!(serde_json::json!(JsonApiError::new()).to_string(), "{}");
+}
+
+#[test]
+fn serialize() {
+    assert_eq!(
+        serde_json::json!(
+            JsonApiError::new()
+                .with_id("id")
+                .with_status(StatusCode::UNPROCESSABLE_ENTITY)
+                .with_code("code")
+                .with_title("title")
+                .with_detail("detail")
+                .with_source_pointer("/data/attributes/firstName")
+                .with_source_parameter("parameter")
+                .with_source_header("header")
+                .with_meta(serde_json::json!({"foo": "bar"}))
+        ),
+        serde_json::json!({
+            "id": "id",
+            "status": "422",
+            "code": "code",
+            "title": "title",
+            "detail": "detail",
+            "source": {
+                "pointer": "/data/attributes/firstName",
+                "parameter": "parameter",
+                "header": "header",
+            },
+            "meta": {"foo": "bar"},
+        })
+    );
+}
+
+#[test]
+fn jsonapi() {
+    let error = HttpError::jsonapi(
+        StatusCode::NOT_FOUND,
+        JsonApiError::new().with_title("Not found"),
+    );
+    assert_eq!(error.http_status_code, StatusCode::NOT_FOUND);
+    assert_eq!(
+        serde_json::json!(error).to_string(),
+        r#"{"errors":[{"status":"404","title":"Not found"}]}"#
+    );
+
+    assert_eq!(
+        serde_json::json!(HttpError::jsonapi(
+            StatusCode::BAD_REQUEST,
+            JsonApiError::new().with_status(StatusCode::UNPROCESSABLE_ENTITY),
+        ))
+        .to_string(),
+        r#"{"errors":[{"status":"422"}]}"#
+    );
+}
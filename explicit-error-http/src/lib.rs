@@ -87,7 +87,65 @@
 //! }
 //! ```
 //!
-//! Note: The [HttpError](derive::HttpError) derive implements the conversion to [Error], the impl of [Display](std::fmt::Display) (json format) and [std::error::Error].
+//! Note: The [HttpError](derive::HttpError) derive implements the conversion to [Error] and back with [TryFrom], the impl of [Display](std::fmt::Display) (json format) and [std::error::Error].
+//!
+//! A struct, or enum variants, with a single field that already converts to [HttpError] can be marked `#[explicit_error(transparent)]`:
+//! the derive implements `From<&MyError> for HttpError` by delegating to the field and [source](std::error::Error::source) returns it.
+//!
+//! ```rust
+//! # use http::StatusCode;
+//! # use explicit_error_http::{derive::HttpError, HttpError};
+//! # #[derive(HttpError, Debug)]
+//! # struct PaymentError;
+//! # impl From<&PaymentError> for HttpError {
+//! #     fn from(_: &PaymentError) -> Self {
+//! #         HttpError::new(StatusCode::PAYMENT_REQUIRED, "")
+//! #     }
+//! # }
+//! #[derive(HttpError, Debug)]
+//! enum CheckoutError {
+//!     #[explicit_error(transparent)]
+//!     Payment(PaymentError),
+//! }
+//! ```
+//!
+//! Otherwise, like with [thiserror](https://docs.rs/thiserror), a field marked `#[source]` is returned by [source](std::error::Error::source).
+//! `#[from]` does the same and implements the conversion from the type of the field, which must be the only one of the variant.
+//!
+//! ```rust
+//! # use http::StatusCode;
+//! # use explicit_error_http::{derive::HttpError, HttpError};
+//! #[derive(HttpError, Debug)]
+//! enum UploadError {
+//!     Io(#[from] std::io::Error),
+//! }
+//! # impl From<&UploadError> for HttpError {
+//! #     fn from(_: &UploadError) -> Self {
+//! #         HttpError::new(StatusCode::SERVICE_UNAVAILABLE, "")
+//! #     }
+//! # }
+//! ```
+//!
+//! For simple cases, an enum can declare its `From<&MyError> for HttpError` as a table with `#[explicit_error(map(..))]`: each variant maps to a
+//! [ProblemDetails](problem_details::ProblemDetails) body with a status code, a type and a title. Variants missing from the table are converted by the fallback function.
+//!
+//! ```rust
+//! # use explicit_error_http::{derive::HttpError, HttpError};
+//! # use problem_details::ProblemDetails;
+//! #[derive(HttpError, Debug)]
+//! #[explicit_error(map(
+//!     NotFound => (404, "/errors/users/not-found", "User not found"),
+//!     _ => user_error,
+//! ))]
+//! enum UserError {
+//!     NotFound(u32),
+//!     Conflict(String),
+//! }
+//!
+//! fn user_error(_: &UserError) -> HttpError {
+//!     HttpError::conflict(ProblemDetails::new().with_title("Email already used"))
+//! }
+//! ```
 //!
 //! # Pattern matching
 //!
@@ -151,11 +209,13 @@
 //! The easiest implementation is to declare a [Newtype](https://doc.rust-lang.org/rust-by-example/generics/new_types.html),
 //! derive it with the [HandlerError] and implement the [HandlerError] trait.
 //!
+//! If you do not need to customize responses nor monitoring, use the ready-made [DefaultHandlerError] instead.
+//!
 //! ```rust
 //! # use actix_web::{App, HttpResponse, HttpServer, get};
 //! # use env_logger::Env;
-//! # use explicit_error_http::{Fault, Error, HandlerError, derive::HandlerErrorHelpers};
-//! # use log::{debug, error};
+//! # use explicit_error_http::{Fault, Error, HandlerError, Severity, derive::HandlerErrorHelpers};
+//! # use log::{debug, error, info, trace, warn};
 //! # use problem_details::ProblemDetails;
 //! # use serde::Serialize;
 //! #[derive(HandlerErrorHelpers)]
@@ -186,10 +246,12 @@
 //!
 //!     // Monitor domain variant of your errors and eventually override their body
 //!     fn domain_response(error: &explicit_error_http::DomainError) -> impl Serialize {
-//!         if error.output.http_status_code.as_u16() < 500 {
-//!             debug!("{error}");
-//!         } else {
-//!             error!("{error}");
+//!         match error.severity() {
+//!             Severity::Trace => trace!("{error}"),
+//!             Severity::Debug => debug!("{error}"),
+//!             Severity::Info => info!("{error}"),
+//!             Severity::Warn => warn!("{error}"),
+//!             Severity::Error => error!("{error}"),
 //!         }
 //!         error
 //!     }
@@ -199,21 +261,73 @@
 //!     Ok(HttpResponse::Ok().finish())
 //! }
 //! ```
+extern crate self as explicit_error_http;
+
+#[cfg(feature = "actix-web")]
+pub mod actix;
+#[cfg(feature = "axum")]
+pub mod axum;
+mod default_handler;
 mod domain;
 mod error;
+#[cfg(any(feature = "cbor", feature = "msgpack"))]
+mod format;
 mod handler;
+#[cfg(feature = "jsonapi")]
+pub mod jsonapi;
+#[cfg(feature = "metrics")]
+mod monitoring;
+#[cfg(any(feature = "actix-web", feature = "axum"))]
+mod response_context;
+#[cfg(feature = "test-util")]
+pub mod test_util;
+mod validation;
 
+pub use default_handler::*;
 pub use domain::*;
 pub use error::*;
+#[cfg(any(feature = "cbor", feature = "msgpack"))]
+pub use format::*;
 pub use handler::*;
+#[cfg(feature = "metrics")]
+pub use monitoring::*;
+#[cfg(any(feature = "actix-web", feature = "axum"))]
+pub use response_context::*;
+pub use validation::*;
 
 /// Re-import from [explicit_error] crate.
-pub use explicit_error::Fault;
+pub use explicit_error::{Extensions, Fault};
+
+/// Generate the `type` [Uri](http::Uri) of a [ProblemDetails](problem_details::ProblemDetails), checked at compile time.
+/// Pass a `const` array of the registered types as second argument to prevent drift from your published catalog of errors.
+/// # Examples
+/// ```rust
+/// # use explicit_error_http::{problem_type, HttpError};
+/// # use problem_details::ProblemDetails;
+/// const PROBLEM_TYPES: &[&str] = &["/errors/not-found", "/errors/conflict"];
+///
+/// let error = HttpError::not_found(
+///     ProblemDetails::new().with_type(problem_type!("/errors/not-found", PROBLEM_TYPES)),
+/// );
+/// assert_eq!(problem_type!("/errors/forbidden"), "/errors/forbidden");
+/// ```
+///
+/// Unregistered types do not compile
+/// ```rust,compile_fail
+/// # use explicit_error_http::problem_type;
+/// const PROBLEM_TYPES: &[&str] = &["/errors/not-found"];
+/// problem_type!("/errors/not-fuond", PROBLEM_TYPES);
+/// ```
+pub use explicit_error_derive::problem_type;
+
+#[allow(deprecated)]
+pub use explicit_error::Bug;
 
 pub type Error = explicit_error::Error<DomainError>;
 pub type Result<T> = std::result::Result<T, explicit_error::Error<DomainError>>;
 
 pub mod prelude {
+    pub use crate::{ErrorStatusCode, ResultHttp};
     pub use explicit_error::prelude::*;
 }
 
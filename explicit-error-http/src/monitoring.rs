@@ -0,0 +1,29 @@
+use crate::{Error, HandlerError};
+
+/// Internally used by [HandlerErrorHelpers](crate::derive::HandlerErrorHelpers) derive.
+/// Increment `explicit_error_faults_total{kind="..."}` or `explicit_error_domain_total{status="..."}` with the
+/// [metrics_labels](HandlerError::metrics_labels) of the handler error.
+#[doc(hidden)]
+pub fn record_error_metrics<H: HandlerError>(handler_error: &H) {
+    let error = handler_error.error();
+    let mut labels = H::metrics_labels(error);
+
+    match error {
+        Error::Domain(d) => {
+            labels.push(metrics::Label::new(
+                "status",
+                d.output.http_status_code.as_str().to_string(),
+            ));
+            metrics::counter!("explicit_error_domain_total", labels).increment(1);
+        }
+        Error::Fault(fault) => {
+            if let Some(kind) = fault.kind() {
+                labels.push(metrics::Label::new("kind", kind));
+            }
+            metrics::counter!("explicit_error_faults_total", labels).increment(1);
+        }
+    }
+}
+
+#[cfg(test)]
+mod test;
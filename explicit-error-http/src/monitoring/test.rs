@@ -0,0 +1,87 @@
+use super::*;
+use crate::{HttpError, derive::HandlerErrorHelpers};
+use explicit_error::Fault;
+use metrics_util::debugging::{DebugValue, DebuggingRecorder};
+use serde::Serialize;
+use std::sync::Arc;
+
+struct Route(&'static str);
+
+#[derive(HandlerErrorHelpers)]
+struct MyHandlerError(Error);
+
+impl HandlerError for MyHandlerError {
+    fn error(&self) -> &Error {
+        &self.0
+    }
+
+    fn public_fault_response(_: &Fault) -> impl Serialize {}
+
+    fn domain_response(error: &crate::DomainError) -> impl Serialize {
+        error
+    }
+
+    fn from_error(value: Error) -> Self {
+        MyHandlerError(value)
+    }
+
+    fn metrics_labels(error: &Error) -> Vec<metrics::Label> {
+        error
+            .extension::<Route>()
+            .map(|route| vec![metrics::Label::new("route", route.0)])
+            .unwrap_or_default()
+    }
+}
+
+#[test]
+fn record_error_metrics() {
+    let recorder = DebuggingRecorder::new();
+    let snapshotter = recorder.snapshotter();
+
+    metrics::with_local_recorder(&recorder, || {
+        for _ in 0..2 {
+            super::record_error_metrics(&MyHandlerError(
+                Error::from(HttpError::not_found("")).with_extension(Arc::new(Route("/users"))),
+            ));
+        }
+        super::record_error_metrics(&MyHandlerError(Fault::new().into()));
+        super::record_error_metrics(&MyHandlerError(Fault::new().with_kind("db").into()));
+    });
+
+    let mut counters = snapshotter
+        .snapshot()
+        .into_vec()
+        .into_iter()
+        .map(|(key, _, _, value)| {
+            let mut labels = key
+                .key()
+                .labels()
+                .map(|label| format!("{}={}", label.key(), label.value()))
+                .collect::<Vec<_>>();
+            labels.sort();
+            (key.key().name().to_string(), labels, value)
+        })
+        .collect::<Vec<_>>();
+    counters.sort_by(|a, b| (&a.0, &a.1).cmp(&(&b.0, &b.1)));
+
+    assert_eq!(
+        counters,
+        vec![
+            (
+                "explicit_error_domain_total".to_string(),
+                vec!["route=/users".to_string(), "status=404".to_string()],
+                DebugValue::Counter(2)
+            ),
+            (
+                "explicit_error_faults_total".to_string(),
+                vec![],
+                DebugValue::Counter(1)
+            ),
+            (
+                "explicit_error_faults_total".to_string(),
+                vec!["kind=db".to_string()],
+                DebugValue::Counter(1)
+            ),
+        ]
+    );
+}
@@ -0,0 +1,72 @@
+use http::Method;
+use std::future::Future;
+
+tokio::task_local! {
+    static RESPONSE_CONTEXT: ResponseContext;
+}
+
+/// Header read by the framework middlewares to fill [ResponseContext::request_id].
+pub const REQUEST_ID_HEADER: &str = "x-request-id";
+
+/// Parts of the request being answered, given to [HandlerError::public_fault_response_with_context](crate::HandlerError::public_fault_response_with_context)
+/// to build richer fault bodies, eg: echo the path or the request id for support.
+///
+/// Populate it with the middleware of your web framework: [axum::response_context](crate::axum::response_context)
+/// or [actix::response_context](crate::actix::response_context).
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub struct ResponseContext {
+    pub method: Method,
+    pub path: String,
+    /// Value of the [REQUEST_ID_HEADER] header, if present
+    pub request_id: Option<String>,
+}
+
+impl ResponseContext {
+    pub fn new(method: Method, path: impl Into<String>) -> Self {
+        Self {
+            method,
+            path: path.into(),
+            request_id: None,
+        }
+    }
+
+    pub fn with_request_id(mut self, request_id: impl Into<String>) -> Self {
+        self.request_id = Some(request_id.into());
+        self
+    }
+
+    /// Run the future with this context given to fault responses generated by [HandlerErrorHelpers](crate::derive::HandlerErrorHelpers).
+    /// Usefull to write a middleware for web frameworks that are not integrated.
+    pub async fn scope<F: Future>(self, future: F) -> F::Output {
+        RESPONSE_CONTEXT.scope(self, future).await
+    }
+
+    /// Return the context of the current [scope](ResponseContext::scope), [None] outside of it.
+    pub fn current() -> Option<Self> {
+        RESPONSE_CONTEXT.try_with(Clone::clone).ok()
+    }
+}
+
+#[derive(serde::Serialize)]
+#[serde(untagged)]
+enum FaultResponseBody<C, P> {
+    WithContext(C),
+    Plain(P),
+}
+
+/// Internally used by [HandlerErrorHelpers](crate::derive::HandlerErrorHelpers) derive with the [current](ResponseContext::current) context.
+/// Call [public_fault_response_with_context](crate::HandlerError::public_fault_response_with_context) if there is a context,
+/// [public_fault_response](crate::HandlerError::public_fault_response) otherwise.
+#[doc(hidden)]
+pub fn public_fault_body<'a, H: crate::HandlerError>(
+    fault: &'a crate::Fault,
+    context: Option<&'a ResponseContext>,
+) -> impl serde::Serialize {
+    match context {
+        Some(context) => {
+            FaultResponseBody::WithContext(H::public_fault_response_with_context(fault, context))
+        }
+        None => FaultResponseBody::Plain(H::public_fault_response(fault)),
+    }
+}
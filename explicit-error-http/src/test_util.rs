@@ -0,0 +1,59 @@
+//! Helpers to assert error responses in tests without relying on a web framework, enabled with the `test-util` feature.
+use crate::{Error, HandlerError};
+use http::StatusCode;
+
+/// Generate the HTTP status code and JSON body that a [HandlerError] would send as response.
+/// # Examples
+/// ```rust
+/// # use explicit_error_http::{Error, Fault, HandlerError, HttpError, derive::HandlerErrorHelpers, test_util::*};
+/// # use http::StatusCode;
+/// # use serde::Serialize;
+/// # #[derive(HandlerErrorHelpers)]
+/// # struct MyHandlerError(Error);
+/// # impl HandlerError for MyHandlerError {
+/// #     fn from_error(value: Error) -> Self {
+/// #         MyHandlerError(value)
+/// #     }
+/// #     fn public_fault_response(_: &Fault) -> impl Serialize {
+/// #         "Internal server error"
+/// #     }
+/// #     fn error(&self) -> &Error {
+/// #         &self.0
+/// #     }
+/// #     fn domain_response(error: &explicit_error_http::DomainError) -> impl Serialize {
+/// #         error
+/// #     }
+/// # }
+/// let error = MyHandlerError::from(HttpError::new(
+///     StatusCode::FORBIDDEN,
+///     serde_json::json!({"title": "Forbidden"}),
+/// ));
+/// assert_error_response(
+///     response_parts(&error),
+///     StatusCode::FORBIDDEN,
+///     serde_json::json!({"title": "Forbidden"}),
+/// );
+/// ```
+pub fn response_parts<H: HandlerError>(handler_error: &H) -> (StatusCode, serde_json::Value) {
+    match handler_error.error() {
+        Error::Domain(d) => (
+            d.output.http_status_code,
+            serde_json::json!(H::domain_response(d)),
+        ),
+        Error::Fault(f) => (
+            StatusCode::INTERNAL_SERVER_ERROR,
+            serde_json::json!(H::public_fault_response(f)),
+        ),
+    }
+}
+
+/// Assert that parts generated by [response_parts] have the expected HTTP status code and JSON body.
+#[track_caller]
+pub fn assert_error_response(
+    response_parts: (StatusCode, serde_json::Value),
+    expected_status: StatusCode,
+    expected_body: serde_json::Value,
+) {
+    assert_eq!(response_parts.0, expected_status, "HTTP status code");
+    assert_eq!(response_parts.1, expected_body, "HTTP response body");
+}
@@ -0,0 +1,108 @@
+use crate::{Error, FieldError, HttpError};
+
+/// Collect the [FieldError]s of one or several validation passes of a request. If any, it converts to a 422 Unprocessable Entity
+/// [HttpError] generated by [validation](HttpError::validation): a [ProblemDetails](problem_details::ProblemDetails) body with an `errors` array.
+/// # Examples
+/// ```rust
+/// # use explicit_error_http::{Result, ValidationError};
+/// fn validate_user(email: &str, age: u8) -> Result<()> {
+///     let mut validation = ValidationError::new();
+///
+///     if !email.contains('@') {
+///         validation.add("email", "must be a valid email");
+///     }
+///     if age < 18 {
+///         validation.add("age", "must be at least 18");
+///     }
+///
+///     validation.merge(validate_address(""));
+///     validation.into_result()?;
+///
+///     Ok(())
+/// }
+///
+/// fn validate_address(street: &str) -> ValidationError {
+///     let mut validation = ValidationError::new();
+///     if street.is_empty() {
+///         validation.add("address.street", "is required");
+///     }
+///     validation
+/// }
+///
+/// let error = validate_user("foo", 12).unwrap_err().unwrap();
+/// assert_eq!(error.output.field_errors().unwrap().len(), 3);
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct ValidationError {
+    errors: Vec<FieldError>,
+}
+
+impl ValidationError {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Add an error for the field
+    pub fn add(&mut self, field: impl Into<String>, message: impl Into<String>) -> &mut Self {
+        self.errors.push(FieldError {
+            field: field.into(),
+            message: message.into(),
+        });
+        self
+    }
+
+    /// Add the errors of another validation pass, eg: of a nested object.
+    pub fn merge(&mut self, other: ValidationError) -> &mut Self {
+        self.errors.extend(other.errors);
+        self
+    }
+
+    /// Return true if no error was added
+    pub fn is_empty(&self) -> bool {
+        self.errors.is_empty()
+    }
+
+    /// Return the errors added, in order
+    pub fn field_errors(&self) -> &[FieldError] {
+        &self.errors
+    }
+
+    /// Return [Ok] if no error was added, the [ValidationError] otherwise. Use `?` to convert it to an [Error].
+    pub fn into_result(self) -> Result<(), Self> {
+        if self.is_empty() { Ok(()) } else { Err(self) }
+    }
+}
+
+impl<F, M> FromIterator<(F, M)> for ValidationError
+where
+    F: Into<String>,
+    M: Into<String>,
+{
+    fn from_iter<T: IntoIterator<Item = (F, M)>>(iter: T) -> Self {
+        let mut validation = Self::new();
+        for (field, message) in iter {
+            validation.add(field, message);
+        }
+        validation
+    }
+}
+
+impl From<ValidationError> for HttpError {
+    fn from(value: ValidationError) -> Self {
+        HttpError::validation(
+            value
+                .errors
+                .into_iter()
+                .map(|error| (error.field, error.message)),
+        )
+    }
+}
+
+impl From<ValidationError> for Error {
+    fn from(value: ValidationError) -> Self {
+        HttpError::from(value).into()
+    }
+}
+
+#[cfg(test)]
+mod test;
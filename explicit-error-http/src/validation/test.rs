@@ -0,0 +1,63 @@
+use super::*;
+use http::StatusCode;
+
+#[test]
+fn into_result() {
+    assert!(ValidationError::new().into_result().is_ok());
+
+    let mut validation = ValidationError::new();
+    validation.add("email", "must be a valid email");
+    assert_eq!(validation.clone().into_result().unwrap_err(), validation);
+}
+
+#[test]
+fn merge() {
+    let mut validation = ValidationError::new();
+    validation.add("email", "must be a valid email");
+    validation.merge([("address.street", "is required")].into_iter().collect());
+    validation.merge(ValidationError::new());
+
+    assert_eq!(
+        validation.field_errors(),
+        [
+            FieldError {
+                field: "email".to_string(),
+                message: "must be a valid email".to_string(),
+            },
+            FieldError {
+                field: "address.street".to_string(),
+                message: "is required".to_string(),
+            },
+        ]
+    );
+}
+
+#[test]
+fn into_error() {
+    fn validate() -> crate::Result<()> {
+        let mut validation = ValidationError::new();
+        validation
+            .add("email", "must be a valid email")
+            .add("age", "must be at least 18");
+        validation.into_result()?;
+        Ok(())
+    }
+
+    let error = validate().unwrap_err().unwrap();
+    assert_eq!(
+        error.output.http_status_code,
+        StatusCode::UNPROCESSABLE_ENTITY
+    );
+    assert_eq!(error.output.content_type(), "application/problem+json");
+    assert_eq!(
+        serde_json::json!(error.output),
+        serde_json::json!({
+            "status": 422,
+            "title": "Unprocessable Entity",
+            "errors": [
+                {"field": "email", "message": "must be a valid email"},
+                {"field": "age", "message": "must be at least 18"}
+            ]
+        })
+    );
+}
@@ -2,10 +2,10 @@ use axum::{
     Router,
     body::Body,
     http::{self, Request, StatusCode},
-    routing::get,
+    routing::{get, post},
 };
 // import only derive to validate that derives work without any required import
-use super::{ErrorBody, MyDomainError};
+use super::{ErrorBody, MyDomainError, large_error};
 use explicit_error_http::derive::HandlerErrorHelpers;
 use http_body_util::BodyExt;
 use serde::Serialize;
@@ -26,6 +26,16 @@ impl explicit_error_http::HandlerError for MyHandlerError {
         }
     }
 
+    fn public_fault_response_with_context(
+        _: &explicit_error_http::Fault,
+        ctx: &explicit_error_http::ResponseContext,
+    ) -> impl Serialize {
+        ErrorBody {
+            foo: format!("{} {}", ctx.method, ctx.path),
+            bar: 500,
+        }
+    }
+
     fn error(&self) -> &explicit_error_http::Error {
         &self.0
     }
@@ -43,6 +53,13 @@ fn app() -> Router {
         .route("/domain", get(domain_error))
         .route("/domain2", get(domain_error2))
         .route("/fault", get(fault_error))
+        .route("/trailer", get(trailer_error))
+        .route("/not-modified", get(not_modified_error))
+        .route("/see-other", get(see_other_error))
+        .route("/problem", get(problem_error))
+        .route("/problem-json", get(problem_json_error))
+        .route("/rejection", post(rejection_error))
+        .route("/large", get(large_body_error))
 }
 
 #[tokio::test]
@@ -99,12 +116,284 @@ async fn handler_derive() {
     assert_eq!(resp.bar, 500);
 }
 
+#[tokio::test]
+async fn handler_derive_content_type() {
+    explicit_error_http::set_error_content_type(super::VENDOR_CONTENT_TYPE);
+
+    for uri in ["/domain", "/fault"] {
+        let resp = app()
+            .oneshot(Request::builder().uri(uri).body(Body::empty()).unwrap())
+            .await
+            .unwrap();
+        assert_eq!(
+            resp.headers().get(http::header::CONTENT_TYPE).unwrap(),
+            super::VENDOR_CONTENT_TYPE
+        );
+    }
+}
+
+#[tokio::test]
+async fn handler_derive_trailers() {
+    let resp = app()
+        .oneshot(
+            Request::builder()
+                .uri("/trailer")
+                .body(Body::empty())
+                .unwrap(),
+        )
+        .await
+        .unwrap();
+    assert_eq!(resp.status(), StatusCode::SERVICE_UNAVAILABLE);
+    let collected = resp.into_body().collect().await.unwrap();
+    assert_eq!(
+        collected.trailers().unwrap().get("grpc-status").unwrap(),
+        "14"
+    );
+    let resp =
+        serde_json::from_str::<ErrorBody>(std::str::from_utf8(&collected.to_bytes()).unwrap())
+            .unwrap();
+    assert_eq!(resp.foo, "domain");
+}
+
+#[tokio::test]
+async fn handler_derive_not_modified() {
+    let resp = app()
+        .oneshot(
+            Request::builder()
+                .uri("/not-modified")
+                .body(Body::empty())
+                .unwrap(),
+        )
+        .await
+        .unwrap();
+    assert_eq!(resp.status(), StatusCode::NOT_MODIFIED);
+    assert_eq!(
+        resp.headers().get(http::header::ETAG).unwrap(),
+        "\"33a64df5\""
+    );
+    assert!(resp.headers().get(http::header::CONTENT_TYPE).is_none());
+    assert!(
+        resp.into_body()
+            .collect()
+            .await
+            .unwrap()
+            .to_bytes()
+            .is_empty()
+    );
+}
+
+#[cfg(any(feature = "cbor", feature = "msgpack"))]
+#[tokio::test]
+async fn handler_derive_location() {
+    let resp = app()
+        .oneshot(
+            Request::builder()
+                .uri("/see-other")
+                .body(Body::empty())
+                .unwrap(),
+        )
+        .await
+        .unwrap();
+    assert_eq!(resp.status(), StatusCode::SEE_OTHER);
+    assert_eq!(
+        resp.headers().get(http::header::LOCATION).unwrap(),
+        "/orders/42"
+    );
+}
+
+#[tokio::test]
+async fn handler_derive_negotiate_error_body() {
+    #[allow(clippy::type_complexity)]
+    let formats: &[(&str, fn(&[u8]) -> ErrorBody)] = &[
+        #[cfg(feature = "cbor")]
+        ("application/cbor", |bytes| {
+            ciborium::from_reader(bytes).unwrap()
+        }),
+        #[cfg(feature = "msgpack")]
+        ("application/msgpack", |bytes| {
+            rmp_serde::from_slice(bytes).unwrap()
+        }),
+    ];
+
+    for (content_type, decode) in formats {
+        for (uri, status, foo) in [
+            ("/domain2", StatusCode::BAD_REQUEST, "domain"),
+            ("/fault", StatusCode::INTERNAL_SERVER_ERROR, "fault"),
+        ] {
+            let resp = app()
+                .layer(axum::middleware::from_fn(
+                    explicit_error_http::axum::negotiate_error_body,
+                ))
+                .oneshot(
+                    Request::builder()
+                        .uri(uri)
+                        .header(http::header::ACCEPT, *content_type)
+                        .body(Body::empty())
+                        .unwrap(),
+                )
+                .await
+                .unwrap();
+            assert_eq!(resp.status(), status);
+            assert_eq!(
+                resp.headers().get(http::header::CONTENT_TYPE).unwrap(),
+                content_type
+            );
+            let body = decode(&resp.into_body().collect().await.unwrap().to_bytes());
+            assert_eq!(body.foo, foo);
+        }
+    }
+}
+
+#[tokio::test]
+async fn handler_derive_response_context() {
+    let resp = app()
+        .layer(axum::middleware::from_fn(
+            explicit_error_http::axum::response_context,
+        ))
+        .oneshot(
+            Request::builder()
+                .uri("/fault")
+                .body(Body::empty())
+                .unwrap(),
+        )
+        .await
+        .unwrap();
+    assert_eq!(resp.status(), StatusCode::INTERNAL_SERVER_ERROR);
+    let resp =
+        serde_json::from_slice::<ErrorBody>(&resp.into_body().collect().await.unwrap().to_bytes())
+            .unwrap();
+    assert_eq!(resp.foo, "GET /fault");
+}
+
+#[tokio::test]
+async fn handler_derive_problem_json() {
+    for uri in ["/problem", "/problem-json"] {
+        let resp = app()
+            .oneshot(Request::builder().uri(uri).body(Body::empty()).unwrap())
+            .await
+            .unwrap();
+        assert_eq!(resp.status(), StatusCode::NOT_FOUND);
+        assert_eq!(
+            resp.headers().get(http::header::CONTENT_TYPE).unwrap(),
+            "application/problem+json"
+        );
+    }
+}
+
+#[tokio::test]
+async fn handler_derive_large_body() {
+    let resp = app()
+        .oneshot(
+            Request::builder()
+                .uri("/large")
+                .body(Body::empty())
+                .unwrap(),
+        )
+        .await
+        .unwrap();
+    assert_eq!(resp.status(), StatusCode::UNPROCESSABLE_ENTITY);
+    let expected = serde_json::to_vec(&large_error().public()).unwrap();
+    let mut body = resp.into_body();
+    assert_eq!(
+        axum::body::HttpBody::size_hint(&body).exact(),
+        Some(expected.len() as u64)
+    );
+
+    // The body is sent in several chunks
+    let mut bytes = Vec::new();
+    let mut frames = 0;
+    while let Some(frame) = body.frame().await {
+        bytes.extend_from_slice(&frame.unwrap().into_data().unwrap());
+        frames += 1;
+    }
+    assert!(frames > 1);
+    assert_eq!(bytes, expected);
+
+    let body: serde_json::Value = serde_json::from_slice(&bytes).unwrap();
+    assert_eq!(body["errors"].as_array().unwrap().len(), 10_000);
+    assert_eq!(body, serde_json::json!(large_error().public()));
+}
+
+#[tokio::test]
+async fn rejection() {
+    let request = || {
+        Request::builder()
+            .method(http::Method::POST)
+            .uri("/rejection")
+            .header(http::header::CONTENT_TYPE, "application/json")
+            .body(Body::from(r#"{"foo": "foo", "bar": "#))
+            .unwrap()
+    };
+
+    let resp = app().oneshot(request()).await.unwrap();
+    assert_eq!(resp.status(), StatusCode::BAD_REQUEST);
+    let body: serde_json::Value =
+        serde_json::from_slice(&resp.into_body().collect().await.unwrap().to_bytes()).unwrap();
+    assert_eq!(body["status"], 400);
+    assert_eq!(body["title"], "Bad Request");
+    assert!(
+        body["detail"]
+            .as_str()
+            .unwrap()
+            .starts_with("Failed to parse the request body as JSON")
+    );
+
+    // Data errors are unprocessable
+    let resp = app()
+        .oneshot(
+            Request::builder()
+                .method(http::Method::POST)
+                .uri("/rejection")
+                .header(http::header::CONTENT_TYPE, "application/json")
+                .body(Body::from(r#"{"foo": "foo", "bar": "bar"}"#))
+                .unwrap(),
+        )
+        .await
+        .unwrap();
+    assert_eq!(resp.status(), StatusCode::UNPROCESSABLE_ENTITY);
+
+    let resp = app()
+        .oneshot(
+            Request::builder()
+                .method(http::Method::POST)
+                .uri("/rejection")
+                .header(http::header::CONTENT_TYPE, "application/json")
+                .body(Body::from(r#"{"foo": "foo", "bar": 42}"#))
+                .unwrap(),
+        )
+        .await
+        .unwrap();
+    assert_eq!(resp.status(), StatusCode::CREATED);
+
+    explicit_error_http::axum::set_rejection_mapper(|status, _| {
+        explicit_error_http::HttpError::new(
+            status,
+            problem_details::ProblemDetails::new().with_title("Malformed request"),
+        )
+    });
+    let resp = app().oneshot(request()).await.unwrap();
+    assert_eq!(resp.status(), StatusCode::BAD_REQUEST);
+    let body: serde_json::Value =
+        serde_json::from_slice(&resp.into_body().collect().await.unwrap().to_bytes()).unwrap();
+    assert_eq!(body, serde_json::json!({"title": "Malformed request"}));
+}
+
+async fn rejection_error(
+    body: Result<axum::Json<ErrorBody>, axum::extract::rejection::JsonRejection>,
+) -> Result<StatusCode, explicit_error_http::DefaultHandlerError> {
+    let axum::Json(_) = body.map_err(explicit_error_http::DomainError::from)?;
+
+    Ok(StatusCode::CREATED)
+}
+
+async fn large_body_error() -> Result<StatusCode, explicit_error_http::DefaultHandlerError> {
+    Err(large_error())?;
+
+    Ok(StatusCode::OK)
+}
+
 async fn domain_error() -> Result<StatusCode, MyHandlerError> {
-    Err(explicit_error_http::HttpError {
-        http_status_code: http::StatusCode::FORBIDDEN,
-        public: Box::new(""),
-        context: None,
-    })?;
+    Err(explicit_error_http::HttpError::forbidden(""))?;
 
     Ok(StatusCode::OK)
 }
@@ -120,3 +409,53 @@ async fn fault_error() -> Result<StatusCode, MyHandlerError> {
 
     Ok(StatusCode::OK)
 }
+
+async fn trailer_error() -> Result<StatusCode, MyHandlerError> {
+    Err(
+        explicit_error_http::HttpError::new(http::StatusCode::SERVICE_UNAVAILABLE, "")
+            .with_trailer(
+                http::HeaderName::from_static("grpc-status"),
+                http::HeaderValue::from_static("14"),
+            ),
+    )?;
+
+    Ok(StatusCode::OK)
+}
+
+async fn not_modified_error() -> Result<StatusCode, MyHandlerError> {
+    Err(explicit_error_http::HttpError::not_modified(
+        http::HeaderValue::from_static("\"33a64df5\""),
+    ))?;
+
+    Ok(StatusCode::OK)
+}
+
+async fn see_other_error() -> Result<StatusCode, MyHandlerError> {
+    Err(
+        explicit_error_http::HttpError::new(StatusCode::SEE_OTHER, "")
+            .with_location(http::Uri::from_static("/orders/42")),
+    )?;
+
+    Ok(StatusCode::OK)
+}
+
+async fn problem_error() -> Result<StatusCode, MyHandlerError> {
+    Err(explicit_error_http::HttpError::not_found(
+        problem_details::ProblemDetails::new().with_title("Not found"),
+    ))?;
+
+    Ok(StatusCode::OK)
+}
+
+async fn problem_json_error() -> Result<StatusCode, MyHandlerError> {
+    Err(explicit_error_http::HttpError::not_found(
+        explicit_error_http::ProblemJson::new(
+            problem_details::ProblemDetails::new().with_extensions(ErrorBody {
+                foo: "foo".to_string(),
+                bar: 42,
+            }),
+        ),
+    ))?;
+
+    Ok(StatusCode::OK)
+}
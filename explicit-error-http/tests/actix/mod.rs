@@ -1,6 +1,6 @@
 use actix_web::{App, HttpResponse, body, get, http::StatusCode, test};
 // import only derive to validate that derives work without any required import
-use super::{ErrorBody, MyDomainError};
+use super::{ErrorBody, MyDomainError, large_error};
 use explicit_error_http::derive::HandlerErrorHelpers;
 use serde::Serialize;
 
@@ -19,6 +19,16 @@ impl explicit_error_http::HandlerError for MyHandlerError {
         }
     }
 
+    fn public_fault_response_with_context(
+        _: &explicit_error_http::Fault,
+        ctx: &explicit_error_http::ResponseContext,
+    ) -> impl Serialize {
+        ErrorBody {
+            foo: format!("{} {}", ctx.method, ctx.path),
+            bar: 500,
+        }
+    }
+
     fn error(&self) -> &explicit_error_http::Error {
         &self.0
     }
@@ -30,6 +40,32 @@ impl explicit_error_http::HandlerError for MyHandlerError {
         }
     }
 }
+#[derive(HandlerErrorHelpers)]
+struct RedactingHandlerError(explicit_error_http::Error);
+
+impl explicit_error_http::HandlerError for RedactingHandlerError {
+    fn from_error(value: explicit_error_http::Error) -> Self {
+        RedactingHandlerError(value)
+    }
+
+    fn public_fault_response(_: &explicit_error_http::Fault) -> impl Serialize {}
+
+    fn error(&self) -> &explicit_error_http::Error {
+        &self.0
+    }
+
+    fn domain_response(error: &explicit_error_http::DomainError) -> impl Serialize {
+        error
+    }
+
+    fn redact_domain_error(error: &mut explicit_error_http::DomainError) {
+        let status = error.output.http_status_code;
+        error.output_mut().set_public(
+            problem_details::ProblemDetails::from_status_code(status).with_title("Forbidden"),
+        );
+    }
+}
+
 #[actix_web::test]
 async fn handler_derive() {
     let app = test::init_service(
@@ -69,13 +105,192 @@ async fn handler_derive() {
     assert_eq!(resp.bar, 500);
 }
 
+#[actix_web::test]
+async fn handler_derive_content_type() {
+    explicit_error_http::set_error_content_type(super::VENDOR_CONTENT_TYPE);
+    let app = test::init_service(App::new().service(domain_error).service(fault_error)).await;
+
+    for uri in ["/domain", "/fault"] {
+        let resp = test::call_service(&app, test::TestRequest::get().uri(uri).to_request()).await;
+        assert_eq!(
+            resp.headers()
+                .get(actix_web::http::header::CONTENT_TYPE)
+                .unwrap(),
+            super::VENDOR_CONTENT_TYPE
+        );
+    }
+}
+
+#[actix_web::test]
+async fn handler_derive_not_modified() {
+    let app = test::init_service(App::new().service(not_modified_error)).await;
+
+    let resp = test::call_service(
+        &app,
+        test::TestRequest::get().uri("/not-modified").to_request(),
+    )
+    .await;
+    assert_eq!(resp.status(), StatusCode::NOT_MODIFIED);
+    assert_eq!(
+        resp.headers().get(actix_web::http::header::ETAG).unwrap(),
+        "\"33a64df5\""
+    );
+    assert!(
+        body::to_bytes(resp.into_body())
+            .await
+            .unwrap_or_default()
+            .is_empty()
+    );
+}
+
+#[actix_web::test]
+async fn handler_derive_redact_domain_error() {
+    let app = test::init_service(App::new().service(redacted_error)).await;
+
+    let resp =
+        test::call_service(&app, test::TestRequest::get().uri("/redacted").to_request()).await;
+    assert_eq!(resp.status(), StatusCode::FORBIDDEN);
+    let body = serde_json::from_slice::<serde_json::Value>(
+        &body::to_bytes(resp.into_body()).await.unwrap_or_default(),
+    )
+    .unwrap();
+    assert_eq!(body["title"], "Forbidden");
+    assert!(body.get("detail").is_none());
+}
+
+#[actix_web::test]
+async fn handler_derive_location() {
+    let app = test::init_service(App::new().service(see_other_error)).await;
+
+    let resp = test::call_service(
+        &app,
+        test::TestRequest::get().uri("/see-other").to_request(),
+    )
+    .await;
+    assert_eq!(resp.status(), StatusCode::SEE_OTHER);
+    assert_eq!(
+        resp.headers()
+            .get(actix_web::http::header::LOCATION)
+            .unwrap(),
+        "/orders/42"
+    );
+}
+
+#[cfg(any(feature = "cbor", feature = "msgpack"))]
+#[actix_web::test]
+async fn handler_derive_negotiate_error_body() {
+    let app = test::init_service(
+        App::new()
+            .wrap(actix_web::middleware::from_fn(
+                explicit_error_http::actix::negotiate_error_body,
+            ))
+            .service(domain_error2)
+            .service(fault_error),
+    )
+    .await;
+
+    #[allow(clippy::type_complexity)]
+    let formats: &[(&str, fn(&[u8]) -> ErrorBody)] = &[
+        #[cfg(feature = "cbor")]
+        ("application/cbor", |bytes| {
+            ciborium::from_reader(bytes).unwrap()
+        }),
+        #[cfg(feature = "msgpack")]
+        ("application/msgpack", |bytes| {
+            rmp_serde::from_slice(bytes).unwrap()
+        }),
+    ];
+
+    for (content_type, decode) in formats {
+        for (uri, status, foo) in [
+            ("/domain2", StatusCode::BAD_REQUEST, "domain"),
+            ("/fault", StatusCode::INTERNAL_SERVER_ERROR, "fault"),
+        ] {
+            let resp = test::call_service(
+                &app,
+                test::TestRequest::get()
+                    .uri(uri)
+                    .insert_header((actix_web::http::header::ACCEPT, *content_type))
+                    .to_request(),
+            )
+            .await;
+            assert_eq!(resp.status(), status);
+            assert_eq!(
+                resp.headers()
+                    .get(actix_web::http::header::CONTENT_TYPE)
+                    .unwrap(),
+                content_type
+            );
+            let body = decode(&body::to_bytes(resp.into_body()).await.unwrap_or_default());
+            assert_eq!(body.foo, foo);
+        }
+    }
+}
+
+#[actix_web::test]
+async fn handler_derive_response_context() {
+    let app = test::init_service(
+        App::new()
+            .wrap(actix_web::middleware::from_fn(
+                explicit_error_http::actix::response_context,
+            ))
+            .service(fault_error),
+    )
+    .await;
+
+    let resp = test::call_service(&app, test::TestRequest::get().uri("/fault").to_request()).await;
+    assert_eq!(resp.status(), StatusCode::INTERNAL_SERVER_ERROR);
+    let body: ErrorBody = test::read_body_json(resp).await;
+    assert_eq!(body.foo, "GET /fault");
+}
+
+#[actix_web::test]
+async fn handler_derive_large_body() {
+    let app = test::init_service(App::new().service(large_body_error)).await;
+
+    let resp = test::call_service(&app, test::TestRequest::get().uri("/large").to_request()).await;
+    assert_eq!(resp.status(), StatusCode::UNPROCESSABLE_ENTITY);
+    let expected = serde_json::to_vec(&large_error().public()).unwrap();
+    assert_eq!(
+        body::MessageBody::size(resp.response().body()),
+        body::BodySize::Sized(expected.len() as u64)
+    );
+    let body: serde_json::Value = test::read_body_json(resp).await;
+    assert_eq!(body["errors"].as_array().unwrap().len(), 10_000);
+    assert_eq!(body, serde_json::json!(large_error().public()));
+}
+
+#[actix_web::test]
+async fn handler_derive_problem_json() {
+    let app = test::init_service(
+        App::new()
+            .service(problem_error)
+            .service(problem_json_error),
+    )
+    .await;
+
+    for uri in ["/problem", "/problem-json"] {
+        let resp = test::call_service(&app, test::TestRequest::get().uri(uri).to_request()).await;
+        assert_eq!(resp.status(), StatusCode::NOT_FOUND);
+        assert_eq!(
+            resp.headers()
+                .get(actix_web::http::header::CONTENT_TYPE)
+                .unwrap(),
+            "application/problem+json"
+        );
+    }
+}
+
+#[get("/large")]
+async fn large_body_error() -> Result<HttpResponse, explicit_error_http::DefaultHandlerError> {
+    Err(large_error())?;
+
+    Ok(HttpResponse::Ok().finish())
+}
+
 #[get("/domain")]
 async fn domain_error() -> Result<HttpResponse, MyHandlerError> {
-    Err(explicit_error_http::HttpError {
-        http_status_code: http::StatusCode::FORBIDDEN,
-        public: Box::new(""),
-        context: None,
-    })?;
+    Err(explicit_error_http::HttpError::forbidden(""))?;
 
     Ok(HttpResponse::Ok().finish())
 }
@@ -93,3 +308,54 @@ async fn fault_error() -> Result<HttpResponse, MyHandlerError> {
 
     Ok(HttpResponse::Ok().finish())
 }
+
+#[get("/not-modified")]
+async fn not_modified_error() -> Result<HttpResponse, MyHandlerError> {
+    Err(explicit_error_http::HttpError::not_modified(
+        http::HeaderValue::from_static("\"33a64df5\""),
+    ))?;
+
+    Ok(HttpResponse::Ok().finish())
+}
+
+#[get("/redacted")]
+async fn redacted_error() -> Result<HttpResponse, RedactingHandlerError> {
+    Err(explicit_error_http::HttpError::forbidden(
+        problem_details::ProblemDetails::new().with_detail("User 42 lacks the billing:write scope"),
+    ))?;
+
+    Ok(HttpResponse::Ok().finish())
+}
+
+#[get("/see-other")]
+async fn see_other_error() -> Result<HttpResponse, MyHandlerError> {
+    Err(
+        explicit_error_http::HttpError::new(http::StatusCode::SEE_OTHER, "")
+            .with_location(http::Uri::from_static("/orders/42")),
+    )?;
+
+    Ok(HttpResponse::Ok().finish())
+}
+
+#[get("/problem")]
+async fn problem_error() -> Result<HttpResponse, MyHandlerError> {
+    Err(explicit_error_http::HttpError::not_found(
+        problem_details::ProblemDetails::new().with_title("Not found"),
+    ))?;
+
+    Ok(HttpResponse::Ok().finish())
+}
+
+#[get("/problem-json")]
+async fn problem_json_error() -> Result<HttpResponse, MyHandlerError> {
+    Err(explicit_error_http::HttpError::not_found(
+        explicit_error_http::ProblemJson::new(
+            problem_details::ProblemDetails::new().with_extensions(ErrorBody {
+                foo: "foo".to_string(),
+                bar: 42,
+            }),
+        ),
+    ))?;
+
+    Ok(HttpResponse::Ok().finish())
+}
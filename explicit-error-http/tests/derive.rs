@@ -10,6 +10,16 @@ use explicit_error_http::{DomainError, Error, HttpError, derive::HttpError};
 use http::StatusCode;
 use serde::{Deserialize, Serialize};
 
+// Only asserted by tests setting it, `set_error_content_type` is global to the test binary
+#[cfg(any(feature = "actix-web", feature = "axum"))]
+const VENDOR_CONTENT_TYPE: &str = "application/vnd.acme.error+json";
+
+// Validation report large enough to be representative of the bodies that are costly to build
+#[cfg(any(feature = "actix-web", feature = "axum"))]
+fn large_error() -> HttpError {
+    HttpError::validation((0..10_000).map(|i| (format!("items[{i}].name"), "must not be empty")))
+}
+
 #[derive(HandlerErrorHelpers)]
 struct MyHandlerError(explicit_error_http::Error);
 
@@ -54,7 +64,27 @@ fn converts() {
         .unwrap_fault();
 }
 
-#[derive(Serialize, Deserialize)]
+#[cfg(feature = "test-util")]
+#[test]
+fn assert_error_response() {
+    use explicit_error_http::test_util::{assert_error_response, response_parts};
+
+    assert_error_response(
+        response_parts(&MyHandlerError::from(Fault::new())),
+        StatusCode::INTERNAL_SERVER_ERROR,
+        serde_json::json!({"foo": "fault", "bar": 500}),
+    );
+    assert_error_response(
+        response_parts(&MyHandlerError::from(HttpError::new(
+            StatusCode::FORBIDDEN,
+            "",
+        ))),
+        StatusCode::FORBIDDEN,
+        serde_json::json!({"foo": "domain", "bar": 200}),
+    );
+}
+
+#[derive(Serialize, Deserialize, Clone)]
 struct ErrorBody {
     foo: String,
     bar: i64,
@@ -65,14 +95,14 @@ struct MyDomainError;
 
 impl From<&MyDomainError> for explicit_error_http::HttpError {
     fn from(_: &MyDomainError) -> Self {
-        explicit_error_http::HttpError {
-            http_status_code: StatusCode::BAD_REQUEST,
-            public: Box::new(ErrorBody {
+        explicit_error_http::HttpError::new(
+            StatusCode::BAD_REQUEST,
+            ErrorBody {
                 foo: "foo".to_string(),
                 bar: 42,
-            }),
-            context: Some("context".to_string()),
-        }
+            },
+        )
+        .with_context("context")
     }
 }
 
@@ -82,14 +112,14 @@ fn http_error() {
 
     assert_eq!(
         error.output,
-        explicit_error_http::HttpError {
-            http_status_code: StatusCode::BAD_REQUEST,
-            public: Box::new(ErrorBody {
+        explicit_error_http::HttpError::new(
+            StatusCode::BAD_REQUEST,
+            ErrorBody {
                 foo: "foo".to_string(),
                 bar: 42,
-            }),
-            context: Some("context".to_string()),
-        }
+            },
+        )
+        .with_context("context")
     );
     assert!(
         error
@@ -102,6 +132,66 @@ fn http_error() {
 
     assert_eq!(
         error.to_string(),
-        r#"{"context":"context","http_status_code":400,"public":{"bar":42,"foo":"foo"},"source":"MyDomainError"}"#
+        r#"{"http_status_code":400,"context":"context","public":{"foo":"foo","bar":42},"source":"MyDomainError"}"#
+    );
+}
+
+#[test]
+fn try_from_error() {
+    assert!(MyDomainError::try_from(Error::from(MyDomainError)).is_ok());
+    assert!(
+        MyDomainError::try_from(Error::from(
+            HttpError::new(StatusCode::BAD_REQUEST, "").with_source(MyDomainError)
+        ))
+        .is_ok()
+    );
+
+    let error = MyDomainError::try_from(Error::from(HttpError::new(StatusCode::FORBIDDEN, "")))
+        .unwrap_err();
+    assert_eq!(
+        error.unwrap().output.http_status_code,
+        StatusCode::FORBIDDEN
+    );
+    MyDomainError::try_from(Error::Fault(Fault::new()))
+        .unwrap_err()
+        .unwrap_fault();
+}
+
+#[derive(Debug, HttpError)]
+#[explicit_error(transparent)]
+struct TransparentError(MyDomainError);
+
+#[derive(Debug, HttpError)]
+enum TransparentEnum {
+    #[explicit_error(transparent)]
+    Domain { inner: MyDomainError },
+}
+
+#[test]
+fn transparent() {
+    use std::error::Error as _;
+
+    assert_eq!(
+        HttpError::from(&TransparentError(MyDomainError)),
+        HttpError::from(&MyDomainError)
+    );
+    assert!(
+        TransparentError(MyDomainError)
+            .source()
+            .unwrap()
+            .downcast_ref::<MyDomainError>()
+            .is_some()
+    );
+
+    let error = TransparentEnum::Domain {
+        inner: MyDomainError,
+    };
+    assert_eq!(HttpError::from(&error), HttpError::from(&MyDomainError));
+    assert!(
+        error
+            .source()
+            .unwrap()
+            .downcast_ref::<MyDomainError>()
+            .is_some()
     );
 }
@@ -0,0 +1,15 @@
+#[test]
+fn ui() {
+    let t = trybuild::TestCases::new();
+    t.pass("tests/ui/generic.rs");
+    t.pass("tests/ui/handler_enum.rs");
+    t.pass("tests/ui/map.rs");
+    t.pass("tests/ui/problem_type.rs");
+    t.pass("tests/ui/source.rs");
+    t.pass("tests/ui/transparent.rs");
+    t.compile_fail("tests/ui/transparent_*_fail.rs");
+    t.compile_fail("tests/ui/handler_enum_*_fail.rs");
+    t.compile_fail("tests/ui/map_*_fail.rs");
+    t.compile_fail("tests/ui/problem_type_*_fail.rs");
+    t.compile_fail("tests/ui/default_handler_*_fail.rs");
+}
@@ -0,0 +1,11 @@
+use explicit_error_http::{DefaultHandlerConfig, DefaultHandlerError, Fault, HandlerError};
+
+struct MyConfig;
+
+impl DefaultHandlerConfig for MyConfig {
+    const FAULT_TYPE: Option<&'static str> = Some("/errors/internal server error");
+}
+
+fn main() {
+    let _ = DefaultHandlerError::<MyConfig>::public_fault_response(&Fault::new());
+}
@@ -0,0 +1,40 @@
+use explicit_error_http::{Error, HttpError, derive::HttpError};
+use http::StatusCode;
+use serde::Serialize;
+
+#[derive(HttpError, Debug)]
+enum ApiError<T: Serialize> {
+    Invalid(T),
+}
+
+impl<T> From<&ApiError<T>> for HttpError
+where
+    T: Serialize + Clone + Send + Sync + 'static,
+{
+    fn from(value: &ApiError<T>) -> Self {
+        match value {
+            ApiError::Invalid(body) => HttpError::bad_request(body.clone()),
+        }
+    }
+}
+
+#[derive(HttpError, Debug)]
+#[explicit_error(transparent)]
+struct Wrapper<T: Serialize>(ApiError<T>);
+
+#[derive(Serialize, Clone, Debug, PartialEq)]
+struct Body {
+    field: &'static str,
+}
+
+fn main() {
+    let error = Error::from(ApiError::Invalid(Body { field: "name" }));
+    assert!(error.to_string().contains(r#""public":{"field":"name"}"#));
+    assert!(matches!(
+        ApiError::<Body>::try_from(error),
+        Ok(ApiError::Invalid(Body { field: "name" }))
+    ));
+
+    let error = Error::from(Wrapper(ApiError::Invalid(Body { field: "name" })));
+    assert_eq!(error.unwrap().output.http_status_code, StatusCode::BAD_REQUEST);
+}
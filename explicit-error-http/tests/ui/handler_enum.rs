@@ -0,0 +1,73 @@
+use explicit_error_http::{DefaultHandlerError, HttpError, derive::HandlerErrorHelpers};
+use http::StatusCode;
+
+#[derive(Debug)]
+struct Rejection;
+
+impl std::fmt::Display for Rejection {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "Invalid JSON body")
+    }
+}
+
+impl std::error::Error for Rejection {}
+
+impl actix_web::ResponseError for Rejection {
+    fn status_code(&self) -> actix_web::http::StatusCode {
+        actix_web::http::StatusCode::UNSUPPORTED_MEDIA_TYPE
+    }
+}
+
+impl axum::response::IntoResponse for Rejection {
+    fn into_response(self) -> axum::response::Response {
+        StatusCode::UNSUPPORTED_MEDIA_TYPE.into_response()
+    }
+}
+
+#[derive(HandlerErrorHelpers)]
+enum ApiError {
+    Json(Rejection),
+    #[explicit_error]
+    Explicit(DefaultHandlerError),
+}
+
+#[derive(HandlerErrorHelpers)]
+enum NamedApiError {
+    #[explicit_error]
+    Explicit { error: DefaultHandlerError },
+}
+
+fn main() {
+    let error = ApiError::from(HttpError::new(StatusCode::NOT_FOUND, "Not found"));
+    assert!(matches!(error, ApiError::Explicit(_)));
+    assert_eq!(ApiError::Json(Rejection).to_string(), "Invalid JSON body");
+    assert!(matches!(
+        NamedApiError::from(explicit_error_http::Fault::new()),
+        NamedApiError::Explicit { .. }
+    ));
+
+    #[cfg(feature = "axum")]
+    {
+        use axum::response::IntoResponse;
+        assert_eq!(error.into_response().status(), StatusCode::NOT_FOUND);
+        assert_eq!(
+            ApiError::Json(Rejection).into_response().status(),
+            StatusCode::UNSUPPORTED_MEDIA_TYPE
+        );
+    }
+
+    #[cfg(feature = "actix-web")]
+    {
+        use actix_web::ResponseError;
+        assert_eq!(
+            ApiError::from(HttpError::new(StatusCode::FORBIDDEN, ""))
+                .error_response()
+                .status(),
+            actix_web::http::StatusCode::FORBIDDEN
+        );
+        assert_eq!(
+            ApiError::Json(Rejection).error_response().status(),
+            actix_web::http::StatusCode::UNSUPPORTED_MEDIA_TYPE
+        );
+    }
+}
@@ -0,0 +1,8 @@
+use explicit_error_http::{DefaultHandlerError, derive::HandlerErrorHelpers};
+
+#[derive(HandlerErrorHelpers)]
+enum ApiError {
+    Explicit(DefaultHandlerError),
+}
+
+fn main() {}
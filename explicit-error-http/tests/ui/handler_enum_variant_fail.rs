@@ -0,0 +1,10 @@
+use explicit_error_http::{DefaultHandlerError, derive::HandlerErrorHelpers};
+
+#[derive(HandlerErrorHelpers)]
+enum ApiError {
+    #[explicit_error]
+    Explicit(DefaultHandlerError),
+    Unit,
+}
+
+fn main() {}
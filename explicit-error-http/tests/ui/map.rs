@@ -0,0 +1,51 @@
+use explicit_error_http::{Error, HttpError, derive::HttpError};
+use http::StatusCode;
+
+#[derive(HttpError, Debug)]
+#[explicit_error(map(
+    Foo => (404, "/errors/foo", "Foo not found"),
+    Bar => (400, "/errors/bar", "Invalid bar"),
+))]
+enum MyError {
+    Foo,
+    Bar { id: u32 },
+}
+
+#[derive(HttpError, Debug)]
+#[explicit_error(map(
+    NotFound => (404, "/errors/not-found", "Not found"),
+    _ => fallback,
+))]
+enum MixedError {
+    NotFound(u32),
+    Conflict(String),
+}
+
+fn fallback(value: &MixedError) -> HttpError {
+    match value {
+        MixedError::Conflict(field) => HttpError::conflict(format!("{field} already used")),
+        MixedError::NotFound(_) => unreachable!(),
+    }
+}
+
+fn main() {
+    let error = Error::from(MyError::Foo).unwrap();
+    assert_eq!(error.output.http_status_code, StatusCode::NOT_FOUND);
+    assert_eq!(
+        serde_json::json!(error.output),
+        serde_json::json!({"type": "/errors/foo", "status": 404, "title": "Foo not found"})
+    );
+    assert_eq!(
+        HttpError::from(&MyError::Bar { id: 42 }).http_status_code,
+        StatusCode::BAD_REQUEST
+    );
+
+    assert_eq!(
+        HttpError::from(&MixedError::NotFound(42)).user_message(),
+        Some("Not found")
+    );
+    assert_eq!(
+        HttpError::from(&MixedError::Conflict("email".to_string())).http_status_code,
+        StatusCode::CONFLICT
+    );
+}
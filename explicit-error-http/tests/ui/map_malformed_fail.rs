@@ -0,0 +1,9 @@
+use explicit_error_http::derive::HttpError;
+
+#[derive(HttpError, Debug)]
+#[explicit_error(map(Foo => (404, "/errors/foo")))]
+enum MyError {
+    Foo,
+}
+
+fn main() {}
@@ -0,0 +1,10 @@
+use explicit_error_http::derive::HttpError;
+
+#[derive(HttpError, Debug)]
+#[explicit_error(map(Foo => (404, "/errors/foo", "Foo not found")))]
+enum MyError {
+    Foo,
+    Bar,
+}
+
+fn main() {}
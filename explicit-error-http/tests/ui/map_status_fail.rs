@@ -0,0 +1,9 @@
+use explicit_error_http::derive::HttpError;
+
+#[derive(HttpError, Debug)]
+#[explicit_error(map(Foo => (42, "/errors/foo", "Foo not found")))]
+enum MyError {
+    Foo,
+}
+
+fn main() {}
@@ -0,0 +1,12 @@
+use explicit_error_http::derive::HttpError;
+
+#[derive(HttpError, Debug)]
+#[explicit_error(map(
+    Foo => (404, "/errors/foo", "Foo not found"),
+    Baz => (400, "/errors/baz", "Invalid baz"),
+))]
+enum MyError {
+    Foo,
+}
+
+fn main() {}
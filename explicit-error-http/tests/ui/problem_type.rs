@@ -0,0 +1,15 @@
+use explicit_error_http::problem_type;
+
+const PROBLEM_TYPES: &[&str] = &["/errors/not-found", "https://example.com/errors/conflict"];
+
+fn main() {
+    assert_eq!(problem_type!("/errors/not-found"), "/errors/not-found");
+    assert_eq!(
+        problem_type!("/errors/not-found", PROBLEM_TYPES),
+        "/errors/not-found"
+    );
+    assert_eq!(
+        problem_type!("https://example.com/errors/conflict", PROBLEM_TYPES,),
+        "https://example.com/errors/conflict"
+    );
+}
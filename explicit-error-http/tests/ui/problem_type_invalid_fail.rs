@@ -0,0 +1,5 @@
+use explicit_error_http::problem_type;
+
+fn main() {
+    problem_type!("/errors/not found");
+}
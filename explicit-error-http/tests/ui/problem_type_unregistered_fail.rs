@@ -0,0 +1,7 @@
+use explicit_error_http::problem_type;
+
+const PROBLEM_TYPES: &[&str] = &["/errors/not-found"];
+
+fn main() {
+    problem_type!("/errors/not-fuond", PROBLEM_TYPES);
+}
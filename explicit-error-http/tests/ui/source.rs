@@ -0,0 +1,31 @@
+use explicit_error_http::{HttpError, derive::HttpError};
+use http::StatusCode;
+use std::error::Error;
+
+#[derive(HttpError, Debug)]
+enum UploadError {
+    Io(#[from] std::io::Error),
+    TooLarge {
+        #[source]
+        source: std::num::TryFromIntError,
+        size: u64,
+    },
+}
+
+impl From<&UploadError> for HttpError {
+    fn from(_: &UploadError) -> Self {
+        HttpError::new(StatusCode::SERVICE_UNAVAILABLE, "")
+    }
+}
+
+fn main() {
+    assert!(UploadError::from(std::io::Error::other("io")).source().is_some());
+    assert!(
+        UploadError::TooLarge {
+            source: u8::try_from(300_u64).unwrap_err(),
+            size: 300,
+        }
+        .source()
+        .is_some()
+    );
+}
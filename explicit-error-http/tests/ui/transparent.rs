@@ -0,0 +1,37 @@
+use explicit_error_http::{HttpError, derive::HttpError};
+use http::StatusCode;
+
+#[derive(HttpError, Debug)]
+struct Inner;
+
+impl From<&Inner> for HttpError {
+    fn from(_: &Inner) -> Self {
+        HttpError::new(StatusCode::BAD_REQUEST, "inner")
+    }
+}
+
+#[derive(HttpError, Debug)]
+#[explicit_error(transparent)]
+struct Tuple(Inner);
+
+#[derive(HttpError, Debug)]
+#[explicit_error(transparent)]
+struct Named {
+    inner: Inner,
+}
+
+#[derive(HttpError, Debug)]
+enum Enum {
+    #[explicit_error(transparent)]
+    Tuple(Tuple),
+    #[explicit_error(transparent)]
+    Named { named: Named },
+}
+
+fn main() {
+    let _ = HttpError::from(&Tuple(Inner));
+    let _ = HttpError::from(&Named { inner: Inner });
+    let _ = HttpError::from(&Enum::Named {
+        named: Named { inner: Inner },
+    });
+}
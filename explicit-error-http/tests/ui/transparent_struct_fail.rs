@@ -0,0 +1,17 @@
+use explicit_error_http::{HttpError, derive::HttpError};
+use http::StatusCode;
+
+#[derive(HttpError, Debug)]
+struct Inner;
+
+impl From<&Inner> for HttpError {
+    fn from(_: &Inner) -> Self {
+        HttpError::new(StatusCode::BAD_REQUEST, "inner")
+    }
+}
+
+#[derive(HttpError, Debug)]
+#[explicit_error(transparent)]
+struct Outer(Inner, u32);
+
+fn main() {}
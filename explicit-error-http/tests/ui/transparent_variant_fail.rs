@@ -0,0 +1,19 @@
+use explicit_error_http::{HttpError, derive::HttpError};
+use http::StatusCode;
+
+#[derive(HttpError, Debug)]
+struct Inner;
+
+impl From<&Inner> for HttpError {
+    fn from(_: &Inner) -> Self {
+        HttpError::new(StatusCode::BAD_REQUEST, "inner")
+    }
+}
+
+#[derive(HttpError, Debug)]
+enum Outer {
+    #[explicit_error(transparent)]
+    Foo { inner: Inner, id: u32 },
+}
+
+fn main() {}
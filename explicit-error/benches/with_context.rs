@@ -0,0 +1,77 @@
+//! Compare the cost of a context built with [format!] and with [format_args!] on a [Result],
+//! on the happy path where it is discarded and when the [Fault] is returned.
+//!
+//! Run with `cargo bench -p explicit-error --bench with_context`.
+
+use explicit_error::{Fault, prelude::*};
+use std::{
+    alloc::{GlobalAlloc, Layout, System},
+    hint::black_box,
+    sync::atomic::{AtomicUsize, Ordering},
+    time::{Duration, Instant},
+};
+
+const ITERATIONS: usize = 1_000_000;
+
+static ALLOCATIONS: AtomicUsize = AtomicUsize::new(0);
+
+struct CountingAllocator;
+
+unsafe impl GlobalAlloc for CountingAllocator {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        ALLOCATIONS.fetch_add(1, Ordering::Relaxed);
+        unsafe { System.alloc(layout) }
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        unsafe { System.dealloc(ptr, layout) }
+    }
+}
+
+#[global_allocator]
+static ALLOCATOR: CountingAllocator = CountingAllocator;
+
+fn result(ok: bool) -> Result<u32, Fault> {
+    match black_box(ok) {
+        true => Ok(42),
+        false => Err(Fault::new()),
+    }
+}
+
+/// Return the allocations and the duration per iteration
+fn measure(op: impl Fn() -> Result<u32, Fault>) -> (f64, Duration) {
+    let allocations = ALLOCATIONS.load(Ordering::Relaxed);
+    let start = Instant::now();
+    for _ in 0..ITERATIONS {
+        let _ = black_box(op());
+    }
+    let elapsed = start.elapsed();
+
+    (
+        (ALLOCATIONS.load(Ordering::Relaxed) - allocations) as f64 / ITERATIONS as f64,
+        elapsed / ITERATIONS as u32,
+    )
+}
+
+fn main() {
+    let user_id = black_box(42);
+
+    for ok in [true, false] {
+        for (name, op) in [
+            (
+                "format!",
+                (&|| result(ok).with_context(format!("User: {user_id}")))
+                    as &dyn Fn() -> Result<u32, Fault>,
+            ),
+            ("format_args!", &|| {
+                result(ok).with_context(format_args!("User: {user_id}"))
+            }),
+        ] {
+            let (allocations, duration) = measure(op);
+            println!(
+                "{:>3} {name:>12}: {allocations:.1} allocations, {duration:?} per result",
+                if ok { "Ok" } else { "Err" }
+            );
+        }
+    }
+}
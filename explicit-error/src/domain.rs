@@ -1,17 +1,62 @@
+use crate::Extensions;
+use std::{any::Any, sync::Arc};
+
+static EMPTY_EXTENSIONS: Extensions = Extensions::EMPTY;
+
 /// This trait must be implemented for type that converts to [Error](crate::Error)
 /// Example of such implementation can be found in crates `explicit-error-http` or `explicit-error-exit` for `DomainError`.
+///
+/// Implementors do not have to provide `From<Self> for Error<Self>`, it is implemented for any [Domain] so `?` converts
+/// `Result<T, Self>`, `Result<T, Fault>` and `Result<T, Error<Self>>` alike in a function returning `Result<T, Error<Self>>`.
 pub trait Domain
 where
-    Self: std::error::Error
-        + 'static
-        + std::fmt::Debug
-        + Into<crate::error::Error<Self>>
-        + Send
-        + Sync,
+    Self: std::error::Error + 'static + std::fmt::Debug + Send + Sync + Sized,
 {
     fn with_context(self, context: impl std::fmt::Display) -> Self;
 
+    /// Add a context if there is one, see [with_context](Domain::with_context)
+    fn with_context_opt(self, context: Option<impl std::fmt::Display>) -> Self {
+        match context {
+            Some(context) => self.with_context(context),
+            None => self,
+        }
+    }
+
     fn context(&self) -> Option<&str>;
 
+    /// Mutable access to the context, eg: to enrich it in place in a middleware.
+    /// [None] by default, implementors opt in by overriding it. [Error::append_context](crate::Error::append_context)
+    /// works either way, it rebuilds the error with [with_context](Domain::with_context) when it is [None].
+    fn context_mut(&mut self) -> Option<&mut Option<String>> {
+        None
+    }
+
     fn into_source(self) -> Option<Box<dyn std::error::Error + Send + Sync>>;
+
+    /// Return the extensions attached with [with_extension](Domain::with_extension). Empty by default,
+    /// implementors that store [Extensions] opt in by overriding it and [extensions_mut](Domain::extensions_mut).
+    fn extensions(&self) -> &Extensions {
+        &EMPTY_EXTENSIONS
+    }
+
+    /// Mutable access to the extensions, [None] by default: [with_extension](Domain::with_extension) is then a no-op.
+    fn extensions_mut(&mut self) -> Option<&mut Extensions> {
+        None
+    }
+
+    fn with_extension<T: Any + Send + Sync>(mut self, extension: Arc<T>) -> Self {
+        if let Some(extensions) = self.extensions_mut() {
+            extensions.insert(extension);
+        }
+        self
+    }
+
+    fn extension<T: Any + Send + Sync>(&self) -> Option<&T> {
+        self.extensions().get()
+    }
+
+    /// A single human-readable sentence describing the error, eg: for a toast or a notification. None by default.
+    fn user_message(&self) -> Option<&str> {
+        None
+    }
 }
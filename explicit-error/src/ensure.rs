@@ -0,0 +1,66 @@
+/// Return early with a domain error if the condition is not met. The error is converted with [From], like the `?` operator does.
+///
+/// Re-exported by the preludes, it is a shorthand for `if !cond { Err(error)? }`.
+/// # Examples
+/// ```rust
+/// use explicit_error_exit::{prelude::*, ExitError, Result};
+/// use std::process::ExitCode;
+///
+/// fn check_age(age: u8) -> Result<()> {
+///     ensure_domain!(age >= 18, ExitError::new("Too young", ExitCode::from(42)));
+///     Ok(())
+/// }
+///
+/// assert!(check_age(20).is_ok());
+/// assert!(check_age(12).unwrap_err().is_domain());
+/// ```
+#[macro_export]
+macro_rules! ensure_domain {
+    ($cond:expr, $error:expr $(,)?) => {
+        if !$cond {
+            return ::core::result::Result::Err(::core::convert::From::from($error));
+        }
+    };
+}
+
+/// Return early with a [Fault](crate::Fault) if the condition is not met. The message, formatted like [format!],
+/// is set as the context of the [Fault](crate::Fault). Without message, the context is the stringified condition.
+///
+/// Re-exported by the preludes, it is a shorthand for `if !cond { Err(Fault::new().with_context(..))? }`.
+/// # Examples
+/// ```rust
+/// use explicit_error_exit::{prelude::*, Result};
+///
+/// fn checksum(bytes: &[u8], len: usize) -> Result<u8> {
+///     ensure_fault!(bytes.len() == len, "Expected {len} bytes, got {}", bytes.len());
+///     ensure_fault!(!bytes.is_empty());
+///     Ok(bytes.iter().fold(0, |acc, b| acc ^ b))
+/// }
+///
+/// assert_eq!(checksum(&[1, 2], 2).ok(), Some(3));
+/// assert_eq!(
+///     checksum(&[1, 2], 3).unwrap_err().unwrap_fault().context(),
+///     Some("Expected 3 bytes, got 2")
+/// );
+/// ```
+#[macro_export]
+macro_rules! ensure_fault {
+    ($cond:expr $(,)?) => {
+        if !$cond {
+            return ::core::result::Result::Err(::core::convert::From::from(
+                $crate::Fault::new().with_context(::core::concat!(
+                    "Condition failed: `",
+                    ::core::stringify!($cond),
+                    "`"
+                )),
+            ));
+        }
+    };
+    ($cond:expr, $($message:tt)+) => {
+        if !$cond {
+            return ::core::result::Result::Err(::core::convert::From::from(
+                $crate::Fault::new().with_context(::std::format!($($message)+)),
+            ));
+        }
+    };
+}
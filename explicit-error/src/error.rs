@@ -1,7 +1,19 @@
 use crate::domain::Domain;
 use crate::fault::*;
 use crate::unwrap_failed;
-use std::{error::Error as StdError, fmt::Display};
+#[cfg(feature = "otel")]
+use opentelemetry::trace::{SpanId, TraceId};
+#[cfg(feature = "otel")]
+use serde::ser::SerializeStruct;
+use serde::{Serialize, Serializer, ser::SerializeMap};
+use std::{
+    any::{Any, TypeId},
+    collections::HashMap,
+    error::Error as StdError,
+    fmt::Display,
+    panic::Location,
+    sync::{Arc, RwLock},
+};
 
 /// Use `Result<T, explicit_error::Error>` as the return type of any binary crate
 /// faillible function returning errors.
@@ -14,6 +26,17 @@ pub enum Error<D> {
     Fault(Fault),
 }
 
+/// Any type implementing [Domain] converts to the [Error::Domain] variant. Along with `From<Fault>`, it enables `?` on
+/// `Result<T, Fault>`, `Result<T, D>` and `Result<T, Error<D>>` in the same function returning `Result<T, Error<D>>`.
+impl<D> From<D> for Error<D>
+where
+    D: Domain,
+{
+    fn from(value: D) -> Self {
+        Error::Domain(Box::new(value))
+    }
+}
+
 impl<D> StdError for Error<D>
 where
     D: StdError + 'static,
@@ -40,35 +63,157 @@ where
     }
 }
 
+static ERROR_VARIANT_TAG: RwLock<&'static str> = RwLock::new("error_kind");
+
+/// Set the key of the field holding the variant, `"domain"` or `"fault"`, when an [Error] is serialized.
+/// Usefull to match the schema of structured logs.
+///
+/// Default to `error_kind`. Choose a key that the serialized domain errors do not have, eg: not `type` with a
+/// [problem details](https://www.rfc-editor.org/rfc/rfc9457) body, or the variant would be written twice.
+/// # Examples
+/// ```rust
+/// # use explicit_error_exit::Fault;
+/// explicit_error::set_error_variant_tag("category");
+///
+/// let error = explicit_error_http::Error::Fault(Fault::new());
+/// assert_eq!(serde_json::json!(error)["category"], "fault");
+/// ```
+pub fn set_error_variant_tag(key: &'static str) {
+    *ERROR_VARIANT_TAG
+        .write()
+        .unwrap_or_else(|poisoned| poisoned.into_inner()) = key;
+}
+
+/// Serialize the wrapped type flattened with the variant under the key set with [set_error_variant_tag].
+impl<D> Serialize for Error<D>
+where
+    D: Serialize,
+{
+    fn serialize<S>(&self, s: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        #[derive(Serialize)]
+        struct Tagged<'a, T: Serialize> {
+            #[serde(flatten)]
+            tag: VariantTag,
+            #[serde(flatten)]
+            error: &'a T,
+        }
+
+        struct VariantTag(&'static str);
+
+        impl Serialize for VariantTag {
+            fn serialize<S>(&self, s: S) -> Result<S::Ok, S::Error>
+            where
+                S: Serializer,
+            {
+                let key = *ERROR_VARIANT_TAG
+                    .read()
+                    .unwrap_or_else(|poisoned| poisoned.into_inner());
+                let mut map = s.serialize_map(Some(1))?;
+                map.serialize_entry(key, self.0)?;
+                map.end()
+            }
+        }
+
+        match self {
+            Error::Domain(d) => Tagged {
+                tag: VariantTag("domain"),
+                error: d.as_ref(),
+            }
+            .serialize(s),
+            Error::Fault(fault) => Tagged {
+                tag: VariantTag("fault"),
+                error: fault,
+            }
+            .serialize(s),
+        }
+    }
+}
+
 impl<D> Error<D>
 where
     D: StdError + 'static,
 {
+    /// Generate an [Error::Domain] variant, without boxing the domain error yourself.
+    /// # Examples
+    /// ```rust
+    /// # use explicit_error_exit::{DomainError, Error, ExitError};
+    /// # use std::process::ExitCode;
+    /// let error = Error::new_domain(DomainError {
+    ///     output: ExitError::new("Something went wrong", ExitCode::from(42)),
+    ///     source: None,
+    /// });
+    /// assert!(error.is_domain());
+    /// ```
+    pub fn new_domain(domain: D) -> Self {
+        Error::Domain(Box::new(domain))
+    }
+
+    /// Generate an [Error::Fault] variant, equivalent to `Error::from(fault)`.
+    /// # Examples
+    /// ```rust
+    /// # use explicit_error_exit::{Error, Fault};
+    /// let error = Error::new_fault(Fault::new().with_context("Unreachable"));
+    /// assert!(error.is_fault());
+    /// ```
+    pub fn new_fault(fault: Fault) -> Self {
+        Error::Fault(fault)
+    }
+
     /// Return true if it's a [Error::Domain] variant
+    #[must_use]
     pub fn is_domain(&self) -> bool {
         matches!(*self, Error::Domain(_))
     }
 
     /// Return true if it's a [Error::Fault] variant
+    #[must_use]
     pub fn is_fault(&self) -> bool {
         !self.is_domain()
     }
 
-    /// Unwrap the [Error::Domain] variant, panic otherwise
+    /// Unwrap the [Error::Domain] variant, panic otherwise with the first line of the context of the [Fault]
+    #[track_caller]
     pub fn unwrap(self) -> D {
         match self {
             Self::Domain(e) => *e,
-            Self::Fault(f) => unwrap_failed("called `Error::unwrap()` on an `Fault` value", &f),
+            Self::Fault(f) => {
+                unwrap_failed("called `Error::unwrap()` on a `Fault` value", f.context())
+            }
         }
     }
 
-    /// Unwrap the [Error::Fault] variant, panic otherwise
+    /// Unwrap the [Error::Fault] variant, panic otherwise with the first line of the display of the domain error
+    #[track_caller]
     pub fn unwrap_fault(self) -> Fault {
         match self {
             Self::Fault(b) => b,
-            Self::Domain(e) => {
-                unwrap_failed("called `Error::unwrap_err()` on an `Domain` value", &e)
-            }
+            Self::Domain(e) => unwrap_failed(
+                "called `Error::unwrap_fault()` on a `Domain` value",
+                Some(&e.to_string()),
+            ),
+        }
+    }
+
+    /// Return the [Error::Domain] variant's wrapped type, [None] otherwise
+    pub fn domain(self) -> Option<D> {
+        match self {
+            Self::Domain(e) => Some(*e),
+            Self::Fault(_) => None,
+        }
+    }
+
+    /// Return the [Error::Fault] variant's wrapped [Fault], otherwise convert the domain error using `op`.
+    /// Unlike [Error::unwrap_fault] it never panics.
+    pub fn fault_or_else<F>(self, op: F) -> Fault
+    where
+        F: FnOnce(Box<D>) -> Fault,
+    {
+        match self {
+            Self::Fault(fault) => fault,
+            Self::Domain(e) => op(e),
         }
     }
 
@@ -79,12 +224,9 @@ where
     /// ```rust
     /// use explicit_error_exit::{ExitError, derive::ExitError, Error};
     /// # use std::process::ExitCode;
-    /// #[test]
-    /// fn test() {
-    ///     assert_eq!(to_test().unwrap_err().downcast_source_ref()::<MyError>().unwrap(), &MyError::Foo);
-    /// }
+    /// assert_eq!(to_test().unwrap_err().downcast_source_ref::<MyError>().unwrap(), &MyError::Foo);
     ///
-    /// #[derive(ExitError, Debug)]
+    /// #[derive(ExitError, Debug, PartialEq)]
     /// enum MyError {
     ///     Foo,
     /// }
@@ -121,6 +263,49 @@ where
         }
         .downcast_ref::<E>()
     }
+
+    /// Return true if the type wrapped in either [Error::Domain] or [Error::Fault] variant, or any error of its chain of
+    /// [sources](StdError::source), is of type `E`. The chain is walked up to 32 errors and stops if it loops, like [errors_chain_debug].
+    /// # Examples
+    /// ```rust
+    /// # use explicit_error_exit::{prelude::*, Error, Fault};
+    /// let error: Error = Fault::new()
+    ///     .with_source(sqlx::Error::Io(std::io::Error::other("connection reset")))
+    ///     .into();
+    ///
+    /// assert!(error.is_caused_by::<sqlx::Error>());
+    /// assert!(error.is_caused_by::<std::io::Error>());
+    /// assert!(!error.is_caused_by::<std::fmt::Error>());
+    /// ```
+    pub fn is_caused_by<E>(&self) -> bool
+    where
+        E: StdError + 'static,
+    {
+        self.chain().any(|error| error.is::<E>())
+    }
+
+    /// Iterate over the type wrapped in either [Error::Domain] or [Error::Fault] variant followed by its chain of
+    /// [sources](StdError::source). The chain is walked up to 32 errors and stops if it loops, like [errors_chain_debug].
+    /// # Examples
+    /// ```rust
+    /// # use explicit_error_exit::{prelude::*, Error, Fault};
+    /// let error: Error = Fault::new()
+    ///     .with_context("context")
+    ///     .with_source(sqlx::Error::Io(std::io::Error::other("connection reset")))
+    ///     .into();
+    ///
+    /// assert_eq!(error.chain().count(), 3);
+    /// assert_eq!(
+    ///     error.chain().skip(1).map(ToString::to_string).collect::<Vec<_>>(),
+    ///     vec!["error communicating with database: connection reset", "connection reset"]
+    /// );
+    /// ```
+    pub fn chain(&self) -> impl Iterator<Item = &(dyn StdError + 'static)> {
+        errors_chain(match self {
+            Error::Domain(domain) => domain.as_ref() as &dyn StdError,
+            Error::Fault(fault) => fault as &dyn StdError,
+        })
+    }
 }
 
 impl<D> Error<D>
@@ -130,16 +315,16 @@ where
     /// Try to downcast the source of the type wrapped in either [Error::Domain] or [Error::Fault] variant.
     /// If it is not set try to downcast the type wrapped.
     /// Usefull to assert_eq! in tests
+    ///
+    /// Types deriving `HttpError` or `ExitError` also implement `TryFrom<Error>`, which returns the [Error] back if it does not match.
     /// # Examples
     /// ```rust
     /// use explicit_error_exit::{ExitError, derive::ExitError, Error};
     /// # use std::process::ExitCode;
-    /// #[test]
-    /// fn test() {
-    ///     assert_eq!(to_test().unwrap_err().downcast_source::<MyError>().unwrap(), MyError::Foo);
-    /// }
+    /// assert_eq!(to_test().unwrap_err().downcast_source::<MyError>().unwrap(), MyError::Foo);
+    /// assert_eq!(MyError::try_from(to_test().unwrap_err()).unwrap(), MyError::Foo);
     ///
-    /// #[derive(ExitError, Debug)]
+    /// #[derive(ExitError, Debug, PartialEq)]
     /// enum MyError {
     ///     Foo,
     /// }
@@ -178,15 +363,96 @@ where
         .map(|o| *o)
     }
 
+    /// Move out the source of the type wrapped in either [Error::Domain] or [Error::Fault] variant, [None] if it is not set.
+    /// Usefull to rebuild the source as a different type, eg: when adapting to another error ecosystem.
+    /// # Examples
+    /// ```rust
+    /// # use explicit_error_exit::{Error, Fault};
+    /// let source = Error::Fault(Fault::new().with_source(std::io::Error::other("io"))).into_source();
+    /// assert_eq!(source.unwrap().to_string(), "io");
+    ///
+    /// assert!(Error::Fault(Fault::new()).into_source().is_none());
+    /// ```
+    pub fn into_source(self) -> Option<Box<dyn StdError + Send + Sync>> {
+        match self {
+            Error::Domain(domain) => domain.into_source(),
+            Error::Fault(fault) => fault.source,
+        }
+    }
+
+    /// Convert to a [Fault]. The [Error::Fault] variant is returned as is while
+    /// the [Error::Domain] variant becomes the source of a new [Fault].
+    /// Usefull when an error previously considered as a domain error must be treated as a bug.
+    /// # Examples
+    /// ```rust
+    /// # use explicit_error_exit::{ExitError, Error, DomainError};
+    /// # use std::process::ExitCode;
+    /// let fault = Error::from(ExitError::new("Something went wrong", ExitCode::FAILURE)).into_fault();
+    /// assert!(fault.source.unwrap().downcast::<DomainError>().is_ok());
+    /// ```
+    pub fn into_fault(self) -> Fault {
+        match self {
+            Error::Domain(d) => Fault::new().with_source(*d),
+            Error::Fault(fault) => fault,
+        }
+    }
+
+    /// Convert to an [eyre::Report], enabled with the `eyre` feature. [Error::Fault] variant is converted with [Fault::into_report].
+    /// [Error::Domain] variant is the root of the report, wrapped by its context if there is one.
+    /// # Examples
+    /// ```rust
+    /// # use explicit_error_exit::{ExitError, Error};
+    /// # use std::process::ExitCode;
+    /// let report = Error::from(ExitError::new("Invalid configuration", ExitCode::FAILURE))
+    ///     .with_context("Loading /etc/app.toml")
+    ///     .into_report();
+    ///
+    /// assert_eq!(report.to_string(), "Loading /etc/app.toml");
+    /// assert_eq!(report.root_cause().to_string(), "Invalid configuration");
+    /// ```
+    #[cfg(feature = "eyre")]
+    pub fn into_report(self) -> eyre::Report {
+        match self {
+            Error::Domain(d) => match d.context().map(str::to_string) {
+                Some(context) => eyre::Report::new(*d).wrap_err(context),
+                None => eyre::Report::new(*d),
+            },
+            Error::Fault(fault) => fault.into_report(),
+        }
+    }
+
+    /// Hide an [Error::Domain] variant as an [Error::Fault], the domain error is kept as source of the [Fault]
+    /// to be logged while the public output becomes the one of faults.
+    /// Usefull when a domain error reveals too much to the user.
+    /// # Examples
+    /// ```rust
+    /// # use explicit_error_exit::{ExitError, Error, DomainError};
+    /// # use std::process::ExitCode;
+    /// let error = Error::from(ExitError::new("Something went wrong", ExitCode::FAILURE)).downgrade_to_fault();
+    /// assert!(error.is_fault());
+    /// ```
+    pub fn downgrade_to_fault(self) -> Self {
+        Error::Fault(self.into_fault())
+    }
+
     /// Add context of either [Error::Domain] or [Error::Fault] variant.
     /// Override existing context
     pub fn with_context(self, context: impl Display) -> Self {
         match self {
-            Error::Domain(d) => Error::Domain(Box::new(d.with_context(context))),
+            Error::Domain(d) => Error::new_domain(d.with_context(context)),
             Error::Fault(fault) => Error::Fault(fault.with_context(context)),
         }
     }
 
+    /// Add context of either [Error::Domain] or [Error::Fault] variant if there is one.
+    /// Override existing context
+    pub fn with_context_opt(self, context: Option<impl Display>) -> Self {
+        match context {
+            Some(context) => self.with_context(context),
+            None => self,
+        }
+    }
+
     /// Return the context of either [Error::Domain] or [Error::Fault] variant.
     pub fn context(&self) -> Option<&str> {
         match self {
@@ -194,21 +460,373 @@ where
             Error::Fault(fault) => fault.context(),
         }
     }
+
+    /// Mutable access to the context of either [Error::Domain] or [Error::Fault] variant,
+    /// to edit it in place rather than rebuilding the error with [with_context](Error::with_context).
+    ///
+    /// It is [None] for domain errors that do not support it, see [Domain::context_mut]: editing their context requires
+    /// to rebuild them, as [append_context](Error::append_context) does.
+    /// # Examples
+    /// ```rust
+    /// # use explicit_error_exit::Fault;
+    /// let mut error = explicit_error_exit::Error::from(Fault::new().with_context("Timeout"));
+    ///
+    /// match error.context_mut() {
+    ///     Some(Some(context)) => context.push_str(", trace id: 42"),
+    ///     Some(context) => *context = Some("Trace id: 42".to_string()),
+    ///     None => error = error.with_context("Trace id: 42"), // The domain error does not support it
+    /// }
+    ///
+    /// assert_eq!(error.context(), Some("Timeout, trace id: 42"));
+    /// ```
+    pub fn context_mut(&mut self) -> Option<&mut Option<String>> {
+        match self {
+            Error::Domain(d) => d.context_mut(),
+            Error::Fault(fault) => Some(fault.context_mut()),
+        }
+    }
+
+    /// Append to the context of either [Error::Domain] or [Error::Fault] variant, separated by a comma, or set it if there is none.
+    /// Domain errors that do not support [context_mut](Domain::context_mut) are rebuilt with [with_context](Domain::with_context).
+    /// # Examples
+    /// ```rust
+    /// # use explicit_error_exit::Fault;
+    /// let mut error = explicit_error_exit::Error::from(Fault::new().with_context("Timeout"));
+    /// error.append_context("trace id: 42");
+    /// assert_eq!(error.context(), Some("Timeout, trace id: 42"));
+    /// ```
+    pub fn append_context(&mut self, context: impl Display) {
+        if let Some(current) = self.context_mut() {
+            match current {
+                Some(current) => current.push_str(&format!(", {context}")),
+                None => *current = Some(context.to_string()),
+            }
+            return;
+        }
+
+        let context = match self.context() {
+            Some(current) => format!("{current}, {context}"),
+            None => context.to_string(),
+        };
+        let error = std::mem::replace(self, Error::Fault(Fault::placeholder()));
+        *self = error.with_context(context);
+    }
+
+    /// Return the human-readable message of the [Error::Domain] variant, see [Domain::user_message].
+    /// Faults do not have one, their output is generic.
+    /// # Examples
+    /// ```rust
+    /// # use explicit_error_exit::{ExitError, Error, Fault};
+    /// # use std::process::ExitCode;
+    /// let error = Error::from(ExitError::new("Configuration file is missing", ExitCode::FAILURE));
+    /// assert_eq!(error.user_message(), Some("Configuration file is missing"));
+    /// assert_eq!(Error::Fault(Fault::new()).user_message(), None);
+    /// ```
+    pub fn user_message(&self) -> Option<&str> {
+        match self {
+            Error::Domain(d) => d.user_message(),
+            Error::Fault(_) => None,
+        }
+    }
+
+    /// Return the context of either [Error::Domain] or [Error::Fault] variant followed by the contexts of the
+    /// [Fault], `D` and [Error] found by walking the source chain.
+    /// # Examples
+    /// ```rust
+    /// # use explicit_error_exit::{Error, Fault};
+    /// let error = Error::Fault(
+    ///     Fault::new()
+    ///         .with_context("Loading configuration")
+    ///         .with_source(Fault::new().with_context("Reading foo.conf")),
+    /// );
+    /// assert_eq!(error.context_chain(), vec!["Loading configuration", "Reading foo.conf"]);
+    /// ```
+    pub fn context_chain(&self) -> Vec<&str> {
+        let mut chain: Vec<&str> = self.context().into_iter().collect();
+        let mut source = match self {
+            Error::Domain(d) => d.source(),
+            Error::Fault(fault) => fault.source(),
+        };
+
+        while let Some(current) = source {
+            source = if let Some(fault) = current.downcast_ref::<Fault>() {
+                chain.extend(fault.context());
+                fault.source()
+            } else if let Some(d) = current.downcast_ref::<D>() {
+                chain.extend(d.context());
+                d.source()
+            } else if let Some(error) = current.downcast_ref::<Error<D>>() {
+                chain.extend(error.context());
+                match error {
+                    Error::Domain(d) => d.source(),
+                    Error::Fault(fault) => fault.source(),
+                }
+            } else {
+                current.source()
+            };
+        }
+
+        chain
+    }
+
+    /// Attach app-specific data to either [Error::Domain] or [Error::Fault] variant, override if one of the same type was set.
+    /// Monitoring hooks can retrieve it with [extension](Error::extension).
+    /// # Examples
+    /// ```rust
+    /// # use explicit_error_exit::{ExitError, Error};
+    /// # use std::{process::ExitCode, sync::Arc};
+    /// struct Tenant {
+    ///     name: &'static str,
+    /// }
+    ///
+    /// let error = Error::from(ExitError::new("Something went wrong", ExitCode::FAILURE))
+    ///     .with_extension(Arc::new(Tenant { name: "acme" }));
+    /// assert_eq!(error.extension::<Tenant>().unwrap().name, "acme");
+    /// ```
+    pub fn with_extension<T: Any + Send + Sync>(self, extension: Arc<T>) -> Self {
+        match self {
+            Error::Domain(d) => Error::new_domain(d.with_extension(extension)),
+            Error::Fault(fault) => Error::Fault(fault.with_extension(extension)),
+        }
+    }
+
+    /// Return the extension of type `T` of either [Error::Domain] or [Error::Fault] variant.
+    pub fn extension<T: Any + Send + Sync>(&self) -> Option<&T> {
+        match self {
+            Error::Domain(d) => d.extension(),
+            Error::Fault(fault) => fault.extension(),
+        }
+    }
+}
+
+/// Classify errors that are worth retrying (eg: in a job runner) because they are transient.
+///
+/// To use this trait on [Error] import the prelude `use explicit_error::prelude::*`
+/// # Examples
+/// ```rust
+/// # use explicit_error_http::{prelude::*, Error, Fault};
+/// fn run_job(job: impl Fn() -> Result<(), Error>) {
+///     for _ in 0..3 {
+///         match job() {
+///             Err(e) if e.is_retryable() => continue, // backoff
+///             _ => return,
+///         }
+///     }
+/// }
+///
+/// run_job(|| Err(Fault::new().retryable())?);
+/// ```
+pub trait Retryable {
+    fn is_retryable(&self) -> bool;
+}
+
+impl<D> Retryable for Error<D>
+where
+    D: Retryable,
+{
+    fn is_retryable(&self) -> bool {
+        match self {
+            Error::Domain(d) => d.is_retryable(),
+            Error::Fault(fault) => fault.is_retryable(),
+        }
+    }
+}
+
+/// Type-erased store of app-specific data attached to an error, one value per type.
+/// Used by [Fault] and implementations of [Domain].
+#[derive(Default, Clone)]
+pub struct Extensions(Option<Arc<HashMap<TypeId, Arc<dyn Any + Send + Sync>>>>); // Arc for size: https://doc.rust-lang.org/clippy/lint_configuration.html#large-error-threshold
+
+impl Extensions {
+    pub(crate) const EMPTY: Self = Self(None);
+
+    /// Generate [Extensions], with the `SpanIds` of the current OpenTelemetry context if the `otel` feature is enabled.
+    pub fn new() -> Self {
+        #[allow(unused_mut)]
+        let mut extensions = Self::default();
+
+        #[cfg(feature = "otel")]
+        if let Some(span_ids) = SpanIds::current() {
+            extensions.insert(Arc::new(span_ids));
+        }
+
+        extensions
+    }
+
+    /// Insert an extension, override if one of the same type was set.
+    pub fn insert<T: Any + Send + Sync>(&mut self, extension: Arc<T>) {
+        Arc::make_mut(self.0.get_or_insert_with(Default::default))
+            .insert(TypeId::of::<T>(), extension);
+    }
+
+    /// Return the extension of type `T` if set.
+    pub fn get<T: Any + Send + Sync>(&self) -> Option<&T> {
+        self.0
+            .as_ref()?
+            .get(&TypeId::of::<T>())
+            .and_then(|extension| extension.downcast_ref())
+    }
+}
+
+/// OpenTelemetry identifiers of the span active when an error is created. Used to correlate logs and traces.
+#[cfg(feature = "otel")]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SpanIds {
+    pub trace_id: TraceId,
+    pub span_id: SpanId,
+}
+
+#[cfg(feature = "otel")]
+impl SpanIds {
+    /// Return the identifiers of the span of the current OpenTelemetry context, [None] if there is no valid span
+    pub fn current() -> Option<Self> {
+        use opentelemetry::trace::TraceContextExt;
+
+        let context = opentelemetry::Context::current();
+        let span = context.span();
+        let span_context = span.span_context();
+
+        span_context.is_valid().then(|| Self {
+            trace_id: span_context.trace_id(),
+            span_id: span_context.span_id(),
+        })
+    }
+}
+
+#[cfg(feature = "otel")]
+impl Serialize for SpanIds {
+    fn serialize<S>(&self, s: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut state = s.serialize_struct("SpanIds", 2)?;
+        state.serialize_field("trace_id", &self.trace_id.to_string())?;
+        state.serialize_field("span_id", &self.span_id.to_string())?;
+        state.end()
+    }
+}
+
+/// Only the `SpanIds` are serialized, other extensions are app-specific.
+impl Serialize for Extensions {
+    fn serialize<S>(&self, s: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        #[cfg(feature = "otel")]
+        if let Some(span_ids) = self.get::<SpanIds>() {
+            return span_ids.serialize(s);
+        }
+
+        s.serialize_map(Some(0))?.end()
+    }
+}
+
+impl std::fmt::Debug for Extensions {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Extensions").finish_non_exhaustive()
+    }
 }
 
+/// Maximum number of errors displayed by [errors_chain_debug]
+const ERRORS_CHAIN_MAX_DEPTH: usize = 32;
+
+/// Debug format the error and its chain of sources separated by `->`.
+///
+/// The chain is truncated with `->...` if it exceeds 32 errors or loops, eg: an error whose [source](StdError::source) returns itself.
 pub fn errors_chain_debug(source: &dyn StdError) -> String {
     use std::fmt::Write;
-    let mut source = source;
+    let mut chain = vec![source];
     let mut str = format!("{:?}", source);
 
-    while source.source().is_some() {
-        source = source.source().unwrap();
+    while let Some(source) = chain.last().copied().and_then(StdError::source) {
+        if chain.len() == ERRORS_CHAIN_MAX_DEPTH || chain.iter().any(|s| std::ptr::eq(*s, source)) {
+            str.push_str("->...");
+            break;
+        }
+
         let _ = write!(&mut str, "->{:?}", source);
+        chain.push(source);
     }
 
     str
 }
 
+/// Internally used to implement [PartialEq] on errors with a source: sources are equal if both are unset or if they
+/// [debug format](errors_chain_debug) the same, ie: same types and same messages along the chain.
+#[doc(hidden)]
+pub fn sources_eq(a: Option<&dyn StdError>, b: Option<&dyn StdError>) -> bool {
+    match (a, b) {
+        (None, None) => true,
+        (Some(a), Some(b)) => errors_chain_debug(a) == errors_chain_debug(b),
+        _ => false,
+    }
+}
+
+/// Iterate over the error followed by its chain of sources.
+///
+/// The chain is truncated if it exceeds 32 errors or loops, eg: an error whose [source](StdError::source) returns itself.
+pub fn errors_chain<'a>(
+    error: &'a (dyn StdError + 'static),
+) -> impl Iterator<Item = &'a (dyn StdError + 'static)> {
+    ErrorsChain {
+        chain: Vec::new(),
+        next: Some(error),
+    }
+}
+
+struct ErrorsChain<'a> {
+    chain: Vec<&'a (dyn StdError + 'static)>,
+    next: Option<&'a (dyn StdError + 'static)>,
+}
+
+impl<'a> Iterator for ErrorsChain<'a> {
+    type Item = &'a (dyn StdError + 'static);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let error = self.next.take()?;
+        self.chain.push(error);
+        self.next = error.source().filter(|source| {
+            self.chain.len() < ERRORS_CHAIN_MAX_DEPTH
+                && !self.chain.iter().any(|e| std::ptr::eq(*e, *source))
+        });
+
+        Some(error)
+    }
+}
+
+type Redactor = fn(&str) -> String;
+
+static REDACTOR: RwLock<Option<Redactor>> = RwLock::new(None);
+
+/// Set a function applied to the description of sources, their chain of debug and display, when a [Fault] or a domain error is
+/// serialized or displayed. Usefull to scrub secrets embedded by libraries in their errors, eg: connection strings or tokens.
+///
+/// Default to identity.
+/// # Examples
+/// ```rust
+/// # use explicit_error::Fault;
+/// explicit_error::set_redactor(|source| source.replace("hunter2", "***"));
+///
+/// let fault = Fault::new().with_source(std::io::Error::other("password=hunter2"));
+/// assert!(!fault.to_string().contains("hunter2"));
+/// ```
+pub fn set_redactor(redactor: Redactor) {
+    *REDACTOR
+        .write()
+        .unwrap_or_else(|poisoned| poisoned.into_inner()) = Some(redactor);
+}
+
+/// Apply the function set with [set_redactor] to the description of a source.
+pub fn redact(source: &str) -> String {
+    match *REDACTOR
+        .read()
+        .unwrap_or_else(|poisoned| poisoned.into_inner())
+    {
+        Some(redactor) => redactor(source),
+        None => source.to_string(),
+    }
+}
+
 /// To use this trait on [Result] import the prelude `use explicit_error::prelude::*`
 pub trait ResultFault<T, S> {
     /// Convert with a closure any error wrapped in a [Result] to an [Error]. Returning an [Ok] convert the wrapped type to
@@ -255,6 +873,8 @@ pub trait ResultFault<T, S> {
     /// #   }
     /// # }
     /// ```
+    #[must_use = "the error must be handled, eg: propagated with `?`"]
+    #[track_caller]
     fn map_err_or_fault<F, E, D>(self, op: F) -> Result<T, Error<D>>
     where
         F: FnOnce(S) -> Result<E, S>,
@@ -262,6 +882,36 @@ pub trait ResultFault<T, S> {
         S: StdError + 'static + Send + Sync,
         D: Into<Error<D>>;
 
+    /// Same as [map_err_or_fault](ResultFault::map_err_or_fault) but the closure returns a boxed error trait object
+    /// in the [Err] variant. Usefull with libraries returning `Box<dyn Error + Send + Sync>`.
+    /// Returning an [Err] generates a [Fault] with the boxed error has its source.
+    /// # Examples
+    /// ```rust
+    /// # use std::process::ExitCode;
+    /// # use explicit_error_exit::{Error, ExitError, prelude::*};
+    /// fn load_plugin(name: &str) -> Result<(), Error> {
+    ///     plugin(name).map_err_or_fault_dyn(|e|
+    ///         match e.downcast::<std::io::Error>() {
+    ///             Ok(e) if e.kind() == std::io::ErrorKind::NotFound => Ok(
+    ///                 ExitError::new(format!("Plugin {name} not found"), ExitCode::FAILURE)),
+    ///             Ok(e) => Err(e), // Convert to Error::Fault
+    ///             Err(e) => Err(e),
+    ///         }
+    ///     )?;
+    ///     Ok(())
+    /// }
+    /// # fn plugin(name: &str) -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
+    /// #     Err(Box::new(std::io::Error::from(std::io::ErrorKind::NotFound)))
+    /// # }
+    /// ```
+    #[must_use = "the error must be handled, eg: propagated with `?`"]
+    #[track_caller]
+    fn map_err_or_fault_dyn<F, E, D>(self, op: F) -> Result<T, Error<D>>
+    where
+        F: FnOnce(S) -> Result<E, Box<dyn StdError + Send + Sync>>,
+        E: Into<Error<D>>,
+        D: Into<Error<D>>;
+
     /// Convert any [Result::Err] into a [Result::Err] wrapping a [Fault]
     /// Use [fault](ResultFault::or_fault) instead if the error implements [std::error::Error]
     ///  ```rust
@@ -275,6 +925,7 @@ pub trait ResultFault<T, S> {
     ///     # Ok(())
     /// }
     /// ```
+    #[must_use = "the error must be handled, eg: propagated with `?`"]
     fn or_fault_no_source(self) -> Result<T, Fault>;
 
     /// Convert any [Result::Err] wrapping an error that implements
@@ -289,10 +940,28 @@ pub trait ResultFault<T, S> {
     ///     # Ok(())
     /// }
     /// ```
+    #[must_use = "the error must be handled, eg: propagated with `?`"]
     fn or_fault(self) -> Result<T, Fault>
     where
         S: StdError + 'static + Send + Sync;
 
+    /// Same as [or_fault](ResultFault::or_fault). [OptionFault::fault] is its counterpart on [Option],
+    /// both are in the prelude to mark any [Result] or [Option] as a [Fault] with the same method.
+    ///  ```rust
+    /// # use explicit_error_exit::{Error, prelude::*};
+    /// fn foo() -> Result<(), Error> {
+    ///     let file = std::fs::read_to_string("foo.conf")
+    ///         .fault()
+    ///         .with_context("Configuration file foo.conf is missing.")?;
+    ///     let line = file.lines().next().fault().with_context("Configuration file is empty.")?;
+    ///     # Ok(())
+    /// }
+    /// ```
+    #[must_use = "the error must be handled, eg: propagated with `?`"]
+    fn fault(self) -> Result<T, Fault>
+    where
+        S: StdError + 'static + Send + Sync;
+
     /// Convert any [Result::Err] into a [Result::Err] wrapping a [Fault] forcing backtrace capture
     /// Use [or_fault_force](ResultFault::or_fault_force) instead if the error implements [std::error::Error]
     ///  ```rust
@@ -304,6 +973,7 @@ pub trait ResultFault<T, S> {
     ///     # Ok(())
     /// }
     /// ```
+    #[must_use = "the error must be handled, eg: propagated with `?`"]
     fn or_fault_no_source_force(self) -> Result<T, Fault>;
 
     /// Convert any [Result::Err] wrapping an error that implements
@@ -318,24 +988,156 @@ pub trait ResultFault<T, S> {
     ///     # Ok(())
     /// }
     /// ```
+    #[must_use = "the error must be handled, eg: propagated with `?`"]
     fn or_fault_force(self) -> Result<T, Fault>
     where
         S: StdError + 'static + Send + Sync;
-}
 
-impl<T, S> ResultFault<T, S> for Result<T, S> {
-    fn map_err_or_fault<F, E, D>(self, op: F) -> Result<T, Error<D>>
+    /// Convert any [Result::Err] wrapping an error that implements
+    /// [std::error::Error] into a [Result::Err] wrapping a [Fault] with a context computed from the error
+    ///  ```rust
+    /// # use explicit_error_exit::{Error, prelude::*};
+    /// fn foo(id: u64) -> Result<(), Error> {
+    ///     Err(sqlx::Error::RowNotFound)
+    ///         .or_fault_with(|e| format!("While loading user {id}: {e}"))?;
+    ///     # Ok(())
+    /// }
+    /// ```
+    #[must_use = "the error must be handled, eg: propagated with `?`"]
+    fn or_fault_with<F, C>(self, op: F) -> Result<T, Fault>
     where
-        F: FnOnce(S) -> Result<E, S>,
+        S: StdError + 'static + Send + Sync,
+        F: FnOnce(&S) -> C,
+        C: Display;
+
+    /// Convert any [Result::Err] wrapping an error that implements
+    /// [std::error::Error] into a [Result::Err] wrapping a [Fault] with a context computed from the error
+    /// forcing backtrace capture
+    ///  ```rust
+    /// # use explicit_error_exit::{Error, prelude::*};
+    /// fn foo(id: u64) -> Result<(), Error> {
+    ///     Err(sqlx::Error::RowNotFound)
+    ///         .or_fault_with_force(|e| format!("While loading user {id}: {e}"))?;
+    ///     # Ok(())
+    /// }
+    /// ```
+    #[must_use = "the error must be handled, eg: propagated with `?`"]
+    fn or_fault_with_force<F, C>(self, op: F) -> Result<T, Fault>
+    where
+        S: StdError + 'static + Send + Sync,
+        F: FnOnce(&S) -> C,
+        C: Display;
+
+    /// Call a closure with a reference to the error wrapped in a [Result::Err], leaving the [Result] untouched.
+    /// Usefull to monitor (eg: metrics, logs) errors at the exact point they are converted to a [Fault].
+    ///  ```rust
+    /// # use explicit_error_exit::{Error, prelude::*};
+    /// fn foo() -> Result<(), Error> {
+    ///     Err(sqlx::Error::RowNotFound)
+    ///         .inspect_err_as_fault(|e| eprintln!("database error: {e}"))
+    ///         .or_fault()?;
+    ///     # Ok(())
+    /// }
+    /// ```
+    #[must_use = "the error must be handled, eg: propagated with `?`"]
+    fn inspect_err_as_fault<F>(self, op: F) -> Self
+    where
+        F: FnOnce(&S);
+
+    /// Convert any [Result::Err] wrapping an error that implements [std::error::Error] into a [Fault]
+    /// unless the predicate considers it expected. Expected errors are propagated untouched in the inner [Result]
+    /// for the caller to handle them, eg: as a domain error, instead of generating a 500.
+    ///  ```rust
+    /// # use std::process::ExitCode;
+    /// # use explicit_error_exit::{Error, ExitError, prelude::*};
+    /// fn read_conf() -> Result<String, Error> {
+    ///     let conf = std::fs::read_to_string("foo.conf")
+    ///         .or_fault_unless(|e| e.kind() == std::io::ErrorKind::NotFound)?
+    ///         .map_err(|_| ExitError::new("Configuration file foo.conf is missing.", ExitCode::FAILURE))?;
+    ///     Ok(conf)
+    /// }
+    /// ```
+    #[must_use = "the error must be handled, eg: propagated with `?`"]
+    fn or_fault_unless<P>(self, is_expected: P) -> Result<Result<T, S>, Fault>
+    where
+        S: StdError + 'static + Send + Sync,
+        P: FnOnce(&S) -> bool;
+
+    /// Convert any [Result::Err] wrapping an error that implements [std::error::Error] into a [Fault]
+    /// only if the predicate considers it a fault, the opposite of [or_fault_unless](ResultFault::or_fault_unless).
+    /// Other errors are propagated untouched in the inner [Result] to be mapped, eg: to a domain error.
+    ///  ```rust
+    /// # use std::process::ExitCode;
+    /// # use explicit_error_exit::{Error, ExitError, prelude::*};
+    /// fn fetch_user(id: i64) -> Result<String, Error> {
+    ///     let user = Err::<String, _>(sqlx::Error::RowNotFound)
+    ///         .or_fault_if(|e| !matches!(e, sqlx::Error::RowNotFound))?
+    ///         .map_err(|_| ExitError::new(format!("User {id} does not exist."), ExitCode::FAILURE))?;
+    ///     Ok(user)
+    /// }
+    /// ```
+    #[must_use = "the error must be handled, eg: propagated with `?`"]
+    fn or_fault_if<P>(self, is_fault: P) -> Result<Result<T, S>, Fault>
+    where
+        S: StdError + 'static + Send + Sync,
+        P: FnOnce(&S) -> bool;
+
+    /// Convert any [Result::Err] wrapping an error that implements [std::error::Error] into a [Fault]
+    /// and log it with [Fault::log_throttled]: at most once per interval for faults of the same [fingerprint](Fault::fingerprint).
+    ///  ```rust
+    /// # use std::time::Duration;
+    /// # use explicit_error_exit::{Error, prelude::*};
+    /// fn foo() -> Result<(), Error> {
+    ///     Err(sqlx::Error::PoolTimedOut).or_fault_log_throttled(Duration::from_secs(60))?;
+    ///     # Ok(())
+    /// }
+    /// ```
+    #[must_use = "the error must be handled, eg: propagated with `?`"]
+    #[cfg(feature = "tracing")]
+    fn or_fault_log_throttled(self, interval: std::time::Duration) -> Result<T, Fault>
+    where
+        S: StdError + 'static + Send + Sync;
+}
+
+impl<T, S> ResultFault<T, S> for Result<T, S> {
+    #[track_caller]
+    fn map_err_or_fault<F, E, D>(self, op: F) -> Result<T, Error<D>>
+    where
+        F: FnOnce(S) -> Result<E, S>,
         E: Into<Error<D>>,
         S: StdError + 'static + Send + Sync,
         D: Into<Error<D>>,
     {
+        let location = Location::caller();
+        match self {
+            Ok(ok) => Ok(ok),
+            Err(error) => Err(match op(error) {
+                Ok(d) => d.into(),
+                Err(e) => Fault::new()
+                    .with_source(e)
+                    .with_caller_location(location)
+                    .into(),
+            }),
+        }
+    }
+
+    #[track_caller]
+    fn map_err_or_fault_dyn<F, E, D>(self, op: F) -> Result<T, Error<D>>
+    where
+        F: FnOnce(S) -> Result<E, Box<dyn StdError + Send + Sync>>,
+        E: Into<Error<D>>,
+        D: Into<Error<D>>,
+    {
+        let location = Location::caller();
         match self {
             Ok(ok) => Ok(ok),
             Err(error) => Err(match op(error) {
                 Ok(d) => d.into(),
-                Err(e) => Fault::new().with_source(e).into(),
+                Err(e) => {
+                    let mut fault = Fault::new().with_caller_location(location);
+                    fault.source = Some(e);
+                    fault.into()
+                }
             }),
         }
     }
@@ -364,6 +1166,13 @@ impl<T, S> ResultFault<T, S> for Result<T, S> {
         }
     }
 
+    fn fault(self) -> Result<T, Fault>
+    where
+        S: StdError + 'static + Send + Sync,
+    {
+        self.or_fault()
+    }
+
     fn or_fault_force(self) -> Result<T, Fault>
     where
         S: StdError + 'static + Send + Sync,
@@ -373,8 +1182,80 @@ impl<T, S> ResultFault<T, S> for Result<T, S> {
             Err(error) => Err(Fault::new_force().with_source(error)),
         }
     }
+
+    fn or_fault_with<F, C>(self, op: F) -> Result<T, Fault>
+    where
+        S: StdError + 'static + Send + Sync,
+        F: FnOnce(&S) -> C,
+        C: Display,
+    {
+        match self {
+            Ok(ok) => Ok(ok),
+            Err(error) => Err(Fault::new().with_context(op(&error)).with_source(error)),
+        }
+    }
+
+    fn or_fault_with_force<F, C>(self, op: F) -> Result<T, Fault>
+    where
+        S: StdError + 'static + Send + Sync,
+        F: FnOnce(&S) -> C,
+        C: Display,
+    {
+        match self {
+            Ok(ok) => Ok(ok),
+            Err(error) => Err(Fault::new_force()
+                .with_context(op(&error))
+                .with_source(error)),
+        }
+    }
+
+    fn inspect_err_as_fault<F>(self, op: F) -> Self
+    where
+        F: FnOnce(&S),
+    {
+        if let Err(error) = &self {
+            op(error);
+        }
+
+        self
+    }
+
+    fn or_fault_unless<P>(self, is_expected: P) -> Result<Result<T, S>, Fault>
+    where
+        S: StdError + 'static + Send + Sync,
+        P: FnOnce(&S) -> bool,
+    {
+        match self {
+            Err(error) if !is_expected(&error) => Err(Fault::new().with_source(error)),
+            result => Ok(result),
+        }
+    }
+
+    fn or_fault_if<P>(self, is_fault: P) -> Result<Result<T, S>, Fault>
+    where
+        S: StdError + 'static + Send + Sync,
+        P: FnOnce(&S) -> bool,
+    {
+        self.or_fault_unless(|error| !is_fault(error))
+    }
+
+    #[cfg(feature = "tracing")]
+    fn or_fault_log_throttled(self, interval: std::time::Duration) -> Result<T, Fault>
+    where
+        S: StdError + 'static + Send + Sync,
+    {
+        self.or_fault()
+            .inspect_err(|fault| fault.log_throttled(interval))
+    }
 }
 
+/// Former name of [ResultFault], kept during the transition.
+#[deprecated(since = "0.2.4", note = "renamed to `ResultFault`")]
+pub trait ResultBug<T, S>: ResultFault<T, S> {}
+
+#[allow(deprecated)]
+impl<T, S, R: ResultFault<T, S>> ResultBug<T, S> for R {}
+
 /// To use this trait on [Result] import the prelude `use explicit_error::prelude::*`
 pub trait ResultError<T, D>
 where
@@ -426,19 +1307,223 @@ where
     /// #     Ok(())
     /// # }
     /// ```
+    #[must_use = "the error must be handled, eg: propagated with `?`"]
     fn try_map_on_source<F, S, E>(self, op: F) -> Result<T, Error<D>>
     where
         F: FnOnce(S) -> E,
         S: StdError + 'static,
         E: Into<Error<D>>;
 
+    /// Same as [try_map_on_source](ResultError::try_map_on_source) to attempt several downcasts of the source at once.
+    /// The closure receives the source, if there is one, and the error is left untouched when it returns [None].
+    /// # Examples
+    /// ```rust
+    /// # use explicit_error_exit::{prelude::*, ExitError, Result};
+    /// # use std::process::ExitCode;
+    /// fn business_logic() -> Result<()> {
+    ///     Err(sqlx::Error::RowNotFound).or_fault()?;
+    ///     Ok(())
+    /// }
+    ///
+    /// let result = business_logic().try_map_on_sources(|source| {
+    ///     if let Some(sqlx::Error::RowNotFound) = source.downcast_ref::<sqlx::Error>() {
+    ///         return Some(ExitError::new("Not found", ExitCode::from(4)));
+    ///     }
+    ///
+    ///     source
+    ///         .downcast_ref::<std::io::Error>()
+    ///         .map(|e| ExitError::new(format!("IO: {e}"), ExitCode::from(5)))
+    /// });
+    /// assert_eq!(result.unwrap_err().unwrap().output.exit_code(), ExitCode::from(4));
+    /// ```
+    #[must_use = "the error must be handled, eg: propagated with `?`"]
+    fn try_map_on_sources<F, E>(self, op: F) -> Result<T, Error<D>>
+    where
+        F: FnOnce(&(dyn StdError + 'static)) -> Option<E>,
+        E: Into<Error<D>>;
+
+    /// Pattern match on the [Error::Domain] variant if either the domain type itself or its source
+    /// is the closure's parameter type. [Error::Fault] variant is left untouched.
+    ///
+    /// Errors generated with a derive store the original enum or struct as source of the domain type,
+    /// whereas a custom [Domain] implementation can be the typed enum itself. This function handles both.
+    /// To pattern match on the source of an inline domain error (eg: `HttpError::new(..).with_source(e)`)
+    /// or of a [Fault], use [try_map_on_source](ResultError::try_map_on_source) instead.
+    /// # Examples
+    /// ```rust
+    /// # use explicit_error_exit::{prelude::*, ExitError, Result, derive::ExitError};
+    /// # use std::process::ExitCode;
+    /// #[derive(ExitError, Debug)]
+    /// enum MyError {
+    ///     Foo,
+    ///     Bar,
+    /// }
+    /// # impl From<&MyError> for ExitError {
+    /// #     fn from(value: &MyError) -> Self {
+    /// #         ExitError::new("Something went wrong because ..", ExitCode::from(42))
+    /// #     }
+    /// # }
+    /// # fn business_logic() -> Result<()> {
+    /// let err: Result<()> = Err(MyError::Foo)?;
+    ///
+    /// err.try_map_on_domain(|e| match e {
+    ///     MyError::Foo => ExitError::new("Foo", ExitCode::SUCCESS),
+    ///     MyError::Bar => ExitError::new("Bar", ExitCode::FAILURE),
+    /// })?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[must_use = "the error must be handled, eg: propagated with `?`"]
+    fn try_map_on_domain<F, S, E>(self, op: F) -> Result<T, Error<D>>
+    where
+        F: FnOnce(S) -> E,
+        S: StdError + 'static,
+        E: Into<Error<D>>;
+
+    /// Convert the [Error::Fault] variant to an [Error::Domain] with the closure, the [Fault] is moved to let its source and context be kept.
+    /// The [Error::Domain] variant is left untouched. It is the inverse of [into_fault](Error::into_fault),
+    /// usefull when a fault turns out to be representable as a domain error, eg: at the handler boundary.
+    /// # Examples
+    /// ```rust
+    /// # use explicit_error_http::{prelude::*, HttpError, Result};
+    /// # use http::StatusCode;
+    /// # fn charge() -> Result<()> {
+    /// #     Err(sqlx::Error::PoolTimedOut).or_fault()?;
+    /// #     Ok(())
+    /// # }
+    /// fn checkout() -> Result<()> {
+    ///     charge().map_fault_to_domain(|fault| {
+    ///         HttpError::new(StatusCode::SERVICE_UNAVAILABLE, "Payments are temporarily unavailable")
+    ///             .with_source(fault)
+    ///     })
+    /// }
+    /// ```
+    #[must_use = "the error must be handled, eg: propagated with `?`"]
+    fn map_fault_to_domain<F>(self, op: F) -> Result<T, Error<D>>
+    where
+        F: FnOnce(Fault) -> D;
+
     /// Add a context to any variant of an [Error] wrapped in a [Result::Err]
+    ///
+    /// The context is formatted only if the [Result] is an [Err]: pass [format_args!] rather than [format!] to not allocate on the happy path.
     /// # Examples
     /// ```rust
     /// use explicit_error::{prelude::*, Fault};
     /// Err::<(), _>(Fault::new()).with_context("Foo bar");
+    /// # let user_id = 42;
+    /// Err::<(), _>(Fault::new()).with_context(format_args!("User: {user_id}"));
     /// ```
+    #[must_use = "the error must be handled, eg: propagated with `?`"]
     fn with_context(self, context: impl Display) -> Result<T, Error<D>>;
+
+    /// Add a context, if there is one, to any variant of an [Error] wrapped in a [Result::Err]
+    /// # Examples
+    /// ```rust
+    /// use explicit_error::{prelude::*, Fault};
+    /// # let tenant_id: Option<u32> = None;
+    /// Err::<(), _>(Fault::new()).with_context_opt(tenant_id.map(|id| format!("Tenant: {id}")));
+    /// ```
+    #[must_use = "the error must be handled, eg: propagated with `?`"]
+    fn with_context_opt(self, context: Option<impl Display>) -> Result<T, Error<D>>;
+
+    /// Call the closure with the value wrapped in a [Result::Ok] to chain faillible operations returning an [Error].
+    /// The error type of the closure is inferred to be the same [Error]. Use [and_then_domain_step](ResultError::and_then_domain_step)
+    /// to add the name of the step to the context of the error.
+    /// # Examples
+    /// ```rust
+    /// # use explicit_error_exit::{prelude::*, ExitError, Fault, Result};
+    /// # use std::process::ExitCode;
+    /// fn parse(input: &str) -> Result<u8> {
+    ///     input.parse::<u8>().or_fault().map_err(Into::into)
+    /// }
+    ///
+    /// fn business_logic(input: &str) -> Result<u8> {
+    ///     parse(input).and_then_domain(|value| {
+    ///         if value == 0 {
+    ///             Err(ExitError::new("Value cannot be zero", ExitCode::from(42)))?;
+    ///         }
+    ///         Ok(value)
+    ///     })
+    /// }
+    /// ```
+    #[must_use = "the error must be handled, eg: propagated with `?`"]
+    fn and_then_domain<U, F>(self, op: F) -> Result<U, Error<D>>
+    where
+        F: FnOnce(T) -> Result<U, Error<D>>;
+
+    /// Same as [and_then_domain](ResultError::and_then_domain) but the name of the step is added to the context of the error
+    /// returned by the closure, before its context if it has one: `{step}: {context}`.
+    /// Errors already wrapped in [Result::Err] come from a previous step and are returned unchanged.
+    /// # Examples
+    /// ```rust
+    /// # use explicit_error_exit::{prelude::*, ExitError, Fault, Result};
+    /// # use std::process::ExitCode;
+    /// fn parse(input: &str) -> Result<u8> {
+    ///     input.parse::<u8>().or_fault().map_err(Into::into)
+    /// }
+    ///
+    /// fn business_logic(input: &str) -> Result<u8> {
+    ///     parse(input).and_then_domain_step("check value", |value| {
+    ///         if value == 0 {
+    ///             Err(ExitError::new("Value cannot be zero", ExitCode::from(42)))?;
+    ///         }
+    ///         Ok(value)
+    ///     })
+    /// }
+    ///
+    /// assert_eq!(business_logic("0").unwrap_err().context(), Some("check value"));
+    /// ```
+    #[must_use = "the error must be handled, eg: propagated with `?`"]
+    fn and_then_domain_step<U, F>(self, step: impl Display, op: F) -> Result<U, Error<D>>
+    where
+        F: FnOnce(T) -> Result<U, Error<D>>;
+
+    /// Open and close a short-lived [tracing] span recording the [Error] wrapped in a [Result::Err], so that it is attributed to a named operation in traces.
+    ///
+    /// [tracing] span names are static, `name` is recorded as the `otel.name` field that OpenTelemetry layers use as the span name.
+    /// # Examples
+    /// ```rust
+    /// # use explicit_error_exit::{prelude::*, Result};
+    /// fn load_config() -> Result<()> {
+    ///     Err(sqlx::Error::PoolTimedOut).or_fault().map_err(Into::into).with_span("load_config")?;
+    ///     # Ok(())
+    /// }
+    /// ```
+    #[must_use = "the error must be handled, eg: propagated with `?`"]
+    #[cfg(feature = "tracing")]
+    fn with_span(self, name: &'static str) -> Self;
+
+    /// Log the display of the [Error] wrapped in a [Result::Err] with the [log] facade, enabled with the `log` feature.
+    /// The [Result] is returned unchanged. Usefull to quickly instrument code without a [tracing](https://docs.rs/tracing) set-up.
+    /// # Examples
+    /// ```rust
+    /// # use explicit_error_exit::{prelude::*, Result};
+    /// fn load_config() -> Result<()> {
+    ///     Err(sqlx::Error::PoolTimedOut).or_fault().map_err(Into::into).log_err(log::Level::Warn)?;
+    ///     # Ok(())
+    /// }
+    /// ```
+    #[must_use = "the error must be handled, eg: propagated with `?`"]
+    #[cfg(feature = "log")]
+    fn log_err(self, level: log::Level) -> Self;
+
+    /// Log the display of the [Error] wrapped in a [Result::Err] at the warn level and discard it, returning [None].
+    /// [Result::Ok] is converted to [Some]. Usefull in best-effort code paths, eg: an optional cache warmup,
+    /// to make explicit that an error is swallowed but recorded.
+    ///
+    /// Enabled with the `log` feature, or the `tracing` feature which is used when `log` is disabled.
+    /// # Examples
+    /// ```rust
+    /// # use explicit_error_exit::{prelude::*, Result};
+    /// fn warmup_cache() -> Result<usize> {
+    ///     Err(sqlx::Error::PoolTimedOut).or_fault().map_err(Into::into)
+    /// }
+    ///
+    /// let warmed = warmup_cache().ok_or_log().unwrap_or_default();
+    /// # assert_eq!(warmed, 0);
+    /// ```
+    #[cfg(any(feature = "log", feature = "tracing"))]
+    fn ok_or_log(self) -> Option<T>;
 }
 
 impl<T, D> ResultError<T, D> for Result<T, Error<D>>
@@ -463,10 +1548,10 @@ where
                     Err(Error::Domain(d))
                 }
                 Error::Fault(b) => {
-                    if let Some(s) = &b.source {
-                        if s.is::<S>() {
-                            return Err(op(*b.source.unwrap().downcast::<S>().unwrap()).into());
-                        }
+                    if let Some(s) = &b.source
+                        && s.is::<S>()
+                    {
+                        return Err(op(*b.source.unwrap().downcast::<S>().unwrap()).into());
                     }
 
                     Err(Error::Fault(b))
@@ -475,6 +1560,57 @@ where
         }
     }
 
+    fn try_map_on_sources<F, E>(self, op: F) -> Result<T, Error<D>>
+    where
+        F: FnOnce(&(dyn StdError + 'static)) -> Option<E>,
+        E: Into<Error<D>>,
+    {
+        self.map_err(|error| {
+            let source = match &error {
+                Error::Domain(d) => d.source(),
+                Error::Fault(b) => b.source.as_deref().map(|s| s as _),
+            };
+
+            match source.and_then(op) {
+                Some(mapped) => mapped.into(),
+                None => error,
+            }
+        })
+    }
+
+    fn try_map_on_domain<F, S, E>(self, op: F) -> Result<T, Error<D>>
+    where
+        F: FnOnce(S) -> E,
+        S: StdError + 'static,
+        E: Into<Error<D>>,
+    {
+        match self {
+            Ok(ok) => Ok(ok),
+            Err(Error::Domain(d)) => {
+                if (d.as_ref() as &dyn Any).is::<S>() {
+                    return Err(op(*(d as Box<dyn Any>).downcast::<S>().unwrap()).into());
+                }
+
+                if d.source().is_some_and(|s| s.is::<S>()) {
+                    return Err(op(*d.into_source().unwrap().downcast::<S>().unwrap()).into());
+                }
+
+                Err(Error::Domain(d))
+            }
+            Err(fault) => Err(fault),
+        }
+    }
+
+    fn map_fault_to_domain<F>(self, op: F) -> Result<T, Error<D>>
+    where
+        F: FnOnce(Fault) -> D,
+    {
+        match self {
+            Err(Error::Fault(fault)) => Err(Error::new_domain(op(fault))),
+            result => result,
+        }
+    }
+
     fn with_context(self, context: impl Display) -> Result<T, Error<D>> {
         match self {
             Ok(ok) => Ok(ok),
@@ -484,6 +1620,74 @@ where
             }),
         }
     }
+
+    fn with_context_opt(self, context: Option<impl Display>) -> Result<T, Error<D>> {
+        self.map_err(|error| error.with_context_opt(context))
+    }
+
+    fn and_then_domain<U, F>(self, op: F) -> Result<U, Error<D>>
+    where
+        F: FnOnce(T) -> Result<U, Error<D>>,
+    {
+        match self {
+            Ok(ok) => op(ok),
+            Err(error) => Err(error),
+        }
+    }
+
+    fn and_then_domain_step<U, F>(self, step: impl Display, op: F) -> Result<U, Error<D>>
+    where
+        F: FnOnce(T) -> Result<U, Error<D>>,
+    {
+        match self {
+            Ok(ok) => op(ok).map_err(|error| {
+                let context = match error.context() {
+                    Some(context) => format!("{step}: {context}"),
+                    None => step.to_string(),
+                };
+                error.with_context(context)
+            }),
+            Err(error) => Err(error),
+        }
+    }
+
+    #[cfg(feature = "tracing")]
+    fn with_span(self, name: &'static str) -> Self {
+        if let Err(error) = &self {
+            let span = tracing::error_span!(
+                "explicit_error",
+                otel.name = name,
+                otel.status_code = "ERROR",
+                error = tracing::field::Empty,
+            );
+            span.record("error", tracing::field::display(error));
+        }
+
+        self
+    }
+
+    #[cfg(feature = "log")]
+    fn log_err(self, level: log::Level) -> Self {
+        if let Err(error) = &self {
+            log::log!(level, "{error}");
+        }
+
+        self
+    }
+
+    #[cfg(any(feature = "log", feature = "tracing"))]
+    fn ok_or_log(self) -> Option<T> {
+        match self {
+            Ok(value) => Some(value),
+            Err(error) => {
+                #[cfg(feature = "log")]
+                log::warn!("{error}");
+                #[cfg(not(feature = "log"))]
+                tracing::warn!("{error}");
+                None
+            }
+        }
+    }
 }
 
 /// To use this trait on [Option] import the prelude `use explicit_error::prelude::*`
@@ -498,8 +1702,21 @@ pub trait OptionFault<T> {
     ///     # Ok(())
     /// }
     /// ```
+    #[must_use = "the error must be handled, eg: propagated with `?`"]
     fn ok_or_fault(self) -> Result<T, Fault>;
 
+    /// Same as [ok_or_fault](OptionFault::ok_or_fault). [ResultFault::fault] is its counterpart on [Result].
+    /// ```rust
+    /// # use explicit_error_exit::{Error, prelude::*};
+    /// fn foo() -> Result<(), Error> {
+    ///     let option: Option<u8> = None;
+    ///     option.fault().with_context("Help debugging")?;
+    ///     # Ok(())
+    /// }
+    /// ```
+    #[must_use = "the error must be handled, eg: propagated with `?`"]
+    fn fault(self) -> Result<T, Fault>;
+
     /// Transforms the `Option<T>` into a `Result<T, Fault>`, mapping Some(v) to Ok(v) and None to Err(Fault)
     /// forcing backtrace capture
     /// ```rust
@@ -511,7 +1728,40 @@ pub trait OptionFault<T> {
     ///     # Ok(())
     /// }
     /// ```
+    #[must_use = "the error must be handled, eg: propagated with `?`"]
     fn ok_or_fault_force(self) -> Result<T, Fault>;
+
+    /// Transforms the `Option<T>` into a `Result<T, Fault>`, mapping Some(v) to Ok(v) and None to Err(Fault)
+    /// with a context computed by the closure. The closure is only called on [None].
+    /// ```rust
+    /// # use std::collections::HashMap;
+    /// # use explicit_error_exit::{Error, prelude::*};
+    /// fn foo(config: &HashMap<&str, &str>, key: &str) -> Result<(), Error> {
+    ///     config.get(key).ok_or_fault_with(|| format!("missing config key {key}"))?;
+    ///     # Ok(())
+    /// }
+    /// ```
+    #[must_use = "the error must be handled, eg: propagated with `?`"]
+    fn ok_or_fault_with<F, C>(self, op: F) -> Result<T, Fault>
+    where
+        F: FnOnce() -> C,
+        C: Display;
+
+    /// Transforms the `Option<T>` into a `Result<T, Fault>`, mapping Some(v) to Ok(v) and None to Err(Fault)
+    /// with a context computed by the closure forcing backtrace capture. The closure is only called on [None].
+    /// ```rust
+    /// # use std::collections::HashMap;
+    /// # use explicit_error_exit::{Error, prelude::*};
+    /// fn foo(config: &HashMap<&str, &str>, key: &str) -> Result<(), Error> {
+    ///     config.get(key).ok_or_fault_with_force(|| format!("missing config key {key}"))?;
+    ///     # Ok(())
+    /// }
+    /// ```
+    #[must_use = "the error must be handled, eg: propagated with `?`"]
+    fn ok_or_fault_with_force<F, C>(self, op: F) -> Result<T, Fault>
+    where
+        F: FnOnce() -> C,
+        C: Display;
 }
 
 impl<T> OptionFault<T> for Option<T> {
@@ -522,23 +1772,71 @@ impl<T> OptionFault<T> for Option<T> {
         }
     }
 
+    fn fault(self) -> Result<T, Fault> {
+        self.ok_or_fault()
+    }
+
     fn ok_or_fault_force(self) -> Result<T, Fault> {
         match self {
             Some(ok) => Ok(ok),
             None => Err(Fault::new_force()),
         }
     }
+
+    fn ok_or_fault_with<F, C>(self, op: F) -> Result<T, Fault>
+    where
+        F: FnOnce() -> C,
+        C: Display,
+    {
+        match self {
+            Some(ok) => Ok(ok),
+            None => Err(Fault::new().with_context(op())),
+        }
+    }
+
+    fn ok_or_fault_with_force<F, C>(self, op: F) -> Result<T, Fault>
+    where
+        F: FnOnce() -> C,
+        C: Display,
+    {
+        match self {
+            Some(ok) => Ok(ok),
+            None => Err(Fault::new_force().with_context(op())),
+        }
+    }
 }
 
+/// Former name of [OptionFault], kept during the transition.
+#[deprecated(since = "0.2.4", note = "renamed to `OptionFault`")]
+pub trait OptionBug<T>: OptionFault<T> {}
+
+#[allow(deprecated)]
+impl<T, O: OptionFault<T>> OptionBug<T> for O {}
+
 /// To use this trait on [Result] import the prelude `use explicit_error::prelude::*`
 pub trait ResultFaultWithContext<T> {
     /// Add a context to the [Fault] wrapped in a [Result::Err]
+    ///
+    /// The context is formatted only if the [Result] is an [Err]: pass [format_args!] rather than [format!] to not allocate on the happy path.
     /// # Examples
     /// ```rust
     /// # use explicit_error::{prelude::*, Fault};
     /// Err::<(), _>(Fault::new()).with_context("Foo bar");
+    /// # let user_id = 42;
+    /// Err::<(), _>(Fault::new()).with_context(format_args!("User: {user_id}"));
     /// ```
+    #[must_use = "the error must be handled, eg: propagated with `?`"]
     fn with_context(self, context: impl Display) -> Result<T, Fault>;
+
+    /// Add a context, if there is one, to the [Fault] wrapped in a [Result::Err]
+    /// # Examples
+    /// ```rust
+    /// # use explicit_error::{prelude::*, Fault};
+    /// # let tenant_id: Option<u32> = None;
+    /// Err::<(), _>(Fault::new()).with_context_opt(tenant_id.map(|id| format!("Tenant: {id}")));
+    /// ```
+    #[must_use = "the error must be handled, eg: propagated with `?`"]
+    fn with_context_opt(self, context: Option<impl Display>) -> Result<T, Fault>;
 }
 
 impl<T> ResultFaultWithContext<T> for Result<T, Fault> {
@@ -548,7 +1846,18 @@ impl<T> ResultFaultWithContext<T> for Result<T, Fault> {
             Err(b) => Err(b.with_context(context)),
         }
     }
+
+    fn with_context_opt(self, context: Option<impl Display>) -> Result<T, Fault> {
+        self.map_err(|fault| fault.with_context_opt(context))
+    }
 }
 
+/// Former name of [ResultFaultWithContext], kept during the transition.
+#[deprecated(since = "0.2.4", note = "renamed to `ResultFaultWithContext`")]
+pub trait ResultBugWithContext<T>: ResultFaultWithContext<T> {}
+
+#[allow(deprecated)]
+impl<T, R: ResultFaultWithContext<T>> ResultBugWithContext<T> for R {}
+
 #[cfg(test)]
 mod test;
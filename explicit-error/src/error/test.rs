@@ -38,20 +38,20 @@ fn source() {
         &MyError::default()
     );
     assert!(
-        Error::Domain(Box::new(DomainError {
+        Error::new_domain(DomainError {
             output: ExitError::new("", ExitCode::SUCCESS),
-            source: None
-        }))
+            source: None,
+        })
         .source()
         .unwrap()
         .downcast_ref::<DomainError>()
         .is_some()
     );
     assert_eq!(
-        Error::Domain(Box::new(DomainError {
+        Error::new_domain(DomainError {
             output: ExitError::new("", ExitCode::SUCCESS),
-            source: Some(Box::new(MyError::default()))
-        }))
+            source: Some(Box::new(MyError::default())),
+        })
         .source()
         .unwrap()
         .downcast_ref::<MyError>()
@@ -78,6 +78,12 @@ fn unwrap_panic() {
     Error::Fault(Fault::new()).unwrap();
 }
 
+#[should_panic(expected = "called `Error::unwrap()` on a `Fault` value: first line")]
+#[test]
+fn unwrap_panic_message() {
+    Error::Fault(Fault::new().with_context("first line\nsecond line")).unwrap();
+}
+
 #[test]
 fn unwrap() {
     Error::from(ExitError::new("", ExitCode::SUCCESS)).unwrap();
@@ -89,11 +95,163 @@ fn unwrap_fault_panic() {
     Error::from(ExitError::new("", ExitCode::SUCCESS)).unwrap_fault();
 }
 
+#[should_panic(expected = "called `Error::unwrap_fault()` on a `Domain` value: message")]
+#[test]
+fn unwrap_fault_panic_message() {
+    Error::from(ExitError::new("message", ExitCode::SUCCESS)).unwrap_fault();
+}
+
 #[test]
 fn unwrap_fault() {
     Error::Fault(Fault::new()).unwrap_fault();
 }
 
+#[test]
+fn question_mark() {
+    fn fault() -> std::result::Result<(), Fault> {
+        Ok(())
+    }
+
+    fn domain(fail: bool) -> std::result::Result<(), DomainError> {
+        match fail {
+            true => Err(DomainError {
+                output: ExitError::new("domain", ExitCode::FAILURE),
+                source: None,
+            }),
+            false => Ok(()),
+        }
+    }
+
+    fn error() -> Result<()> {
+        Ok(())
+    }
+
+    fn all(fail: bool) -> Result<()> {
+        fault()?;
+        error()?;
+        domain(fail)?;
+        Ok(())
+    }
+
+    assert!(all(false).is_ok());
+    assert_eq!(all(true).unwrap_err().unwrap().output.message, "domain");
+}
+
+#[test]
+fn domain() {
+    assert!(Error::Fault(Fault::new()).domain().is_none());
+    assert_eq!(
+        Error::from(ExitError::new("message", ExitCode::SUCCESS))
+            .domain()
+            .unwrap()
+            .output
+            .message,
+        "message"
+    );
+}
+
+#[test]
+fn fault_or_else() {
+    assert_eq!(
+        Error::Fault(Fault::new().with_context("fault"))
+            .fault_or_else(|_| Fault::new().with_context("domain"))
+            .context()
+            .unwrap(),
+        "fault"
+    );
+    assert_eq!(
+        Error::from(ExitError::new("message", ExitCode::SUCCESS))
+            .fault_or_else(|d| Fault::new().with_context(d.output.message))
+            .context()
+            .unwrap(),
+        "message"
+    );
+}
+
+#[test]
+fn into_fault() {
+    assert_eq!(
+        Error::Fault(Fault::new().with_context("context"))
+            .into_fault()
+            .context()
+            .unwrap(),
+        "context"
+    );
+
+    let fault = Error::new_domain(DomainError {
+        output: ExitError::new("", ExitCode::SUCCESS),
+        source: Some(Box::new(MyError::default())),
+    })
+    .into_fault();
+    assert!(
+        fault
+            .source
+            .unwrap()
+            .downcast::<DomainError>()
+            .unwrap()
+            .source
+            .unwrap()
+            .downcast::<MyError>()
+            .is_ok()
+    );
+}
+
+#[test]
+fn downgrade_to_fault() {
+    let error = Error::new_domain(DomainError {
+        output: ExitError::new("", ExitCode::SUCCESS),
+        source: Some(Box::new(MyError::default())),
+    })
+    .downgrade_to_fault();
+    assert!(error.is_fault());
+    assert!(
+        error
+            .unwrap_fault()
+            .source
+            .unwrap()
+            .downcast::<DomainError>()
+            .is_ok()
+    );
+
+    assert_eq!(
+        Error::Fault(Fault::new().with_context("context"))
+            .downgrade_to_fault()
+            .context()
+            .unwrap(),
+        "context"
+    );
+}
+
+#[test]
+fn into_source() {
+    assert!(Error::Fault(Fault::new()).into_source().is_none());
+    assert!(
+        Error::Fault(Fault::new().with_source(MyError::default()))
+            .into_source()
+            .unwrap()
+            .downcast::<MyError>()
+            .is_ok()
+    );
+    assert!(
+        Error::new_domain(DomainError {
+            output: ExitError::new("", ExitCode::SUCCESS),
+            source: None,
+        })
+        .into_source()
+        .is_none()
+    );
+    assert!(
+        Error::new_domain(DomainError {
+            output: ExitError::new("", ExitCode::SUCCESS),
+            source: Some(Box::new(MyError::default())),
+        })
+        .into_source()
+        .unwrap()
+        .downcast::<MyError>()
+        .is_ok()
+    );
+}
+
 #[test]
 fn downcast_source() {
     assert!(
@@ -107,18 +265,18 @@ fn downcast_source() {
             .is_ok()
     );
     assert!(
-        Error::Domain(Box::new(DomainError {
+        Error::new_domain(DomainError {
             output: ExitError::new("", ExitCode::SUCCESS),
-            source: None
-        }))
+            source: None,
+        })
         .downcast_source::<DomainError>()
         .is_ok()
     );
     assert!(
-        Error::Domain(Box::new(DomainError {
+        Error::new_domain(DomainError {
             output: ExitError::new("", ExitCode::SUCCESS),
-            source: Some(Box::new(MyError::default()))
-        }))
+            source: Some(Box::new(MyError::default())),
+        })
         .downcast_source::<MyError>()
         .is_ok()
     );
@@ -141,10 +299,10 @@ fn with_context() {
     );
 
     assert_eq!(
-        Error::Domain(Box::new(DomainError {
+        Error::new_domain(DomainError {
             output: ExitError::new("", ExitCode::SUCCESS),
-            source: None
-        }))
+            source: None,
+        })
         .with_context("context")
         .context()
         .unwrap(),
@@ -152,6 +310,140 @@ fn with_context() {
     );
 }
 
+#[test]
+fn with_context_format_args() {
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    struct Counted<'a>(&'a AtomicUsize);
+
+    impl std::fmt::Display for Counted<'_> {
+        fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+            self.0.fetch_add(1, Ordering::Relaxed);
+            write!(f, "counted")
+        }
+    }
+
+    let calls = AtomicUsize::new(0);
+    let _ = Ok::<(), Error>(()).with_context(format_args!("{}", Counted(&calls)));
+    let _ = Ok::<(), Fault>(()).with_context(format_args!("{}", Counted(&calls)));
+    assert_eq!(calls.load(Ordering::Relaxed), 0);
+
+    let error = Err::<(), Error>(Fault::new().into())
+        .with_context(format_args!("x={}", Counted(&calls)))
+        .unwrap_err();
+    assert_eq!(error.context(), Some("x=counted"));
+
+    let fault = Err::<(), Fault>(Fault::new())
+        .with_context(format_args!("y={}", Counted(&calls)))
+        .unwrap_err();
+    assert_eq!(fault.context(), Some("y=counted"));
+    assert_eq!(calls.load(Ordering::Relaxed), 2);
+}
+
+#[test]
+fn exit_domain_error_partial_eq() {
+    let domain = |message: &str| DomainError {
+        output: ExitError::new(message, ExitCode::from(42)),
+        source: Some(Box::new(MyError::default())),
+    };
+    assert_eq!(domain("message"), domain("message"));
+    assert_ne!(domain("message"), domain("other"));
+    assert_ne!(
+        domain("message"),
+        DomainError {
+            source: None,
+            ..domain("message")
+        }
+    );
+}
+
+#[test]
+fn context_mut() {
+    let mut error = Error::Fault(Fault::new().with_context("fault"));
+    error
+        .context_mut()
+        .unwrap()
+        .as_mut()
+        .unwrap()
+        .push_str(", appended");
+    assert_eq!(error.context(), Some("fault, appended"));
+    *error.context_mut().unwrap() = None;
+    assert_eq!(error.context(), None);
+
+    let mut error = Error::new_domain(DomainError {
+        output: ExitError::new("", ExitCode::SUCCESS),
+        source: None,
+    });
+    assert_eq!(error.context(), None);
+    error
+        .context_mut()
+        .unwrap()
+        .get_or_insert_default()
+        .push_str("domain");
+    assert_eq!(error.context(), Some("domain"));
+    error
+        .context_mut()
+        .unwrap()
+        .as_mut()
+        .unwrap()
+        .push_str(", appended");
+    assert_eq!(error.context(), Some("domain, appended"));
+}
+
+#[test]
+fn append_context() {
+    let mut error = Error::Fault(Fault::new());
+    error.append_context("fault");
+    assert_eq!(error.context(), Some("fault"));
+    error.append_context(format_args!("trace id: {}", 42));
+    assert_eq!(error.context(), Some("fault, trace id: 42"));
+
+    let mut error = Error::new_domain(DomainError {
+        output: ExitError::new("", ExitCode::SUCCESS),
+        source: None,
+    });
+    error.append_context("domain");
+    error.append_context("appended");
+    assert_eq!(error.context(), Some("domain, appended"));
+}
+
+#[test]
+fn with_context_opt() {
+    let error = Error::Fault(Fault::new()).with_context_opt(Some("context"));
+    assert_eq!(error.context(), Some("context"));
+    assert_eq!(
+        error.with_context_opt(None::<&str>).context(),
+        Some("context")
+    );
+
+    let error = Err::<(), Error>(ExitError::new("", ExitCode::SUCCESS).into())
+        .with_context_opt(Some("context"))
+        .unwrap_err();
+    assert_eq!(error.context(), Some("context"));
+    assert!(
+        Err::<(), Error>(Fault::new().into())
+            .with_context_opt(None::<&str>)
+            .unwrap_err()
+            .context()
+            .is_none()
+    );
+
+    assert_eq!(
+        Err::<(), _>(Fault::new())
+            .with_context_opt(Some("context"))
+            .unwrap_err()
+            .context(),
+        Some("context")
+    );
+    assert!(
+        Err::<(), _>(Fault::new())
+            .with_context_opt(None::<&str>)
+            .unwrap_err()
+            .context()
+            .is_none()
+    );
+}
+
 #[test]
 fn context() {
     assert_eq!(
@@ -162,16 +454,123 @@ fn context() {
     );
 
     assert_eq!(
-        Error::Domain(Box::new(DomainError {
+        Error::new_domain(DomainError {
             output: ExitError::new("", ExitCode::SUCCESS).with_context("context"),
-            source: None
-        }))
+            source: None,
+        })
         .context()
         .unwrap(),
         "context"
     );
 }
 
+#[test]
+fn context_chain() {
+    let domain = DomainError {
+        output: ExitError::new("", ExitCode::SUCCESS).with_context("domain"),
+        source: Some(Box::new(MyError::default())),
+    };
+    let error = Error::Fault(Fault::new().with_context("outer").with_source(
+        Fault::new().with_source(Fault::new().with_context("middle").with_source(domain)),
+    ));
+
+    assert_eq!(error.context_chain(), vec!["outer", "middle", "domain"]);
+    assert!(Error::Fault(Fault::new()).context_chain().is_empty());
+}
+
+#[test]
+fn with_extension() {
+    struct Tenant(&'static str);
+
+    let error = Error::Fault(Fault::new()).with_extension(std::sync::Arc::new(Tenant("acme")));
+    assert_eq!(error.extension::<Tenant>().unwrap().0, "acme");
+    assert!(error.extension::<MyError>().is_none());
+
+    let error = Error::from(ExitError::new("", ExitCode::SUCCESS))
+        .with_extension(std::sync::Arc::new(Tenant("acme")))
+        .with_extension(std::sync::Arc::new(Tenant("override")));
+    assert_eq!(error.extension::<Tenant>().unwrap().0, "override");
+}
+
+#[test]
+fn domain_default_methods() {
+    // A domain implementing only the required methods of the trait
+    #[derive(Debug)]
+    struct Minimal(Option<String>);
+
+    impl std::fmt::Display for Minimal {
+        fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+            write!(f, "minimal")
+        }
+    }
+
+    impl StdError for Minimal {}
+
+    impl crate::Domain for Minimal {
+        fn with_context(self, context: impl std::fmt::Display) -> Self {
+            Self(Some(context.to_string()))
+        }
+
+        fn context(&self) -> Option<&str> {
+            self.0.as_deref()
+        }
+
+        fn into_source(self) -> Option<Box<dyn StdError + Send + Sync>> {
+            None
+        }
+    }
+
+    struct Tenant;
+
+    let mut error =
+        crate::Error::new_domain(Minimal(None)).with_extension(std::sync::Arc::new(Tenant));
+    assert!(error.extension::<Tenant>().is_none());
+    assert!(error.context_mut().is_none());
+
+    error.append_context("first");
+    error.append_context("second");
+    assert_eq!(error.context(), Some("first, second"));
+    assert!(matches!(error, crate::Error::Domain(_)));
+}
+
+#[test]
+fn is_retryable() {
+    assert!(Error::Fault(Fault::new().retryable()).is_retryable());
+    assert!(!Error::Fault(Fault::new()).is_retryable());
+    assert!(!Error::from(ExitError::new("", ExitCode::SUCCESS)).is_retryable());
+}
+
+#[test]
+fn serialize() {
+    // Use this crate types, the ones re-exported by explicit_error_http come from another instance of the crate
+    let fault = crate::Error::<explicit_error_http::DomainError>::Fault(
+        crate::Fault::new().with_context("context"),
+    );
+    let domain = crate::Error::new_domain(
+        explicit_error_http::HttpError::new(
+            http::StatusCode::BAD_REQUEST,
+            serde_json::json!({"foo": "bar"}),
+        )
+        .with_source(MyError::default()),
+    );
+
+    assert_eq!(serde_json::json!(fault)["error_kind"], "fault");
+    assert_eq!(serde_json::json!(fault)["context"], "context");
+    assert_eq!(
+        serde_json::json!(domain),
+        serde_json::json!({"error_kind": "domain", "foo": "bar"})
+    );
+
+    let problem_details = crate::Error::new_domain(explicit_error_http::DomainError {
+        output: explicit_error_http::HttpError::from_status(http::StatusCode::NOT_FOUND),
+        source: None,
+    });
+    assert_eq!(
+        serde_json::to_string(&problem_details).unwrap(),
+        r#"{"error_kind":"domain","type":"about:blank","status":404,"title":"Not Found"}"#
+    );
+}
+
 #[test]
 fn errors_chain_debug() {
     #[derive(Debug)]
@@ -190,98 +589,560 @@ fn errors_chain_debug() {
     }
 
     #[derive(Debug)]
-    struct Chain0(Chain1);
+    struct Chain0(Chain1);
+
+    impl std::fmt::Display for Chain0 {
+        fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+            write!(f, "Chain1",)
+        }
+    }
+
+    impl std::error::Error for Chain0 {
+        fn source(&self) -> Option<&(dyn StdError + 'static)> {
+            Some(&self.0)
+        }
+    }
+
+    assert_eq!(
+        crate::errors_chain_debug(&Chain0(Chain1(MyError::default()))),
+        "Chain0(Chain1(MyError(true)))->Chain1(MyError(true))->MyError(true)"
+    );
+}
+
+#[test]
+fn is_caused_by() {
+    #[derive(Debug)]
+    struct Wrapper(Box<dyn StdError + Send + Sync>);
+
+    impl std::fmt::Display for Wrapper {
+        fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+            write!(f, "Wrapper")
+        }
+    }
+
+    impl std::error::Error for Wrapper {
+        fn source(&self) -> Option<&(dyn StdError + 'static)> {
+            Some(self.0.as_ref())
+        }
+    }
+
+    #[derive(Debug)]
+    struct Cyclic;
+
+    impl std::fmt::Display for Cyclic {
+        fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+            write!(f, "Cyclic")
+        }
+    }
+
+    impl std::error::Error for Cyclic {
+        fn source(&self) -> Option<&(dyn StdError + 'static)> {
+            Some(self)
+        }
+    }
+
+    let fault = Error::from(
+        Fault::new().with_source(Wrapper(Box::new(Wrapper(Box::new(MyError::default()))))),
+    );
+    assert!(fault.is_caused_by::<Fault>());
+    assert!(fault.is_caused_by::<Wrapper>());
+    assert!(fault.is_caused_by::<MyError>());
+    assert!(!fault.is_caused_by::<sqlx::Error>());
+
+    let domain = Error::new_domain(DomainError {
+        output: ExitError::new("", ExitCode::SUCCESS),
+        source: Some(Box::new(Wrapper(Box::new(sqlx::Error::RowNotFound)))),
+    });
+    assert!(domain.is_caused_by::<DomainError>());
+    assert!(domain.is_caused_by::<sqlx::Error>());
+    assert!(!domain.is_caused_by::<MyError>());
+
+    assert!(!Error::from(Fault::new().with_source(Cyclic)).is_caused_by::<MyError>());
+}
+
+#[test]
+fn chain() {
+    #[derive(Debug)]
+    struct Wrapper(MyError);
+
+    impl std::fmt::Display for Wrapper {
+        fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+            write!(f, "Wrapper")
+        }
+    }
+
+    impl std::error::Error for Wrapper {
+        fn source(&self) -> Option<&(dyn StdError + 'static)> {
+            Some(&self.0)
+        }
+    }
+
+    #[derive(Debug)]
+    struct Cyclic;
+
+    impl std::fmt::Display for Cyclic {
+        fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+            write!(f, "Cyclic")
+        }
+    }
+
+    impl std::error::Error for Cyclic {
+        fn source(&self) -> Option<&(dyn StdError + 'static)> {
+            Some(self)
+        }
+    }
+
+    let error = Error::from(
+        Fault::new()
+            .with_context("context")
+            .with_source(Wrapper(MyError::default())),
+    );
+    assert_eq!(
+        error
+            .chain()
+            .skip(1)
+            .map(ToString::to_string)
+            .collect::<Vec<_>>(),
+        vec!["Wrapper", "true"]
+    );
+    let mut chain = error.chain();
+    assert!(chain.next().unwrap().is::<Fault>());
+    assert!(chain.next().unwrap().is::<Wrapper>());
+    assert!(chain.next().unwrap().is::<MyError>());
+    assert!(chain.next().is_none());
+    drop(chain);
+    assert_eq!(error.unwrap_fault().chain().count(), 3);
+
+    let domain = DomainError {
+        output: ExitError::new("domain", ExitCode::SUCCESS),
+        source: Some(Box::new(Wrapper(MyError::default()))),
+    };
+    assert_eq!(
+        domain.chain().map(ToString::to_string).collect::<Vec<_>>(),
+        vec!["domain", "Wrapper", "true"]
+    );
+    assert_eq!(Error::new_domain(domain).chain().count(), 3);
+
+    assert!(
+        Error::from(Fault::new().with_source(Cyclic))
+            .chain()
+            .count()
+            <= 32
+    );
+}
+
+#[test]
+fn errors_chain_debug_cycle() {
+    #[derive(Debug)]
+    struct Cyclic;
+
+    impl std::fmt::Display for Cyclic {
+        fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+            write!(f, "Cyclic")
+        }
+    }
+
+    impl std::error::Error for Cyclic {
+        fn source(&self) -> Option<&(dyn StdError + 'static)> {
+            Some(self)
+        }
+    }
+
+    #[derive(Debug)]
+    struct Deep(usize);
+
+    impl std::fmt::Display for Deep {
+        fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+            write!(f, "Deep")
+        }
+    }
+
+    impl std::error::Error for Deep {
+        fn source(&self) -> Option<&(dyn StdError + 'static)> {
+            Some(Box::leak(Box::new(Deep(self.0 + 1))))
+        }
+    }
+
+    assert_eq!(crate::errors_chain_debug(&Cyclic), "Cyclic->...");
+    assert_eq!(
+        crate::errors_chain_debug(&Deep(0)).split("->").count(),
+        super::ERRORS_CHAIN_MAX_DEPTH + 1
+    );
+    assert!(
+        crate::Fault::new()
+            .with_source(Cyclic)
+            .to_string()
+            .contains("->..., Cyclic")
+    );
+}
+
+#[test]
+fn map_fault_to_domain() {
+    let to_domain = |fault: Fault| {
+        explicit_error_http::HttpError::internal(serde_json::json!({"code": "payment_unavailable"}))
+            .with_source(fault)
+    };
+
+    let error = Err::<(), explicit_error_http::Error>(
+        Fault::new()
+            .with_context("Payment provider timed out")
+            .with_source(MyError::default())
+            .into(),
+    )
+    .map_fault_to_domain(to_domain)
+    .unwrap_err();
+
+    let domain = error.unwrap();
+    assert_eq!(
+        domain.output.http_status_code,
+        http::StatusCode::INTERNAL_SERVER_ERROR
+    );
+    assert_eq!(
+        serde_json::json!(domain.output),
+        serde_json::json!({"code": "payment_unavailable"})
+    );
+    let fault = domain.source.unwrap().downcast::<Fault>().unwrap();
+    assert_eq!(fault.context(), Some("Payment provider timed out"));
+    assert!(fault.source.unwrap().downcast::<MyError>().is_ok());
+
+    let error = Err::<(), explicit_error_http::Error>(
+        explicit_error_http::HttpError::not_found("Not found").into(),
+    )
+    .map_fault_to_domain(to_domain)
+    .unwrap_err();
+    assert_eq!(
+        error.unwrap().output.http_status_code,
+        http::StatusCode::NOT_FOUND
+    );
+}
+
+#[test]
+fn map_err_or_fault() {
+    let closure = |e: MyError| match e.0 {
+        true => Ok(ExitError::new("", ExitCode::SUCCESS)),
+        false => Err(e),
+    };
+
+    assert_eq!(Ok(()).map_err_or_fault(closure).unwrap(), ());
+
+    assert!(
+        Err::<(), _>(MyError::default())
+            .map_err_or_fault(closure)
+            .unwrap_err()
+            .is_domain()
+    );
+
+    let line = line!() + 2;
+    let fault = Err::<(), _>(MyError(false))
+        .map_err_or_fault(closure)
+        .unwrap_err()
+        .unwrap_fault();
+    assert_eq!(fault.location().unwrap().file(), file!());
+    assert_eq!(fault.location().unwrap().line(), line);
+    assert!(
+        fault
+            .to_string()
+            .contains(&format!("Location: {}:{line}:", file!()))
+    );
+}
+
+#[test]
+fn map_err_or_fault_dyn() {
+    fn dyn_error(value: bool) -> std::result::Result<(), Box<dyn StdError + Send + Sync>> {
+        Err(Box::new(MyError(value)))
+    }
+
+    let closure = |e: Box<dyn StdError + Send + Sync>| match e.downcast::<MyError>() {
+        Ok(e) if e.0 => Ok(ExitError::new("", ExitCode::SUCCESS)),
+        Ok(e) => Err(e as Box<dyn StdError + Send + Sync>),
+        Err(e) => Err(e),
+    };
+
+    assert_eq!(Ok(()).map_err_or_fault_dyn(closure).unwrap(), ());
+
+    assert!(
+        dyn_error(true)
+            .map_err_or_fault_dyn(closure)
+            .unwrap_err()
+            .is_domain()
+    );
+
+    let fault = dyn_error(false)
+        .map_err_or_fault_dyn(closure)
+        .unwrap_err()
+        .unwrap_fault();
+    assert_eq!(fault.location().unwrap().file(), file!());
+    assert!(fault.source.unwrap().downcast::<MyError>().is_ok());
+}
+
+#[test]
+fn or_fault_no_source() {
+    assert_eq!(
+        Err::<(), _>(())
+            .or_fault_no_source()
+            .unwrap_err()
+            .backtrace_status(),
+        BacktraceStatus::Disabled
+    );
+    assert!(Ok::<_, ()>(()).or_fault_no_source().is_ok());
+}
+
+#[test]
+fn or_fault() {
+    let fault = Err::<(), _>(MyError::default()).or_fault().unwrap_err();
+    assert_eq!(fault.backtrace_status(), BacktraceStatus::Disabled);
+    assert_eq!(
+        *fault.source.unwrap().downcast::<MyError>().unwrap(),
+        MyError::default()
+    );
+
+    assert!(Ok::<_, MyError>(()).or_fault().is_ok());
+}
+
+#[test]
+fn fault() {
+    let fault = Err::<(), _>(MyError::default()).fault().unwrap_err();
+    assert_eq!(
+        *fault.source.unwrap().downcast::<MyError>().unwrap(),
+        MyError::default()
+    );
+    assert!(Ok::<_, MyError>(()).fault().is_ok());
+
+    assert!(None::<()>.fault().unwrap_err().source.is_none());
+    assert_eq!(Some(()).fault().unwrap(), ());
+}
+
+#[test]
+fn or_fault_no_source_force() {
+    assert_eq!(
+        Err::<(), _>(())
+            .or_fault_no_source_force()
+            .unwrap_err()
+            .backtrace_status(),
+        BacktraceStatus::Captured
+    );
+    assert!(Ok::<_, ()>(()).or_fault_no_source_force().is_ok());
+}
+
+#[test]
+fn or_fault_force() {
+    let fault = Err::<(), _>(MyError::default())
+        .or_fault_force()
+        .unwrap_err();
+    assert_eq!(fault.backtrace_status(), BacktraceStatus::Captured);
+    assert_eq!(
+        *fault.source.unwrap().downcast::<MyError>().unwrap(),
+        MyError::default()
+    );
+
+    assert!(Ok::<_, MyError>(()).or_fault_force().is_ok());
+}
+
+#[test]
+fn or_fault_with() {
+    let fault = Err::<(), _>(MyError::default())
+        .or_fault_with(|e| format!("context {e}"))
+        .unwrap_err();
+    assert_eq!(fault.backtrace_status(), BacktraceStatus::Disabled);
+    assert_eq!(fault.context().unwrap(), "context true");
+    assert_eq!(
+        *fault.source.unwrap().downcast::<MyError>().unwrap(),
+        MyError::default()
+    );
+
+    assert!(
+        Ok::<_, MyError>(())
+            .or_fault_with(|_| -> &str { unreachable!() })
+            .is_ok()
+    );
+}
+
+#[test]
+fn or_fault_with_force() {
+    let fault = Err::<(), _>(MyError::default())
+        .or_fault_with_force(|e| format!("context {e}"))
+        .unwrap_err();
+    assert_eq!(fault.backtrace_status(), BacktraceStatus::Captured);
+    assert_eq!(fault.context().unwrap(), "context true");
+    assert_eq!(
+        *fault.source.unwrap().downcast::<MyError>().unwrap(),
+        MyError::default()
+    );
+
+    assert!(
+        Ok::<_, MyError>(())
+            .or_fault_with_force(|_| -> &str { unreachable!() })
+            .is_ok()
+    );
+}
+
+#[test]
+fn inspect_err_as_fault() {
+    let count = std::cell::Cell::new(0);
+
+    let fault = Err::<(), _>(MyError::default())
+        .inspect_err_as_fault(|e| {
+            assert_eq!(e, &MyError::default());
+            count.set(count.get() + 1);
+        })
+        .or_fault()
+        .unwrap_err();
+    assert_eq!(count.get(), 1);
+    assert!(fault.source.unwrap().downcast::<MyError>().is_ok());
+
+    assert!(
+        Ok::<_, MyError>(())
+            .inspect_err_as_fault(|_| count.set(count.get() + 1))
+            .is_ok()
+    );
+    assert_eq!(count.get(), 1);
+}
+
+#[test]
+fn or_fault_unless() {
+    #[derive(Debug, PartialEq)]
+    enum Kind {
+        NotFound,
+        Timeout,
+    }
+
+    #[derive(Debug)]
+    struct KindError(Kind);
 
-    impl std::fmt::Display for Chain0 {
-        fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-            write!(f, "Chain1",)
+    impl KindError {
+        fn kind(&self) -> &Kind {
+            &self.0
         }
     }
 
-    impl std::error::Error for Chain0 {
-        fn source(&self) -> Option<&(dyn StdError + 'static)> {
-            Some(&self.0)
+    impl std::fmt::Display for KindError {
+        fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+            write!(f, "{:?}", self.0)
         }
     }
 
-    assert_eq!(
-        crate::errors_chain_debug(&Chain0(Chain1(MyError::default()))),
-        "Chain0(Chain1(MyError(true)))->Chain1(MyError(true))->MyError(true)"
-    );
-}
-
-#[test]
-fn map_err_or_fault() {
-    let closure = |e: MyError| match e.0 {
-        true => Ok(ExitError::new("", ExitCode::SUCCESS)),
-        false => Err(e),
-    };
+    impl StdError for KindError {}
 
-    assert_eq!(Ok(()).map_err_or_fault(closure).unwrap(), ());
+    let is_expected = |e: &KindError| *e.kind() == Kind::NotFound;
 
-    assert!(
-        Err::<(), _>(MyError::default())
-            .map_err_or_fault(closure)
+    assert_eq!(
+        Ok::<_, KindError>(())
+            .or_fault_unless(is_expected)
+            .unwrap()
+            .unwrap(),
+        ()
+    );
+    assert_eq!(
+        Err::<(), _>(KindError(Kind::NotFound))
+            .or_fault_unless(is_expected)
+            .unwrap()
             .unwrap_err()
-            .is_domain()
+            .0,
+        Kind::NotFound
     );
-
     assert!(
-        Err::<(), _>(MyError(false))
-            .map_err_or_fault(closure)
+        Err::<(), _>(KindError(Kind::Timeout))
+            .or_fault_unless(is_expected)
             .unwrap_err()
-            .is_fault()
+            .source
+            .unwrap()
+            .downcast::<KindError>()
+            .is_ok()
     );
 }
 
 #[test]
-fn or_fault_no_source() {
-    assert_eq!(
-        Err::<(), _>(())
-            .or_fault_no_source()
+fn or_fault_if() {
+    let is_fault = |e: &sqlx::Error| !matches!(e, sqlx::Error::RowNotFound);
+
+    assert!(
+        Ok::<_, sqlx::Error>(())
+            .or_fault_if(is_fault)
+            .unwrap()
+            .is_ok()
+    );
+    assert!(matches!(
+        Err::<(), _>(sqlx::Error::RowNotFound)
+            .or_fault_if(is_fault)
+            .unwrap()
+            .unwrap_err(),
+        sqlx::Error::RowNotFound
+    ));
+    assert!(
+        Err::<(), _>(sqlx::Error::PoolTimedOut)
+            .or_fault_if(is_fault)
             .unwrap_err()
-            .backtrace_status(),
-        BacktraceStatus::Disabled
+            .source
+            .unwrap()
+            .downcast::<sqlx::Error>()
+            .is_ok()
     );
-    assert!(Ok::<_, ()>(()).or_fault_no_source().is_ok());
 }
 
 #[test]
-fn or_fault() {
-    let fault = Err::<(), _>(MyError::default()).or_fault().unwrap_err();
-    assert_eq!(fault.backtrace_status(), BacktraceStatus::Disabled);
-    assert_eq!(
-        *fault.source.unwrap().downcast::<MyError>().unwrap(),
-        MyError::default()
-    );
+fn send_sync() {
+    fn assert_send_sync<T: Send + Sync>() {}
+    assert_send_sync::<Error>();
+    assert_send_sync::<explicit_error_http::Error>();
 
-    assert!(Ok::<_, MyError>(()).or_fault().is_ok());
+    let error = Error::new_domain(DomainError {
+        output: ExitError::new("", ExitCode::SUCCESS),
+        source: Some(Box::new(MyError::default())),
+    });
+    let source = std::thread::spawn(move || {
+        assert!(error.is_domain());
+        error.downcast_source::<sqlx::Error>().unwrap_err()
+    })
+    .join()
+    .unwrap();
+
+    let source = std::thread::spawn(move || source.downcast::<MyError>().unwrap())
+        .join()
+        .unwrap();
+    assert_eq!(*source, MyError::default());
 }
 
 #[test]
-fn or_fault_no_source_force() {
-    assert_eq!(
-        Err::<(), _>(())
-            .or_fault_no_source_force()
+fn try_map_on_sources() {
+    fn map(error: Error) -> Error {
+        Err::<(), _>(error)
+            .try_map_on_sources(|source| {
+                if source.is::<MyError>() {
+                    return Some(ExitError::new("my error", ExitCode::from(1)));
+                }
+
+                source
+                    .downcast_ref::<sqlx::Error>()
+                    .map(|e| ExitError::new(e.to_string(), ExitCode::from(2)))
+            })
             .unwrap_err()
-            .backtrace_status(),
-        BacktraceStatus::Captured
-    );
-    assert!(Ok::<_, ()>(()).or_fault_no_source_force().is_ok());
-}
+    }
 
-#[test]
-fn or_fault_force() {
-    let fault = Err::<(), _>(MyError::default())
-        .or_fault_force()
-        .unwrap_err();
-    assert_eq!(fault.backtrace_status(), BacktraceStatus::Captured);
     assert_eq!(
-        *fault.source.unwrap().downcast::<MyError>().unwrap(),
-        MyError::default()
+        map(Fault::new().with_source(MyError::default()).into())
+            .unwrap()
+            .output
+            .exit_code(),
+        ExitCode::from(1)
     );
-
-    assert!(Ok::<_, MyError>(()).or_fault_force().is_ok());
+    assert_eq!(
+        map(Error::new_domain(DomainError {
+            output: ExitError::new("", ExitCode::SUCCESS),
+            source: Some(Box::new(sqlx::Error::RowNotFound)),
+        }))
+        .unwrap()
+        .output
+        .exit_code(),
+        ExitCode::from(2)
+    );
+    assert!(
+        map(Fault::new().with_source(std::io::Error::other("io")).into())
+            .unwrap_fault()
+            .source()
+            .unwrap()
+            .is::<std::io::Error>()
+    );
+    assert!(map(Fault::new().into()).is_fault());
 }
 
 #[test]
@@ -308,36 +1169,78 @@ fn try_map_on_source() {
     );
 
     assert!(
-        Err::<(), _>(Error::Domain(Box::new(DomainError {
+        Err::<(), _>(Error::new_domain(DomainError {
             output: ExitError::new("", ExitCode::SUCCESS),
-            source: None
-        })))
+            source: None,
+        }))
         .try_map_on_source(|_: MyError| Fault::new())
         .unwrap_err()
         .is_domain()
     );
 
     assert!(
-        Err::<(), _>(Error::Domain(Box::new(DomainError {
+        Err::<(), _>(Error::new_domain(DomainError {
             output: ExitError::new("", ExitCode::SUCCESS),
-            source: Some(Box::new(MyError::default()))
-        })))
+            source: Some(Box::new(MyError::default())),
+        }))
         .try_map_on_source(|_: sqlx::Error| Fault::new())
         .unwrap_err()
         .is_domain()
     );
 
     assert!(
-        Err::<(), _>(Error::Domain(Box::new(DomainError {
+        Err::<(), _>(Error::new_domain(DomainError {
             output: ExitError::new("", ExitCode::SUCCESS),
-            source: Some(Box::new(MyError::default()))
-        })))
+            source: Some(Box::new(MyError::default())),
+        }))
         .try_map_on_source(|_: MyError| Fault::new())
         .unwrap_err()
         .is_fault()
     );
 }
 
+#[test]
+fn try_map_on_domain() {
+    assert!(
+        Err::<(), _>(Error::Fault(Fault::new().with_source(MyError::default())))
+            .try_map_on_domain(|_: MyError| ExitError::new("", ExitCode::SUCCESS))
+            .unwrap_err()
+            .is_fault()
+    );
+
+    // Domain type itself
+    assert!(
+        Err::<(), _>(Error::new_domain(DomainError {
+            output: ExitError::new("", ExitCode::SUCCESS),
+            source: None,
+        }))
+        .try_map_on_domain(|_: DomainError| Fault::new())
+        .unwrap_err()
+        .is_fault()
+    );
+
+    // Source of the domain type, as generated by derives
+    assert!(
+        Err::<(), _>(Error::new_domain(DomainError {
+            output: ExitError::new("", ExitCode::SUCCESS),
+            source: Some(Box::new(MyError::default())),
+        }))
+        .try_map_on_domain(|_: MyError| Fault::new())
+        .unwrap_err()
+        .is_fault()
+    );
+
+    assert!(
+        Err::<(), _>(Error::new_domain(DomainError {
+            output: ExitError::new("", ExitCode::SUCCESS),
+            source: Some(Box::new(MyError::default())),
+        }))
+        .try_map_on_domain(|_: sqlx::Error| Fault::new())
+        .unwrap_err()
+        .is_domain()
+    );
+}
+
 #[test]
 fn result_with_context() {
     assert_eq!(
@@ -350,10 +1253,10 @@ fn result_with_context() {
     );
 
     assert_eq!(
-        Err::<(), _>(Error::Domain(Box::new(DomainError {
+        Err::<(), _>(Error::new_domain(DomainError {
             output: ExitError::new("", ExitCode::SUCCESS),
-            source: None
-        })))
+            source: None,
+        }))
         .with_context("context")
         .unwrap_err()
         .context()
@@ -365,6 +1268,64 @@ fn result_with_context() {
 }
 
 #[test]
+fn and_then_domain() {
+    assert_eq!(Ok::<_, Error>(1).and_then_domain(|v| Ok(v + 1)).unwrap(), 2);
+
+    let error = Ok::<_, Error>(1)
+        .and_then_domain(|_| Err::<(), _>(ExitError::new("", ExitCode::SUCCESS).into()))
+        .unwrap_err();
+    assert!(error.is_domain());
+    assert_eq!(error.context(), None);
+
+    assert_eq!(
+        Err::<u8, _>(Error::Fault(Fault::new().with_context("context")))
+            .and_then_domain(|_| -> Result<()> { unreachable!() })
+            .unwrap_err()
+            .context()
+            .unwrap(),
+        "context"
+    );
+}
+
+#[test]
+fn and_then_domain_step() {
+    assert_eq!(
+        Ok::<_, Error>(1)
+            .and_then_domain_step("increment", |v| Ok(v + 1))
+            .unwrap(),
+        2
+    );
+
+    let error = Ok::<_, Error>(1)
+        .and_then_domain_step("check", |_| {
+            Err::<(), _>(ExitError::new("", ExitCode::SUCCESS).into())
+        })
+        .unwrap_err();
+    assert!(error.is_domain());
+    assert_eq!(error.context(), Some("check"));
+
+    assert_eq!(
+        Ok::<_, Error>(1)
+            .and_then_domain_step("load", |_| {
+                Err::<(), _>(Fault::new().with_context("timeout").into())
+            })
+            .unwrap_err()
+            .context(),
+        Some("load: timeout")
+    );
+
+    assert_eq!(
+        Err::<u8, _>(Error::Fault(Fault::new().with_context("context")))
+            .and_then_domain_step("unreachable", |_| -> Result<()> { unreachable!() })
+            .unwrap_err()
+            .context()
+            .unwrap(),
+        "context"
+    );
+}
+
+#[test]
+#[allow(clippy::unnecessary_literal_unwrap)]
 fn unwrap_err_source() {
     assert_eq!(
         Err::<(), _>(Error::Fault(Fault::new().with_source(MyError::default())))
@@ -377,6 +1338,7 @@ fn unwrap_err_source() {
 
 #[should_panic]
 #[test]
+#[allow(clippy::unnecessary_literal_unwrap)]
 fn unwrap_err_source_panic() {
     Err::<(), _>(Error::Fault(Fault::new()))
         .unwrap_err()
@@ -386,6 +1348,7 @@ fn unwrap_err_source_panic() {
 
 #[should_panic]
 #[test]
+#[allow(clippy::unnecessary_literal_unwrap)]
 fn unwrap_err_source_panic2() {
     Err::<(), _>(Error::Fault(
         Fault::new().with_source(sqlx::Error::RowNotFound),
@@ -416,6 +1379,34 @@ fn ok_or_fault_force() {
     assert_eq!(Some(()).ok_or_fault().unwrap(), ());
 }
 
+#[test]
+fn ok_or_fault_with() {
+    let fault = None::<()>.ok_or_fault_with(|| "missing key").unwrap_err();
+    assert_eq!(fault.context().unwrap(), "missing key");
+    assert_eq!(fault.backtrace_status(), BacktraceStatus::Disabled);
+    assert_eq!(
+        Some(())
+            .ok_or_fault_with(|| -> &str { panic!("closure called on Some") })
+            .unwrap(),
+        ()
+    );
+}
+
+#[test]
+fn ok_or_fault_with_force() {
+    let fault = None::<()>
+        .ok_or_fault_with_force(|| "missing key")
+        .unwrap_err();
+    assert_eq!(fault.context().unwrap(), "missing key");
+    assert_eq!(fault.backtrace_status(), BacktraceStatus::Captured);
+    assert_eq!(
+        Some(())
+            .ok_or_fault_with_force(|| -> &str { panic!("closure called on Some") })
+            .unwrap(),
+        ()
+    );
+}
+
 #[test]
 fn result_fault_with_context() {
     assert_eq!(
@@ -429,3 +1420,207 @@ fn result_fault_with_context() {
 
     assert!(Ok::<(), Fault>(()).with_context("context").is_ok());
 }
+
+#[test]
+fn user_message() {
+    assert_eq!(
+        Error::from(ExitError::new("Something went wrong", ExitCode::FAILURE)).user_message(),
+        Some("Something went wrong")
+    );
+    assert_eq!(Error::Fault(Fault::new()).user_message(), None);
+}
+
+#[cfg(feature = "tracing")]
+#[test]
+fn with_span() {
+    use std::sync::{Arc, Mutex};
+    use tracing::{
+        Event, Metadata, Subscriber,
+        field::{Field, Visit},
+        span::{Attributes, Id, Record},
+    };
+
+    #[derive(Clone, Default)]
+    struct Recorder(Arc<Mutex<Vec<(String, String)>>>);
+
+    impl Visit for Recorder {
+        fn record_debug(&mut self, field: &Field, value: &dyn std::fmt::Debug) {
+            self.0
+                .lock()
+                .unwrap()
+                .push((field.name().to_string(), format!("{value:?}")));
+        }
+
+        fn record_str(&mut self, field: &Field, value: &str) {
+            self.0
+                .lock()
+                .unwrap()
+                .push((field.name().to_string(), value.to_string()));
+        }
+    }
+
+    impl Subscriber for Recorder {
+        fn enabled(&self, _: &Metadata<'_>) -> bool {
+            true
+        }
+
+        fn new_span(&self, span: &Attributes<'_>) -> Id {
+            span.record(&mut self.clone());
+            Id::from_u64(1)
+        }
+
+        fn record(&self, _: &Id, values: &Record<'_>) {
+            values.record(&mut self.clone());
+        }
+
+        fn record_follows_from(&self, _: &Id, _: &Id) {}
+
+        fn event(&self, _: &Event<'_>) {}
+
+        fn enter(&self, _: &Id) {}
+
+        fn exit(&self, _: &Id) {}
+    }
+
+    let recorder = Recorder::default();
+    tracing::subscriber::with_default(recorder.clone(), || {
+        assert!(Ok::<(), Error>(()).with_span("ok").is_ok());
+        assert!(
+            Err::<(), Error>(Fault::new().with_context("context").into())
+                .with_span("load_config")
+                .is_err()
+        );
+    });
+
+    let fields = recorder.0.lock().unwrap();
+    assert!(fields.contains(&("otel.name".to_string(), "load_config".to_string())));
+    assert!(!fields.contains(&("otel.name".to_string(), "ok".to_string())));
+    assert!(
+        fields
+            .iter()
+            .any(|(name, value)| name == "error" && value.contains("context"))
+    );
+}
+
+// The logger can be set once per process: `ok_or_log` is tested here too.
+#[cfg(feature = "log")]
+#[test]
+fn log_err() {
+    use std::sync::{
+        Mutex,
+        atomic::{AtomicUsize, Ordering},
+    };
+
+    struct Recorder(Mutex<Vec<(log::Level, String)>>);
+
+    impl log::Log for Recorder {
+        fn enabled(&self, _: &log::Metadata<'_>) -> bool {
+            true
+        }
+
+        fn log(&self, record: &log::Record<'_>) {
+            if record.target().starts_with("explicit_error") {
+                self.0
+                    .lock()
+                    .unwrap()
+                    .push((record.level(), record.args().to_string()));
+            }
+        }
+
+        fn flush(&self) {}
+    }
+
+    static RECORDER: Recorder = Recorder(Mutex::new(Vec::new()));
+    log::set_logger(&RECORDER).unwrap();
+    log::set_max_level(log::LevelFilter::Trace);
+
+    assert!(Ok::<(), Error>(()).log_err(log::Level::Warn).is_ok());
+    assert!(RECORDER.0.lock().unwrap().is_empty());
+
+    assert!(
+        Err::<(), Error>(Fault::new().with_context("context").into())
+            .log_err(log::Level::Warn)
+            .is_err()
+    );
+    assert_eq!(
+        *RECORDER.0.lock().unwrap(),
+        vec![(log::Level::Warn, "Context: context\n".to_string())]
+    );
+    RECORDER.0.lock().unwrap().clear();
+
+    static DROPPED: AtomicUsize = AtomicUsize::new(0);
+
+    #[derive(Debug)]
+    struct Tracked;
+
+    impl std::fmt::Display for Tracked {
+        fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+            write!(f, "tracked")
+        }
+    }
+
+    impl StdError for Tracked {}
+
+    impl Drop for Tracked {
+        fn drop(&mut self) {
+            DROPPED.fetch_add(1, Ordering::Relaxed);
+        }
+    }
+
+    assert_eq!(Ok::<u8, Error>(42).ok_or_log(), Some(42));
+    assert!(RECORDER.0.lock().unwrap().is_empty());
+
+    let result: Result<u8> = Err::<u8, _>(Tracked)
+        .or_fault()
+        .map_err(|f| f.with_context("warmup").into());
+    assert_eq!(result.ok_or_log(), None);
+    assert_eq!(DROPPED.load(Ordering::Relaxed), 1);
+
+    let logs = RECORDER.0.lock().unwrap();
+    assert_eq!(logs.len(), 1);
+    assert_eq!(logs[0].0, log::Level::Warn);
+    assert!(logs[0].1.starts_with("Context: warmup\n"));
+    assert!(logs[0].1.contains("tracked"));
+}
+
+#[test]
+fn ensure_domain() {
+    fn check(cond: bool) -> Result<()> {
+        ensure_domain!(cond, ExitError::new("domain", ExitCode::from(42)));
+        Ok(())
+    }
+
+    assert!(check(true).is_ok());
+    assert_eq!(
+        check(false).unwrap_err().unwrap().output.exit_code(),
+        ExitCode::from(42)
+    );
+}
+
+#[test]
+fn ensure_fault() {
+    fn check(cond: bool) -> Result<()> {
+        ensure_fault!(cond, "fault {}", 42);
+        Ok(())
+    }
+
+    fn check_without_message(len: usize) -> Result<()> {
+        ensure_fault!(len > 0);
+        Ok(())
+    }
+
+    assert!(check(true).is_ok());
+    assert_eq!(
+        check(false).unwrap_err().unwrap_fault().context(),
+        Some("fault 42")
+    );
+
+    assert!(check_without_message(1).is_ok());
+    assert_eq!(
+        check_without_message(0)
+            .unwrap_err()
+            .unwrap_fault()
+            .context(),
+        Some("Condition failed: `len > 0`")
+    );
+}
@@ -1,9 +1,66 @@
-use crate::error::Error;
+#[cfg(feature = "backtrace")]
+use crate::BacktracePolicy;
+#[cfg(feature = "otel")]
+use crate::error::SpanIds;
+use crate::error::{Error, Extensions, Retryable};
+#[cfg(feature = "otel")]
+use opentelemetry::trace::{SpanId, TraceId};
 use serde::{Serialize, Serializer};
+#[cfg(feature = "backtrace")]
+use std::backtrace::Backtrace;
 use std::{
-    backtrace::{Backtrace, BacktraceStatus},
+    any::Any,
+    backtrace::BacktraceStatus,
     error::Error as StdError,
+    hash::{DefaultHasher, Hash, Hasher},
+    panic::Location,
+    sync::{
+        Arc,
+        atomic::{AtomicBool, Ordering},
+    },
 };
+#[cfg(feature = "tracing")]
+use std::{
+    collections::HashMap,
+    sync::{LazyLock, Mutex},
+    time::{Duration, Instant},
+};
+
+static FORCE_BACKTRACE: AtomicBool = AtomicBool::new(false);
+
+/// Force the backtrace capture of every [Fault], regardless of the `RUST_BACKTRACE` and `RUST_LIB_BACKTRACE` environment variables,
+/// as if they were generated with [Fault::new_force]. Usefull to turn it on at runtime, eg: during an incident.
+///
+/// Disabled by default, it has no effect without the `backtrace` feature.
+/// # Examples
+/// ```rust
+/// explicit_error::set_force_backtrace(true);
+/// assert_eq!(
+///     explicit_error::Fault::new().backtrace_status(),
+///     std::backtrace::BacktraceStatus::Captured
+/// );
+/// ```
+pub fn set_force_backtrace(force: bool) {
+    FORCE_BACKTRACE.store(force, Ordering::Relaxed);
+}
+
+#[cfg(feature = "tracing")]
+static LOG_THROTTLE: LazyLock<Mutex<HashMap<u64, LogThrottle>>> = LazyLock::new(Default::default);
+#[cfg(feature = "tracing")]
+const LOG_THROTTLE_CAPACITY: usize = 1024;
+
+#[derive(Serialize)]
+struct PublicFault {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    id: Option<String>,
+    message: &'static str,
+}
+
+#[cfg(feature = "tracing")]
+struct LogThrottle {
+    logged_at: Instant,
+    suppressed: u64,
+}
 
 /// Wrapper for errors that should not happen but cannot panic.
 /// It is wrapped in the [Error::Fault] variant.
@@ -13,17 +70,59 @@ use std::{
 /// [or_fault_no_source()](crate::error::ResultFault::or_fault_no_source),
 /// [or_fault_force()](crate::error::ResultFault::or_fault_force),
 /// [or_fault_no_source_force()](crate::error::ResultFault::or_fault_no_source_force),
-/// [ok_or_fault()](crate::error::OptionFault::ok_or_fault)
-/// [ok_or_fault_force()](crate::error::OptionFault::ok_or_fault_force)
+/// [or_fault_with()](crate::error::ResultFault::or_fault_with),
+/// [or_fault_with_force()](crate::error::ResultFault::or_fault_with_force),
+/// [ok_or_fault()](crate::error::OptionFault::ok_or_fault),
+/// [ok_or_fault_force()](crate::error::OptionFault::ok_or_fault_force),
+/// [ok_or_fault_with()](crate::error::OptionFault::ok_or_fault_with),
+/// [ok_or_fault_with_force()](crate::error::OptionFault::ok_or_fault_with_force)
 #[derive(Debug, Serialize)]
 pub struct Fault {
     #[serde(serialize_with = "serialize_source")]
     pub source: Option<Box<dyn StdError + Send + Sync>>,
+    #[cfg(feature = "backtrace")]
     #[serde(serialize_with = "serialize_backtrace")]
     backtrace: Backtrace,
     context: Option<String>,
+    #[serde(flatten, serialize_with = "serialize_details")]
+    details: Option<Box<FaultDetails>>, // Box for size: https://doc.rust-lang.org/clippy/lint_configuration.html#large-error-threshold
+    #[serde(flatten)]
+    extensions: Extensions,
 }
 
+/// Optional data of a [Fault], allocated when one of them is set
+#[derive(Debug, Default, Serialize)]
+struct FaultDetails {
+    request_id: Option<String>,
+    /// Sources after the first one, see [Fault::with_sources]
+    #[serde(
+        skip_serializing_if = "Vec::is_empty",
+        serialize_with = "serialize_other_sources"
+    )]
+    other_sources: Vec<Box<dyn StdError + Send + Sync>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    kind: Option<&'static str>,
+    #[serde(
+        skip_serializing_if = "Option::is_none",
+        serialize_with = "serialize_location"
+    )]
+    location: Option<&'static Location<'static>>,
+    #[serde(skip)]
+    retryable: bool,
+}
+
+static EMPTY_DETAILS: FaultDetails = FaultDetails {
+    request_id: None,
+    other_sources: Vec::new(),
+    kind: None,
+    location: None,
+    retryable: false,
+};
+
+/// Former name of [Fault], kept during the transition.
+#[deprecated(since = "0.2.4", note = "renamed to `Fault`")]
+pub type Bug = Fault;
+
 impl<D> From<Fault> for Error<D> {
     fn from(value: Fault) -> Self {
         Error::Fault(value)
@@ -36,28 +135,61 @@ impl StdError for Fault {
     }
 }
 
+/// Two faults are equal if they have the same context, request id, kind, [backtrace status](Fault::backtrace_status) and source.
+/// Sources are compared by their [debug format](crate::errors_chain_debug), chain included, while the content of the
+/// backtraces and the extensions are ignored. Usefull to `assert_eq!` in tests.
+/// # Examples
+/// ```rust
+/// # use explicit_error_exit::Fault;
+/// let fault = || Fault::new().with_context("context").with_source(std::io::Error::other("io"));
+/// assert_eq!(fault(), fault());
+/// assert_ne!(fault(), fault().with_context("other"));
+/// ```
+impl PartialEq for Fault {
+    fn eq(&self, other: &Self) -> bool {
+        self.context == other.context
+            && self.request_id() == other.request_id()
+            && self.kind() == other.kind()
+            && self.backtrace_status() == other.backtrace_status()
+            && crate::sources_eq(
+                self.source.as_deref().map(|s| s as _),
+                other.source.as_deref().map(|s| s as _),
+            )
+    }
+}
+
 impl std::fmt::Display for Fault {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(
             f,
-            "{}{}{}",
-            match self.backtrace.status() {
-                std::backtrace::BacktraceStatus::Captured =>
-                    format!("{}\n ----------------------- \n\n", self.backtrace),
-                _ => String::new(),
-            },
+            "{}{}{}{}{}{}",
+            self.display_backtrace(),
             match &self.context {
                 Some(c) => format!("Context: {}\n", c),
                 None => String::new(),
             },
-            match &self.source {
-                Some(s) => format!(
-                    "Source: {}, {}\n",
-                    crate::error::errors_chain_debug(s.as_ref()),
-                    s
-                ),
+            match self.kind() {
+                Some(k) => format!("Kind: {}\n", k),
+                None => String::new(),
+            },
+            match self.request_id() {
+                Some(r) => format!("Request id: {}\n", r),
+                None => String::new(),
+            },
+            match self.location() {
+                Some(l) => format!("Location: {}\n", l),
                 None => String::new(),
             },
+            self.sources()
+                .map(|s| format!(
+                    "Source: {}\n",
+                    crate::error::redact(&format!(
+                        "{}, {}",
+                        crate::error::errors_chain_debug(s),
+                        s
+                    ))
+                ))
+                .collect::<String>(),
         )
     }
 }
@@ -65,6 +197,9 @@ impl std::fmt::Display for Fault {
 impl Fault {
     /// Usefull to generate a [Fault] when a predicate is not met.
     ///
+    /// The backtrace is captured depending on the `RUST_BACKTRACE` and `RUST_LIB_BACKTRACE` environment variables,
+    /// unless it is forced with [set_force_backtrace] or set otherwise for the current thread with [FaultConfig].
+    ///
     /// # Examples
     /// ```rust
     /// # use explicit_error_http::{Result, Fault};
@@ -78,8 +213,18 @@ impl Fault {
     pub fn new() -> Self {
         Self {
             source: None,
-            backtrace: Backtrace::capture(),
+            #[cfg(feature = "backtrace")]
+            backtrace: match crate::fault_config().backtrace() {
+                BacktracePolicy::Force => Backtrace::force_capture(),
+                BacktracePolicy::Never => Backtrace::disabled(),
+                BacktracePolicy::Environment => match FORCE_BACKTRACE.load(Ordering::Relaxed) {
+                    true => Backtrace::force_capture(),
+                    false => Backtrace::capture(),
+                },
+            },
             context: None,
+            details: None,
+            extensions: Extensions::new(),
         }
     }
 
@@ -106,15 +251,13 @@ impl Fault {
     /// # impl From<&MyEntitysError> for HttpError {
     /// #     fn from(value: &MyEntitysError) -> Self {
     /// #         match value {
-    /// #             MyEntitysError::NotFound => HttpError {
-    /// #               http_status_code: StatusCode::NOT_FOUND,
-    /// #                 public: Box::new(
-    /// #                     ProblemDetails::new()
-    /// #                         .with_type(http::Uri::from_static("/errors/my-entity/not-found"))
-    /// #                         .with_title("Not found"),
-    /// #                     ),
-    /// #                 context: Some("Some usefull info to debug".to_string()),
-    /// #             },
+    /// #             MyEntitysError::NotFound => HttpError::new(
+    /// #                 StatusCode::NOT_FOUND,
+    /// #                 ProblemDetails::new()
+    /// #                     .with_type(http::Uri::from_static("/errors/my-entity/not-found"))
+    /// #                     .with_title("Not found"),
+    /// #             )
+    /// #             .with_context("Some usefull info to debug"),
     /// #         }
     /// #     }
     /// # }
@@ -122,11 +265,87 @@ impl Fault {
     pub fn with_source<E: StdError + 'static + Send + Sync>(self, error: E) -> Self {
         Self {
             source: Some(Box::new(error)),
-            backtrace: self.backtrace,
-            context: self.context,
+            ..self
         }
     }
 
+    /// Add several independent error sources to a [Fault], override the ones that were set.
+    /// Usefull to report together errors collected while validating several inputs.
+    ///
+    /// The first error is the [source](StdError::source), for compatibility, the others are listed after it in display and serialization.
+    /// # Examples
+    /// ```rust
+    /// # use explicit_error::Fault;
+    /// # use std::error::Error;
+    /// let errors: Vec<Box<dyn Error + Send + Sync>> = ["foo", "bar", "baz"]
+    ///     .into_iter()
+    ///     .filter_map(|input| input.parse::<u8>().err())
+    ///     .map(|e| Box::new(e) as _)
+    ///     .collect();
+    ///
+    /// let fault = Fault::new().with_sources(errors);
+    /// assert_eq!(fault.sources().count(), 3);
+    /// ```
+    pub fn with_sources(mut self, errors: Vec<Box<dyn StdError + Send + Sync>>) -> Self {
+        let mut errors = errors.into_iter();
+        self.source = errors.next();
+        self.details_mut().other_sources = errors.collect();
+        self
+    }
+
+    /// Generate a [Fault] aggregating several independent error sources, see [with_sources](Fault::with_sources).
+    pub fn aggregate(errors: Vec<Box<dyn StdError + Send + Sync>>) -> Self {
+        Self::new().with_sources(errors)
+    }
+
+    /// Return the sources of the [Fault], the [source](StdError::source) first followed by the ones added with [with_sources](Fault::with_sources)
+    pub fn sources(&self) -> impl Iterator<Item = &(dyn StdError + Send + Sync + 'static)> {
+        self.source.as_deref().into_iter().chain(
+            self.details
+                .iter()
+                .flat_map(|details| details.other_sources.iter().map(AsRef::as_ref)),
+        )
+    }
+
+    /// Iterate over the [Fault] followed by its chain of [sources](StdError::source), see [errors_chain](crate::errors_chain).
+    /// Sources added with [with_sources](Fault::with_sources) besides the first one are not part of the chain.
+    pub fn chain(&self) -> impl Iterator<Item = &(dyn StdError + 'static)> {
+        crate::error::errors_chain(self)
+    }
+
+    /// Try to downcast the source of the [Fault], it is returned back if it is not set or not of type `E`.
+    /// Usefull to assert_eq! in tests
+    /// # Examples
+    /// ```rust
+    /// # use explicit_error::{prelude::*, Fault};
+    /// let fault = "42a".parse::<u8>().or_fault().unwrap_err();
+    /// assert!(fault.downcast_source_ref::<std::num::ParseIntError>().is_some());
+    ///
+    /// let fault = fault.downcast_source::<std::io::Error>().unwrap_err();
+    /// assert!(fault.downcast_source::<std::num::ParseIntError>().is_ok());
+    /// ```
+    pub fn downcast_source<E>(mut self) -> Result<E, Fault>
+    where
+        E: StdError + 'static,
+    {
+        match self.source.take().map(|source| source.downcast::<E>()) {
+            Some(Ok(source)) => Ok(*source),
+            Some(Err(source)) => {
+                self.source = Some(source);
+                Err(self)
+            }
+            None => Err(self),
+        }
+    }
+
+    /// Try to downcast a reference to the source of the [Fault], [None] if it is not set or not of type `E`.
+    pub fn downcast_source_ref<E>(&self) -> Option<&E>
+    where
+        E: StdError + 'static,
+    {
+        self.source.as_deref()?.downcast_ref::<E>()
+    }
+
     /// Add context to a [Fault], override if one was set. The context appears in display
     /// but not in the http response.
     /// # Examples
@@ -141,14 +360,62 @@ impl Fault {
     /// ```
     pub fn with_context(self, context: impl std::fmt::Display) -> Self {
         Self {
-            source: self.source,
-            backtrace: self.backtrace,
             context: Some(context.to_string()),
+            ..self
+        }
+    }
+
+    /// Add a context to a [Fault] if there is one, override if one was set.
+    /// # Examples
+    /// ```rust
+    /// # use explicit_error_http::Fault;
+    /// # let tenant_id: Option<u32> = None;
+    /// Fault::new().with_context_opt(tenant_id.map(|id| format!("Tenant: {id}")));
+    /// ```
+    pub fn with_context_opt(self, context: Option<impl std::fmt::Display>) -> Self {
+        match context {
+            Some(context) => self.with_context(context),
+            None => self,
         }
     }
 
+    /// Add the identifier of the request that generated the [Fault], override if one was set.
+    /// It appears in display and serialization and can be used to build a response body with a correlation id.
+    /// # Examples
+    /// ```rust
+    /// # use explicit_error_http::{Result, Fault};
+    /// # fn doc(request_id: &str) -> Result<()> {
+    /// if 1 < 2 {
+    ///     Err(Fault::new().with_request_id(request_id))?;
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn with_request_id(mut self, request_id: impl std::fmt::Display) -> Self {
+        self.details_mut().request_id = Some(request_id.to_string());
+        self
+    }
+
+    /// Tag the [Fault] with a coarse kind to group faults in dashboards, eg: `"db"`, `"io"` or `"serialization"`. Override if one was set.
+    /// It appears in display and serialization and, with the `metrics` feature of explicit-error-http, as the `kind` label of faults counter.
+    /// # Examples
+    /// ```rust
+    /// # use explicit_error_http::{prelude::*, Result, Fault};
+    /// # fn doc() -> Result<()> {
+    /// Err(std::io::Error::other("connection reset"))
+    ///     .map_err(|e| Fault::new().with_source(e).with_kind("db"))?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn with_kind(mut self, kind: &'static str) -> Self {
+        self.details_mut().kind = Some(kind);
+        self
+    }
+
     /// Force backtrace capture using [force_capture](std::backtrace::Backtrace::force_capture)
     ///
+    /// Without the `backtrace` feature, it is equivalent to [Fault::new].
+    ///
     /// # Examples
     /// ```rust
     /// # use explicit_error_http::{Result, Fault};
@@ -162,20 +429,366 @@ impl Fault {
     pub fn new_force() -> Self {
         Self {
             source: None,
+            #[cfg(feature = "backtrace")]
             backtrace: Backtrace::force_capture(),
             context: None,
+            details: None,
+            extensions: Extensions::new(),
+        }
+    }
+
+    /// [Fault] without backtrace, cheap to build, to take the place of an error while it is rebuilt
+    pub(crate) fn placeholder() -> Self {
+        Self {
+            source: None,
+            #[cfg(feature = "backtrace")]
+            backtrace: Backtrace::disabled(),
+            context: None,
+            details: None,
+            extensions: Extensions::EMPTY,
+        }
+    }
+
+    /// Convert the payload of a panic caught with [catch_unwind](std::panic::catch_unwind) into a [Fault].
+    /// The panic message, if it is a `&str` or a [String], is set as context and the backtrace capture is forced.
+    ///
+    /// The backtrace is captured where the [Fault] is generated, not where the panic occurred.
+    /// # Examples
+    /// ```rust
+    /// # use explicit_error_http::{Result, Fault};
+    /// fn run_task(task: impl FnOnce() + std::panic::UnwindSafe) -> Result<()> {
+    ///     std::panic::catch_unwind(task).map_err(Fault::from_panic)?;
+    ///     Ok(())
+    /// }
+    /// ```
+    pub fn from_panic(payload: Box<dyn Any + Send>) -> Self {
+        let fault = Self::new_force();
+        match payload
+            .downcast_ref::<&str>()
+            .copied()
+            .or_else(|| payload.downcast_ref::<String>().map(String::as_str))
+        {
+            Some(message) => fault.with_context(format!("Panicked: {message}")),
+            None => fault.with_context("Panicked"),
         }
     }
 
-    /// Return the status of the backtrace
+    /// Return the status of the backtrace, always [BacktraceStatus::Disabled] without the `backtrace` feature
     pub fn backtrace_status(&self) -> BacktraceStatus {
-        self.backtrace.status()
+        #[cfg(feature = "backtrace")]
+        {
+            self.backtrace.status()
+        }
+        #[cfg(not(feature = "backtrace"))]
+        {
+            BacktraceStatus::Disabled
+        }
     }
 
     /// Return the context
     pub fn context(&self) -> Option<&str> {
         self.context.as_deref()
     }
+
+    /// Mutable access to the context, eg: to enrich it in place in a middleware
+    pub fn context_mut(&mut self) -> &mut Option<String> {
+        &mut self.context
+    }
+
+    /// Return the kind set with [with_kind](Fault::with_kind)
+    pub fn kind(&self) -> Option<&'static str> {
+        self.details.as_ref()?.kind
+    }
+
+    /// Return the location in the caller's code of the match that generated the [Fault] when it is created by
+    /// [map_err_or_fault](crate::ResultFault::map_err_or_fault) or [map_err_or_fault_dyn](crate::ResultFault::map_err_or_fault_dyn).
+    pub fn location(&self) -> Option<&'static Location<'static>> {
+        self.details.as_ref()?.location
+    }
+
+    pub(crate) fn with_caller_location(mut self, location: &'static Location<'static>) -> Self {
+        self.details_mut().location = Some(location);
+        self
+    }
+
+    /// Return the identifier of the request that generated the [Fault]
+    pub fn request_id(&self) -> Option<&str> {
+        self.details.as_ref()?.request_id.as_deref()
+    }
+
+    /// Attach app-specific data to a [Fault], override if one of the same type was set.
+    /// # Examples
+    /// ```rust
+    /// # use explicit_error_http::{Result, Fault};
+    /// # use std::sync::Arc;
+    /// struct Tenant(&'static str);
+    ///
+    /// # fn doc() -> Result<()> {
+    /// if 1 < 2 {
+    ///     Err(Fault::new().with_extension(Arc::new(Tenant("acme"))))?;
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn with_extension<T: Any + Send + Sync>(mut self, extension: Arc<T>) -> Self {
+        self.extensions.insert(extension);
+        self
+    }
+
+    /// Return the extension of type `T` if set
+    pub fn extension<T: Any + Send + Sync>(&self) -> Option<&T> {
+        self.extensions.get()
+    }
+
+    /// Mark the [Fault] as retryable, eg: a timeout of a dependency. Equivalent to `with_retryable(true)`
+    /// # Examples
+    /// ```rust
+    /// # use explicit_error_http::{prelude::*, Fault};
+    /// assert!(Fault::new().retryable().is_retryable());
+    /// ```
+    pub fn retryable(self) -> Self {
+        self.with_retryable(true)
+    }
+
+    /// Set whether the [Fault] is [Retryable], override if it was set. Faults are not retryable by default.
+    pub fn with_retryable(mut self, retryable: bool) -> Self {
+        self.details_mut().retryable = retryable;
+        self
+    }
+
+    /// Return a hash identifying faults of the same kind, computed from the context and the chain of sources.
+    /// It is stable during the life of the process only.
+    /// # Examples
+    /// ```rust
+    /// # use explicit_error_http::Fault;
+    /// assert_eq!(
+    ///     Fault::new().with_context("Cache miss").fingerprint(),
+    ///     Fault::new().with_context("Cache miss").fingerprint()
+    /// );
+    /// ```
+    pub fn fingerprint(&self) -> u64 {
+        let mut hasher = DefaultHasher::new();
+        self.context.hash(&mut hasher);
+        self.source
+            .as_deref()
+            .map(|source| crate::error::errors_chain_debug(source))
+            .hash(&mut hasher);
+        hasher.finish()
+    }
+
+    /// Return a view of the [Fault] safe to serialize in a public body, eg: in
+    /// [public_fault_response](https://docs.rs/explicit-error-http/latest/explicit_error_http/trait.HandlerError.html#tymethod.public_fault_response).
+    /// It only contains a generic `message` and, if the fault has one, the [request id](Fault::with_request_id) as `id`.
+    /// The request id is displayed and serialized with the fault, so the `id` correlates the response with the logs of this incident.
+    /// The backtrace, the source and the context are omitted.
+    /// # Examples
+    /// ```rust
+    /// # use explicit_error_http::Fault;
+    /// let fault = Fault::new()
+    ///     .with_context("Connection string: postgres://admin:secret@db")
+    ///     .with_request_id("req-42");
+    /// let body = serde_json::to_string(&fault.public_view()).unwrap();
+    /// assert!(!body.contains("secret"));
+    /// assert!(body.contains("req-42"));
+    /// assert!(fault.to_string().contains("req-42"));
+    /// ```
+    pub fn public_view(&self) -> impl Serialize + use<> {
+        PublicFault {
+            id: self.request_id().map(ToOwned::to_owned),
+            message: "An unexpected error occurred",
+        }
+    }
+
+    /// Serialize the [Fault] as a JSON record that fits on a single line of an NDJSON log: the backtrace
+    /// is split into an array of its lines. Newlines remaining in strings, eg: in the context, are escaped by
+    /// [serde_json::to_string].
+    ///
+    /// Domain errors are not covered: they have no backtrace but the `DomainError` of explicit-error-exit
+    /// does not implement [Serialize]. Record their display as a JSON string instead, its newlines are escaped too.
+    /// # Examples
+    /// ```rust
+    /// # use explicit_error_exit::Fault;
+    /// let record = serde_json::to_string(&Fault::new_force().to_ndjson_value().unwrap()).unwrap();
+    /// assert!(!record.contains('\n'));
+    /// ```
+    #[cfg(feature = "ndjson")]
+    pub fn to_ndjson_value(&self) -> Result<serde_json::Value, serde_json::Error> {
+        let mut value = serde_json::to_value(self)?;
+
+        if let Some(serde_json::Value::String(backtrace)) = value.get("backtrace") {
+            value["backtrace"] = backtrace
+                .lines()
+                .map(str::trim)
+                .filter(|line| !line.is_empty())
+                .collect();
+        }
+
+        Ok(value)
+    }
+
+    /// Log the [Fault] with [tracing] at the error level, at most once per interval for a given [fingerprint](Fault::fingerprint)
+    /// to avoid log storms. The number of occurrences suppressed since the previous log is emitted in the `suppressed` field
+    /// of the next one.
+    /// # Examples
+    /// ```rust
+    /// # use explicit_error_http::Fault;
+    /// # use std::time::Duration;
+    /// Fault::new()
+    ///     .with_context("Cache is unreachable")
+    ///     .log_throttled(Duration::from_secs(60));
+    /// ```
+    #[cfg(feature = "tracing")]
+    pub fn log_throttled(&self, interval: Duration) {
+        let fingerprint = self.fingerprint();
+        if let Some(suppressed) = throttle(fingerprint, interval) {
+            tracing::error!(fingerprint, suppressed, "{self}");
+        }
+    }
+
+    /// Convert to an [eyre::Report], enabled with the `eyre` feature. The message of the report is the context of the [Fault]
+    /// and its chain is the one of the [source](Fault::with_source).
+    ///
+    /// With the `color-eyre` feature, the backtrace captured by the [Fault] and its request id are added as sections
+    /// when the [color_eyre] hook is installed.
+    ///
+    /// Note: eyre implements `From<E: std::error::Error>` for [eyre::Report], so `?` also converts a [Fault] but keeps
+    /// its whole [Display](std::fmt::Display) as message.
+    /// # Examples
+    /// ```rust
+    /// # use explicit_error_exit::Fault;
+    /// fn main() -> eyre::Result<()> {
+    ///     let report = Fault::new()
+    ///         .with_source(std::io::Error::other("connection reset"))
+    ///         .with_context("Cannot load the configuration")
+    ///         .into_report();
+    ///
+    ///     assert_eq!(report.to_string(), "Cannot load the configuration");
+    ///     assert_eq!(report.root_cause().to_string(), "connection reset");
+    ///     Ok(())
+    /// }
+    /// ```
+    #[cfg(feature = "eyre")]
+    pub fn into_report(self) -> eyre::Report {
+        #[cfg(feature = "color-eyre")]
+        {
+            use color_eyre::{Section, SectionExt};
+
+            #[cfg(feature = "backtrace")]
+            let backtrace = (self.backtrace.status() == BacktraceStatus::Captured)
+                .then(|| self.backtrace.to_string());
+            #[cfg(not(feature = "backtrace"))]
+            let backtrace = None::<String>;
+            let request_id = self.request_id().map(ToOwned::to_owned);
+
+            let mut report = eyre::Report::new(FaultReport(self));
+            if let Some(backtrace) = backtrace {
+                report = report.section(backtrace.header("Fault backtrace:"));
+            }
+            if let Some(request_id) = request_id {
+                report = report.note(format!("Request id: {request_id}"));
+            }
+            report
+        }
+        #[cfg(not(feature = "color-eyre"))]
+        {
+            eyre::Report::new(FaultReport(self))
+        }
+    }
+
+    /// Return the OpenTelemetry trace id of the span active when the [Fault] was created
+    #[cfg(feature = "otel")]
+    pub fn trace_id(&self) -> Option<TraceId> {
+        self.extension::<SpanIds>().map(|ids| ids.trace_id)
+    }
+
+    /// Return the OpenTelemetry span id of the span active when the [Fault] was created
+    #[cfg(feature = "otel")]
+    pub fn span_id(&self) -> Option<SpanId> {
+        self.extension::<SpanIds>().map(|ids| ids.span_id)
+    }
+}
+
+impl Fault {
+    #[cfg(feature = "backtrace")]
+    fn display_backtrace(&self) -> String {
+        match self.backtrace.status() {
+            BacktraceStatus::Captured => {
+                format!("{}\n ----------------------- \n\n", self.backtrace)
+            }
+            _ => String::new(),
+        }
+    }
+
+    #[cfg(not(feature = "backtrace"))]
+    fn display_backtrace(&self) -> String {
+        String::new()
+    }
+
+    fn details_mut(&mut self) -> &mut FaultDetails {
+        self.details.get_or_insert_with(Default::default)
+    }
+}
+
+/// Adapter of a [Fault] to an [eyre::Report] with its context as message
+#[cfg(feature = "eyre")]
+#[derive(Debug)]
+struct FaultReport(Fault);
+
+#[cfg(feature = "eyre")]
+impl std::fmt::Display for FaultReport {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.0.context().unwrap_or("Fault"))
+    }
+}
+
+#[cfg(feature = "eyre")]
+impl StdError for FaultReport {
+    fn source(&self) -> Option<&(dyn StdError + 'static)> {
+        self.0.source()
+    }
+}
+
+/// Return the number of occurrences suppressed since the previous log if the fingerprint must be logged
+#[cfg(feature = "tracing")]
+fn throttle(fingerprint: u64, interval: Duration) -> Option<u64> {
+    let mut throttles = LOG_THROTTLE
+        .lock()
+        .unwrap_or_else(|poisoned| poisoned.into_inner());
+
+    if let Some(throttle) = throttles.get_mut(&fingerprint) {
+        if throttle.logged_at.elapsed() < interval {
+            throttle.suppressed += 1;
+            return None;
+        }
+
+        let suppressed = throttle.suppressed;
+        *throttle = LogThrottle {
+            logged_at: Instant::now(),
+            suppressed: 0,
+        };
+        return Some(suppressed);
+    }
+
+    if throttles.len() >= LOG_THROTTLE_CAPACITY {
+        throttles.retain(|_, throttle| throttle.logged_at.elapsed() < interval);
+    }
+    throttles.insert(
+        fingerprint,
+        LogThrottle {
+            logged_at: Instant::now(),
+            suppressed: 0,
+        },
+    );
+
+    Some(0)
+}
+
+impl Retryable for Fault {
+    fn is_retryable(&self) -> bool {
+        self.details
+            .as_ref()
+            .is_some_and(|details| details.retryable)
+    }
 }
 
 impl Default for Fault {
@@ -194,11 +807,51 @@ where
     s.serialize_str(
         &source
             .as_ref()
-            .map(|s| format!("{}: {}", crate::error::errors_chain_debug(s.as_ref()), s))
+            .map(|s| redact_source(s.as_ref()))
             .unwrap_or_default(),
     )
 }
 
+fn redact_source(source: &(dyn StdError + Send + Sync)) -> String {
+    crate::error::redact(&format!(
+        "{}: {}",
+        crate::error::errors_chain_debug(source),
+        source
+    ))
+}
+
+fn serialize_details<S>(details: &Option<Box<FaultDetails>>, s: S) -> Result<S::Ok, S::Error>
+where
+    S: Serializer,
+{
+    details.as_deref().unwrap_or(&EMPTY_DETAILS).serialize(s)
+}
+
+/// Sources added with [with_sources](Fault::with_sources) besides the first one
+fn serialize_other_sources<S>(
+    sources: &[Box<dyn StdError + Send + Sync>],
+    s: S,
+) -> Result<S::Ok, S::Error>
+where
+    S: Serializer,
+{
+    s.collect_seq(sources.iter().map(|source| redact_source(source.as_ref())))
+}
+
+fn serialize_location<S>(
+    location: &Option<&'static Location<'static>>,
+    s: S,
+) -> Result<S::Ok, S::Error>
+where
+    S: Serializer,
+{
+    match location {
+        Some(location) => s.collect_str(location),
+        None => s.serialize_none(),
+    }
+}
+
+#[cfg(feature = "backtrace")]
 fn serialize_backtrace<S>(backtrace: &Backtrace, s: S) -> Result<S::Ok, S::Error>
 where
     S: Serializer,
@@ -19,12 +19,46 @@ fn source() {
     assert!(Fault::new().source().is_none());
 }
 
+#[test]
+fn downcast_source() {
+    let fault = Fault::new().with_source(sqlx::Error::RowNotFound);
+    assert!(matches!(
+        fault.downcast_source_ref::<sqlx::Error>(),
+        Some(sqlx::Error::RowNotFound)
+    ));
+    assert!(fault.downcast_source_ref::<std::io::Error>().is_none());
+    assert!(Fault::new().downcast_source_ref::<sqlx::Error>().is_none());
+
+    let fault = fault
+        .with_context("context")
+        .downcast_source::<std::io::Error>()
+        .unwrap_err();
+    assert_eq!(fault.context(), Some("context"));
+    assert!(matches!(
+        fault.downcast_source::<sqlx::Error>(),
+        Ok(sqlx::Error::RowNotFound)
+    ));
+
+    assert!(
+        Fault::new()
+            .with_context("context")
+            .downcast_source::<sqlx::Error>()
+            .unwrap_err()
+            .source
+            .is_none()
+    );
+
+    #[allow(deprecated)]
+    let bug: crate::Bug = Fault::new().with_source(sqlx::Error::RowNotFound);
+    assert!(bug.downcast_source::<sqlx::Error>().is_ok());
+}
+
 #[test]
 fn new() {
     let fault = Fault::new();
     assert!(fault.context().is_none());
     assert!(fault.source.is_none());
-    assert_eq!(fault.backtrace.status(), BacktraceStatus::Disabled);
+    assert_eq!(fault.backtrace_status(), BacktraceStatus::Disabled);
 }
 
 #[test]
@@ -49,19 +83,45 @@ fn with_context() {
     );
 }
 
+#[test]
+fn with_context_opt() {
+    let fault = Fault::new().with_context_opt(Some("context"));
+    assert_eq!(fault.context(), Some("context"));
+    assert_eq!(
+        fault.with_context_opt(None::<&str>).context(),
+        Some("context")
+    );
+}
+
 #[test]
 fn new_force() {
     let fault = Fault::new_force();
     assert!(fault.context().is_none());
     assert!(fault.source.is_none());
-    assert_eq!(fault.backtrace.status(), BacktraceStatus::Captured);
+    assert_eq!(fault.backtrace_status(), BacktraceStatus::Captured);
+}
+
+#[test]
+fn from_panic() {
+    let fault = Fault::from_panic(
+        std::panic::catch_unwind(|| panic!("worker {} crashed", 42)).unwrap_err(),
+    );
+    assert_eq!(fault.context(), Some("Panicked: worker 42 crashed"));
+    assert_eq!(fault.backtrace_status(), BacktraceStatus::Captured);
+
+    let fault = Fault::from_panic(std::panic::catch_unwind(|| panic!("crashed")).unwrap_err());
+    assert_eq!(fault.context(), Some("Panicked: crashed"));
+
+    let fault =
+        Fault::from_panic(std::panic::catch_unwind(|| std::panic::panic_any(42)).unwrap_err());
+    assert_eq!(fault.context(), Some("Panicked"));
 }
 
 #[test]
 fn backtrace_status() {
     assert_eq!(Fault::new().backtrace_status(), BacktraceStatus::Disabled);
     assert_eq!(
-        Fault::new_force().backtrace.status(),
+        Fault::new_force().backtrace_status(),
         BacktraceStatus::Captured
     );
 }
@@ -73,3 +133,336 @@ fn context() {
         "context"
     );
 }
+
+#[test]
+fn with_request_id() {
+    let fault = Fault::new().with_request_id("request");
+    assert_eq!(fault.request_id().unwrap(), "request");
+    assert_eq!(fault.with_request_id(42).request_id().unwrap(), "42");
+}
+
+#[test]
+fn request_id() {
+    assert!(Fault::new().request_id().is_none());
+    assert_eq!(
+        Fault::new()
+            .with_request_id("request")
+            .request_id()
+            .unwrap(),
+        "request"
+    );
+}
+
+#[test]
+fn serialize() {
+    let json = serde_json::json!(
+        Fault::new()
+            .with_context("context")
+            .with_request_id("request")
+    );
+    assert_eq!(json["context"], "context");
+    assert_eq!(json["request_id"], "request");
+    assert_eq!(json["source"], "");
+
+    assert!(serde_json::json!(Fault::new())["request_id"].is_null());
+}
+
+#[test]
+fn with_sources() {
+    let fault = Fault::aggregate(vec![
+        Box::new(std::io::Error::other("foo")),
+        Box::new(std::io::Error::other("bar")),
+        Box::new(std::io::Error::other("baz")),
+    ]);
+
+    assert_eq!(StdError::source(&fault).unwrap().to_string(), "foo");
+    assert_eq!(
+        fault.sources().map(ToString::to_string).collect::<Vec<_>>(),
+        vec!["foo", "bar", "baz"]
+    );
+
+    let json = serde_json::json!(fault);
+    assert!(json["source"].as_str().unwrap().ends_with(": foo"));
+    assert_eq!(json["other_sources"].as_array().unwrap().len(), 2);
+    assert!(
+        json["other_sources"][0]
+            .as_str()
+            .unwrap()
+            .ends_with(": bar")
+    );
+    assert!(
+        json["other_sources"][1]
+            .as_str()
+            .unwrap()
+            .ends_with(": baz")
+    );
+    assert!(serde_json::json!(Fault::new())["other_sources"].is_null());
+
+    let display = fault.to_string();
+    assert_eq!(display.matches("Source: ").count(), 3);
+    assert!(display.contains("bar") && display.contains("baz"));
+
+    let fault = Fault::new()
+        .with_sources(vec![Box::new(std::io::Error::other("foo"))])
+        .with_sources(vec![]);
+    assert!(fault.source.is_none());
+    assert_eq!(fault.sources().count(), 0);
+}
+
+#[test]
+fn kind() {
+    assert!(Fault::new().kind().is_none());
+    assert!(serde_json::json!(Fault::new())["kind"].is_null());
+
+    let error = explicit_error_exit::Error::from(explicit_error_exit::Fault::new().with_kind("db"));
+    let fault = error.unwrap_fault();
+    assert_eq!(fault.kind(), Some("db"));
+    assert_eq!(serde_json::json!(fault)["kind"], "db");
+    assert_eq!(
+        fault.with_context("context").to_string(),
+        "Context: context\nKind: db\n"
+    );
+}
+
+#[cfg(feature = "eyre")]
+#[test]
+fn into_report() {
+    let report = Fault::new()
+        .with_source(std::io::Error::other("io"))
+        .with_context("context")
+        .into_report();
+    assert_eq!(report.to_string(), "context");
+    assert_eq!(
+        report.chain().map(ToString::to_string).collect::<Vec<_>>(),
+        vec!["context", "io"]
+    );
+    assert!(format!("{report:?}").contains("context"));
+
+    assert_eq!(Fault::new().into_report().to_string(), "Fault");
+}
+
+#[cfg(feature = "ndjson")]
+#[test]
+fn to_ndjson_value() {
+    let fault = Fault::new_force()
+        .with_source(std::io::Error::other("first line\nsecond line"))
+        .with_context("multi\nline");
+    let value = fault.to_ndjson_value().unwrap();
+
+    assert_eq!(value["context"], "multi\nline");
+    #[cfg(feature = "backtrace")]
+    {
+        let backtrace = value["backtrace"].as_array().unwrap();
+        assert!(!backtrace.is_empty());
+        assert!(
+            backtrace
+                .iter()
+                .all(|line| !line.as_str().unwrap().contains('\n'))
+        );
+    }
+
+    let record = serde_json::to_string(&value).unwrap();
+    assert!(!record.contains('\n'));
+}
+
+#[test]
+fn partial_eq() {
+    let fault = || {
+        Fault::new()
+            .with_context("context")
+            .with_source(sqlx::Error::Io(std::io::Error::other("io")))
+    };
+    assert_eq!(fault(), fault());
+    assert_eq!(fault().with_extension(std::sync::Arc::new(42_u32)), fault());
+
+    assert_ne!(fault(), fault().with_context("other"));
+    assert_ne!(fault(), fault().with_request_id("request"));
+    assert_ne!(fault(), fault().with_kind("kind"));
+    assert_ne!(fault(), Fault::new().with_context("context"));
+    assert_ne!(
+        fault(),
+        Fault::new()
+            .with_context("context")
+            .with_source(sqlx::Error::Io(std::io::Error::other("other")))
+    );
+    assert_ne!(
+        fault(),
+        Fault::new()
+            .with_context("context")
+            .with_source(std::io::Error::other("io"))
+    );
+    #[cfg(feature = "backtrace")]
+    assert_ne!(
+        Fault::new_force(),
+        Fault {
+            backtrace: Backtrace::disabled(),
+            ..Fault::new_force()
+        }
+    );
+}
+
+#[test]
+fn display() {
+    assert_eq!(
+        Fault::new()
+            .with_context("context")
+            .with_request_id("request")
+            .to_string(),
+        "Context: context\nRequest id: request\n"
+    );
+}
+
+#[test]
+fn with_extension() {
+    let fault = Fault::new();
+    assert!(fault.extension::<u32>().is_none());
+    assert_eq!(
+        *fault
+            .with_extension(std::sync::Arc::new(42_u32))
+            .extension::<u32>()
+            .unwrap(),
+        42
+    );
+}
+
+#[cfg(feature = "otel")]
+#[test]
+fn span_ids() {
+    use opentelemetry::{
+        Context,
+        trace::{SpanContext, SpanId, TraceContextExt, TraceFlags, TraceId, TraceState},
+    };
+
+    assert!(Fault::new().trace_id().is_none());
+
+    let trace_id = TraceId::from_hex("4bf92f3577b34da6a3ce929d0e0e4736").unwrap();
+    let span_id = SpanId::from_hex("00f067aa0ba902b7").unwrap();
+    let _guard = Context::current()
+        .with_remote_span_context(SpanContext::new(
+            trace_id,
+            span_id,
+            TraceFlags::SAMPLED,
+            true,
+            TraceState::default(),
+        ))
+        .attach();
+
+    let fault = Fault::new();
+    assert_eq!(fault.trace_id().unwrap(), trace_id);
+    assert_eq!(fault.span_id().unwrap(), span_id);
+
+    let json = serde_json::json!(fault);
+    assert_eq!(json["trace_id"], "4bf92f3577b34da6a3ce929d0e0e4736");
+    assert_eq!(json["span_id"], "00f067aa0ba902b7");
+}
+
+#[test]
+fn retryable() {
+    assert!(!Fault::new().is_retryable());
+    assert!(Fault::new().retryable().is_retryable());
+    assert!(Fault::new().with_retryable(true).is_retryable());
+    assert!(
+        !Fault::new()
+            .retryable()
+            .with_retryable(false)
+            .is_retryable()
+    );
+}
+
+#[test]
+fn fingerprint() {
+    assert_eq!(
+        Fault::new()
+            .with_context("context")
+            .with_source(sqlx::Error::RowNotFound)
+            .fingerprint(),
+        Fault::new()
+            .with_context("context")
+            .with_source(sqlx::Error::RowNotFound)
+            .fingerprint()
+    );
+    assert_ne!(
+        Fault::new().with_context("context").fingerprint(),
+        Fault::new().with_context("other").fingerprint()
+    );
+    assert_ne!(
+        Fault::new()
+            .with_source(sqlx::Error::RowNotFound)
+            .fingerprint(),
+        Fault::new()
+            .with_source(sqlx::Error::PoolClosed)
+            .fingerprint()
+    );
+}
+
+#[test]
+fn public_view() {
+    let fault = Fault::new_force()
+        .with_context("context")
+        .with_source(sqlx::Error::RowNotFound);
+    let json = serde_json::to_value(fault.public_view()).unwrap();
+
+    assert_eq!(
+        json,
+        serde_json::json!({
+            "message": "An unexpected error occurred",
+        })
+    );
+
+    let fault = fault.with_request_id("req-42");
+    assert_eq!(
+        serde_json::to_value(fault.public_view()).unwrap(),
+        serde_json::json!({
+            "id": "req-42",
+            "message": "An unexpected error occurred",
+        })
+    );
+    assert!(fault.to_string().contains("Request id: req-42"));
+    assert_eq!(
+        serde_json::to_value(&fault).unwrap()["request_id"],
+        serde_json::json!("req-42")
+    );
+
+    let json = serde_json::to_value(fault.public_view()).unwrap();
+
+    let json = json.to_string();
+    assert!(!json.contains("backtrace"));
+    assert!(!json.contains("RowNotFound"));
+    assert!(!json.contains("context"));
+}
+
+#[cfg(feature = "tracing")]
+#[test]
+fn log_throttled() {
+    let interval = std::time::Duration::from_millis(100);
+    let fingerprint = Fault::new().with_context("log_throttled").fingerprint();
+
+    assert_eq!(throttle(fingerprint, interval), Some(0));
+    for _ in 0..100 {
+        assert_eq!(throttle(fingerprint, interval), None);
+        Fault::new()
+            .with_context("log_throttled")
+            .log_throttled(interval);
+    }
+
+    std::thread::sleep(interval);
+    assert_eq!(throttle(fingerprint, interval), Some(200));
+    assert_eq!(throttle(fingerprint, interval), None);
+}
+
+#[test]
+#[allow(deprecated)]
+fn bug_aliases() {
+    fn result<R: crate::ResultBug<(), std::io::Error>>(result: R) -> Result<(), Fault> {
+        result.or_fault()
+    }
+
+    fn option<O: crate::OptionBug<()>>(option: O) -> Result<(), Fault> {
+        option.ok_or_fault()
+    }
+
+    let bug: crate::Bug = crate::Bug::new().with_context("context");
+    assert_eq!(bug.context.as_deref(), Some("context"));
+    assert!(result(Err(std::io::Error::other("io"))).is_err());
+    assert!(option(None).is_err());
+}
@@ -0,0 +1,105 @@
+use std::{cell::Cell, marker::PhantomData};
+
+thread_local! {
+    static FAULT_CONFIG: Cell<FaultConfig> = const { Cell::new(FaultConfig::new()) };
+}
+
+/// Capture policy of the backtrace of a [Fault](crate::Fault) generated with [Fault::new](crate::Fault::new).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum BacktracePolicy {
+    /// Depend on the `RUST_BACKTRACE` and `RUST_LIB_BACKTRACE` environment variables, unless it is forced with
+    /// [set_force_backtrace](crate::set_force_backtrace).
+    #[default]
+    Environment,
+    /// Always capture, as [Fault::new_force](crate::Fault::new_force) does.
+    Force,
+    /// Never capture, eg: in a hot path where faults are expected and discarded.
+    Never,
+}
+
+/// Configuration of the [Fault](crate::Fault)s generated on the current thread, get the current one with [fault_config].
+///
+/// Unlike [set_force_backtrace](crate::set_force_backtrace), it is applied to a scope: it is restored when the guard returned by
+/// [scope](FaultConfig::scope) is dropped. Usefull for subsystems that need a different behavior, eg: always capture in
+/// the database layer.
+/// # Examples
+/// ```rust
+/// use explicit_error::{BacktracePolicy, Fault, fault_config};
+/// use std::backtrace::BacktraceStatus;
+///
+/// {
+///     let _guard = fault_config().with_backtrace(BacktracePolicy::Force).scope();
+///     assert_eq!(Fault::new().backtrace_status(), BacktraceStatus::Captured);
+/// }
+///
+/// assert_eq!(fault_config().backtrace(), BacktracePolicy::Environment);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct FaultConfig {
+    backtrace: BacktracePolicy,
+}
+
+impl FaultConfig {
+    pub const fn new() -> Self {
+        Self {
+            backtrace: BacktracePolicy::Environment,
+        }
+    }
+
+    /// Set the backtrace capture policy
+    pub fn with_backtrace(mut self, backtrace: BacktracePolicy) -> Self {
+        self.backtrace = backtrace;
+        self
+    }
+
+    /// Return the backtrace capture policy
+    pub fn backtrace(&self) -> BacktracePolicy {
+        self.backtrace
+    }
+
+    /// Apply the configuration to the current thread until the returned guard is dropped,
+    /// then the previous configuration is restored.
+    #[must_use = "the configuration is restored when the guard is dropped"]
+    pub fn scope(self) -> FaultConfigGuard {
+        FaultConfigGuard {
+            previous: FAULT_CONFIG.replace(self),
+            _not_send: PhantomData,
+        }
+    }
+}
+
+/// Restore the previous [FaultConfig] of the thread when dropped, see [FaultConfig::scope].
+#[derive(Debug)]
+pub struct FaultConfigGuard {
+    previous: FaultConfig,
+    _not_send: PhantomData<*const ()>,
+}
+
+impl Drop for FaultConfigGuard {
+    fn drop(&mut self) {
+        FAULT_CONFIG.set(self.previous);
+    }
+}
+
+/// Return the [FaultConfig] of the current thread.
+pub fn fault_config() -> FaultConfig {
+    FAULT_CONFIG.get()
+}
+
+/// Force the backtrace capture of the [Fault](crate::Fault)s generated on the current thread until the returned guard is dropped.
+/// Shorthand for `fault_config().with_backtrace(BacktracePolicy::Force).scope()`.
+/// # Examples
+/// ```rust
+/// # use explicit_error::Fault;
+/// let _guard = explicit_error::force_backtraces();
+/// assert_eq!(Fault::new().backtrace_status(), std::backtrace::BacktraceStatus::Captured);
+/// ```
+#[must_use = "the configuration is restored when the guard is dropped"]
+pub fn force_backtraces() -> FaultConfigGuard {
+    fault_config()
+        .with_backtrace(BacktracePolicy::Force)
+        .scope()
+}
+
+#[cfg(test)]
+mod test;
@@ -0,0 +1,37 @@
+use super::*;
+use crate::Fault;
+use std::backtrace::BacktraceStatus;
+
+#[test]
+fn scope() {
+    assert_eq!(fault_config(), FaultConfig::new());
+
+    {
+        let _guard = fault_config()
+            .with_backtrace(BacktracePolicy::Never)
+            .scope();
+        assert_eq!(fault_config().backtrace(), BacktracePolicy::Never);
+        assert_eq!(Fault::new().backtrace_status(), BacktraceStatus::Disabled);
+
+        {
+            let _guard = force_backtraces();
+            assert_eq!(fault_config().backtrace(), BacktracePolicy::Force);
+            #[cfg(feature = "backtrace")]
+            assert_eq!(Fault::new().backtrace_status(), BacktraceStatus::Captured);
+        }
+
+        assert_eq!(fault_config().backtrace(), BacktracePolicy::Never);
+        assert_eq!(Fault::new().backtrace_status(), BacktraceStatus::Disabled);
+    }
+
+    assert_eq!(fault_config().backtrace(), BacktracePolicy::Environment);
+}
+
+#[test]
+fn thread_local() {
+    let _guard = force_backtraces();
+
+    std::thread::spawn(|| assert_eq!(fault_config().backtrace(), BacktracePolicy::Environment))
+        .join()
+        .unwrap();
+}
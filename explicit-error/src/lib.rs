@@ -169,17 +169,48 @@
 //! - Without a type to unified errors the implementation are spread
 //! - With one type to unify errors (eg: a big enum called AppError), cohesion is discreased with more boilerplate
 mod domain;
+mod ensure;
 mod error;
 mod fault;
+mod fault_config;
 
 pub use domain::*;
 pub use error::*;
 pub use fault::*;
+pub use fault_config::*;
 
+/// Extension traits on [Result] and [Option], import them with `use explicit_error::prelude::*`.
+///
+/// - `.fault()` marks any [Result], whose error implements [std::error::Error], or [Option] as a [Fault].
+///   [or_fault](ResultFault::or_fault) and [ok_or_fault](OptionFault::ok_or_fault) are the same with explicit names.
+/// - [ResultFault] also converts any [Result::Err] into a [Fault] without source, forcing the backtrace capture
+///   or with a context computed lazily.
+/// - [ResultError] adds contexts, pattern matches on the source and maps the variants of `Result<T, Error<D>>`.
+/// - [ResultFaultWithContext] adds a context to `Result<T, Fault>`.
+/// - [Retryable] tells whether an error is worth retrying.
+/// - [ensure_domain] and [ensure_fault] return early with an error if a condition is not met.
+///
+/// ```rust
+/// use explicit_error_exit::{prelude::*, Result};
+///
+/// fn foo(input: &str) -> Result<u8> {
+///     let first = input.split(',').next().fault()?;
+///     Ok(first.parse::<u8>().fault().with_context("First value is not a u8")?)
+/// }
+/// ```
 pub mod prelude {
-    pub use crate::error::{OptionFault, ResultError, ResultFault, ResultFaultWithContext};
+    #[allow(deprecated)]
+    pub use crate::error::{OptionBug, ResultBug, ResultBugWithContext};
+    pub use crate::error::{
+        OptionFault, ResultError, ResultFault, ResultFaultWithContext, Retryable,
+    };
+    pub use crate::{ensure_domain, ensure_fault};
 }
 
-fn unwrap_failed(msg: &str, error: &dyn std::fmt::Debug) -> ! {
-    panic!("{msg}: {error:?}")
+#[track_caller]
+fn unwrap_failed(msg: &str, detail: Option<&str>) -> ! {
+    match detail.and_then(|detail| detail.lines().next()) {
+        Some(line) => panic!("{msg}: {line}"),
+        None => panic!("{msg}"),
+    }
 }
@@ -0,0 +1,24 @@
+// Own test binary: the tag is global and other tests assert the default one
+use explicit_error::set_error_variant_tag;
+use explicit_error_http::{Error, Fault, HttpError};
+use http::StatusCode;
+
+#[test]
+fn set_error_variant_tag_key() {
+    let fault = Error::Fault(Fault::new());
+    let domain = Error::from(HttpError::from_status(StatusCode::NOT_FOUND));
+    assert_eq!(serde_json::json!(fault)["error_kind"], "fault");
+
+    set_error_variant_tag("category");
+    assert_eq!(serde_json::json!(fault)["category"], "fault");
+    assert!(serde_json::json!(fault).get("error_kind").is_none());
+    assert_eq!(
+        serde_json::json!(domain),
+        serde_json::json!({
+            "category": "domain",
+            "type": "about:blank",
+            "status": 404,
+            "title": "Not Found",
+        })
+    );
+}
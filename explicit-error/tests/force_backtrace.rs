@@ -0,0 +1,22 @@
+// Own test binary: the flag is global and other tests assert backtraces are not captured by default
+use explicit_error::{Fault, set_force_backtrace};
+use std::backtrace::BacktraceStatus;
+
+#[cfg(feature = "backtrace")]
+#[test]
+fn set_force_backtrace_toggle() {
+    assert_eq!(Fault::new().backtrace_status(), BacktraceStatus::Disabled);
+
+    set_force_backtrace(true);
+    assert_eq!(Fault::new().backtrace_status(), BacktraceStatus::Captured);
+
+    set_force_backtrace(false);
+    assert_eq!(Fault::new().backtrace_status(), BacktraceStatus::Disabled);
+}
+
+#[cfg(not(feature = "backtrace"))]
+#[test]
+fn set_force_backtrace_without_feature() {
+    set_force_backtrace(true);
+    assert_eq!(Fault::new().backtrace_status(), BacktraceStatus::Disabled);
+}